@@ -1,10 +1,18 @@
 import { createRequire } from 'node:module'
 
-import { bench } from 'vitest'
+import { bench, describe } from 'vitest'
 
 const require = createRequire(import.meta.url)
 
-const { benchCreateBuffer } = require('./index.node')
+const {
+  benchCreateBuffer,
+  benchCreateSmallBuffers,
+  benchCreateSmallBuffersPooled,
+  benchCreateLargeBufferCopy,
+  benchCreateLargeBufferZeroCopy,
+  benchCreateBuffers,
+  benchCreateBuffersFromPool,
+} = require('./index.node')
 
 function createBuffer() {
   const buf = Buffer.allocUnsafe(1024)
@@ -20,3 +28,37 @@ bench('napi-rs', () => {
 bench('JavaScript', () => {
   createBuffer()
 })
+
+describe('create 1000 small buffers', () => {
+  bench('Buffer::from(Vec<u8>)', () => {
+    benchCreateSmallBuffers(1000)
+  })
+
+  bench('Buffer::from_vec_pooled', () => {
+    benchCreateSmallBuffersPooled(1000)
+  })
+})
+
+describe('create a 16 MiB buffer', () => {
+  const len = 16 * 1024 * 1024
+
+  bench('BufferSlice::copy_from', () => {
+    benchCreateLargeBufferCopy(len)
+  })
+
+  bench('Buffer::from_vec_zero_copy', () => {
+    benchCreateLargeBufferZeroCopy(len)
+  })
+})
+
+describe('create 100 buffers of 64 KiB', () => {
+  const len = 64 * 1024
+
+  bench('Buffer::from(Vec<u8>)', () => {
+    benchCreateBuffers(100, len)
+  })
+
+  bench('BufferPool::checkout', () => {
+    benchCreateBuffersFromPool(100, len)
+  })
+})
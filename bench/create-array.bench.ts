@@ -7,6 +7,7 @@ const require = createRequire(import.meta.url)
 const {
   createArrayJson,
   createArray,
+  createArrayFrom,
   createArrayWithSerdeTrait,
 } = require('./index.node')
 
@@ -18,6 +19,10 @@ bench('create array for loop', () => {
   createArray()
 })
 
+bench('create array with Env::create_array_from', () => {
+  createArrayFrom()
+})
+
 bench('create array with serde trait', () => {
   createArrayWithSerdeTrait()
 })
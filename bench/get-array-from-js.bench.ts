@@ -8,9 +8,11 @@ const {
   getArrayFromJson,
   getArrayFromJsArray,
   getArrayWithForLoop,
+  getArrayWithToVec,
 } = require('./index.node')
 
 const FIXTURE = Array.from({ length: 1000 }).fill(42)
+const LARGE_FIXTURE = Array.from({ length: 100_000 }).fill(42)
 
 bench('get array from json string', () => {
   getArrayFromJson(JSON.stringify(FIXTURE))
@@ -23,3 +25,15 @@ bench('get array from serde', () => {
 bench('get array with for loop', () => {
   getArrayWithForLoop(FIXTURE)
 })
+
+bench('get array with to_vec_with', () => {
+  getArrayWithToVec(FIXTURE)
+})
+
+bench('get large array with for loop', () => {
+  getArrayWithForLoop(LARGE_FIXTURE)
+})
+
+bench('get large array with to_vec_with', () => {
+  getArrayWithToVec(LARGE_FIXTURE)
+})
@@ -1,4 +1,11 @@
-use napi::{ContextlessResult, Env, JsBuffer, JsObject, Result};
+use std::sync::LazyLock;
+
+use napi::{
+  bindgen_prelude::{Buffer, BufferPool, BufferSlice},
+  CallContext, ContextlessResult, Env, JsBuffer, JsObject, Result,
+};
+
+static BUFFER_POOL: LazyLock<BufferPool> = LazyLock::new(|| BufferPool::new(1024));
 
 #[contextless_function]
 pub fn bench_create_buffer(env: Env) -> ContextlessResult<JsBuffer> {
@@ -10,7 +17,69 @@ pub fn bench_create_buffer(env: Env) -> ContextlessResult<JsBuffer> {
     .map(|v| Some(v.into_raw()))
 }
 
+#[js_function(1)]
+fn bench_create_small_buffers(ctx: CallContext) -> Result<Vec<Buffer>> {
+  let count = ctx.get::<u32>(0)?;
+  Ok((0..count).map(|_| Buffer::from(vec![1u8; 64])).collect())
+}
+
+#[js_function(1)]
+fn bench_create_small_buffers_pooled(ctx: CallContext) -> Result<Vec<Buffer>> {
+  let count = ctx.get::<u32>(0)?;
+  Ok(
+    (0..count)
+      .map(|_| Buffer::from_vec_pooled(vec![1u8; 64]))
+      .collect(),
+  )
+}
+
+#[js_function(1)]
+fn bench_create_large_buffer_copy(ctx: CallContext) -> Result<BufferSlice<'_>> {
+  let len = ctx.get::<u32>(0)?;
+  BufferSlice::copy_from(ctx.env, vec![1u8; len as usize])
+}
+
+#[js_function(1)]
+fn bench_create_large_buffer_zero_copy(ctx: CallContext) -> Result<Buffer> {
+  let len = ctx.get::<u32>(0)?;
+  Ok(Buffer::from_vec_zero_copy(vec![1u8; len as usize]))
+}
+
+#[js_function(2)]
+fn bench_create_buffers(ctx: CallContext) -> Result<Vec<Buffer>> {
+  let count = ctx.get::<u32>(0)?;
+  let len = ctx.get::<u32>(1)? as usize;
+  Ok((0..count).map(|_| Buffer::from(vec![1u8; len])).collect())
+}
+
+#[js_function(2)]
+fn bench_create_buffers_from_pool(ctx: CallContext) -> Result<Vec<Buffer>> {
+  let count = ctx.get::<u32>(0)?;
+  let len = ctx.get::<u32>(1)? as usize;
+  Ok(
+    (0..count)
+      .map(|_| {
+        let mut data = BUFFER_POOL.checkout(len);
+        data.resize(len, 1);
+        Buffer::from(data)
+      })
+      .collect(),
+  )
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("benchCreateBuffer", bench_create_buffer)?;
+  exports.create_named_method("benchCreateSmallBuffers", bench_create_small_buffers)?;
+  exports.create_named_method(
+    "benchCreateSmallBuffersPooled",
+    bench_create_small_buffers_pooled,
+  )?;
+  exports.create_named_method("benchCreateLargeBufferCopy", bench_create_large_buffer_copy)?;
+  exports.create_named_method(
+    "benchCreateLargeBufferZeroCopy",
+    bench_create_large_buffer_zero_copy,
+  )?;
+  exports.create_named_method("benchCreateBuffers", bench_create_buffers)?;
+  exports.create_named_method("benchCreateBuffersFromPool", bench_create_buffers_from_pool)?;
   Ok(())
 }
@@ -1,9 +1,12 @@
-use napi::{ContextlessResult, Env, JsObject, Result, Unknown};
+use napi::{
+  bindgen_prelude::Array, CallContext, ContextlessResult, Env, JsObject, Result, Unknown,
+};
 use serde_json::to_string;
 
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("createArrayJson", create_array_json)?;
   exports.create_named_method("createArray", create_array)?;
+  exports.create_named_method("createArrayFrom", create_array_from)?;
   exports.create_named_method("createArrayWithSerdeTrait", create_array_with_serde_trait)?;
   Ok(())
 }
@@ -25,6 +28,12 @@ pub fn create_array(env: Env) -> ContextlessResult<JsObject> {
   Ok(Some(ret))
 }
 
+#[js_function]
+pub fn create_array_from(ctx: CallContext) -> Result<Array> {
+  let a: Vec<u32> = vec![42; 1000];
+  ctx.env.create_array_from(a)
+}
+
 #[contextless_function]
 pub fn create_array_with_serde_trait(env: Env) -> ContextlessResult<Unknown<'static>> {
   let a: Vec<u32> = vec![42; 1000];
@@ -1,10 +1,14 @@
-use napi::{bindgen_prelude::Object, CallContext, JsObject, JsString, Result, Unknown};
+use napi::{
+  bindgen_prelude::{Array, Object},
+  CallContext, JsObject, JsString, Result, Unknown,
+};
 use serde_json::from_str;
 
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("getArrayFromJson", get_array_from_json)?;
   exports.create_named_method("getArrayFromJsArray", get_array_from_js_array)?;
   exports.create_named_method("getArrayWithForLoop", get_array_with_for_loop)?;
+  exports.create_named_method("getArrayWithToVec", get_array_with_to_vec)?;
   Ok(())
 }
 
@@ -32,3 +36,10 @@ fn get_array_with_for_loop(ctx: CallContext) -> Result<()> {
   }
   Ok(())
 }
+
+#[js_function(1)]
+fn get_array_with_to_vec(ctx: CallContext) -> Result<()> {
+  let input = ctx.get::<Array>(0)?;
+  let _: Vec<u32> = input.to_vec_with()?;
+  Ok(())
+}
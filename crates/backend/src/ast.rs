@@ -16,6 +16,8 @@ pub struct NapiFn {
   pub kind: FnKind,
   pub vis: syn::Visibility,
   pub parent: Option<Ident>,
+  /// The JavaScript name of the class, only recorded for the constructor
+  pub parent_js_name: Option<String>,
   pub strict: bool,
   pub return_if_invalid: bool,
   pub js_mod: Option<String>,
@@ -30,6 +32,11 @@ pub struct NapiFn {
   pub enumerable: bool,
   pub configurable: bool,
   pub catch_unwind: bool,
+  pub lazy: bool,
+  /// Cache the value returned by the getter on the instance, `#[napi(getter, cache)]`
+  pub cache: bool,
+  /// Return the `&[u8]` borrowed from `&self` as a `Buffer` without copying, `#[napi(zero_copy)]`
+  pub zero_copy: bool,
   pub unsafe_: bool,
   pub register_name: Ident,
 }
@@ -45,6 +52,8 @@ pub struct CallbackArg {
 pub struct NapiFnArg {
   pub kind: NapiFnArgKind,
   pub ts_arg_type: Option<String>,
+  /// `#[napi(rest)]`, the `Vec<T>` collects all the remaining arguments
+  pub rest: bool,
 }
 
 impl NapiFnArg {
@@ -111,6 +120,8 @@ pub struct NapiClass {
   pub implement_iterator: bool,
   pub is_tuple: bool,
   pub use_custom_finalize: bool,
+  pub use_async_finalize: bool,
+  pub use_custom_inspect: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -219,6 +230,7 @@ pub struct NapiConst {
   pub js_mod: Option<String>,
   pub comments: Vec<String>,
   pub skip_typescript: bool,
+  pub lazy: bool,
   pub register_name: Ident,
 }
 
@@ -26,6 +26,11 @@ impl NapiConst {
       self.name.span(),
     );
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let register_module_export = if self.lazy {
+      quote! { register_lazy_module_export }
+    } else {
+      quote! { register_module_export }
+    };
 
     quote! {
       #[allow(non_snake_case)]
@@ -38,7 +43,7 @@ impl NapiConst {
       #[cfg(all(not(test), not(target_family = "wasm")))]
       #[napi::ctor::ctor(crate_path=::napi::ctor)]
       fn #register_name() {
-        napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #cb_name);
+        napi::bindgen_prelude::#register_module_export(#js_mod_ident, #js_name_lit, #cb_name);
       }
 
       #[allow(non_snake_case)]
@@ -46,7 +51,7 @@ impl NapiConst {
       #[cfg(all(not(test), target_family = "wasm"))]
       #[no_mangle]
       unsafe extern "C" fn #register_name() {
-        napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #cb_name);
+        napi::bindgen_prelude::#register_module_export(#js_mod_ident, #js_name_lit, #cb_name);
       }
     }
   }
@@ -1,11 +1,11 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
-use syn::{spanned::Spanned, Type, TypePath};
+use syn::{spanned::Spanned, GenericArgument, PathArguments, Type, TypePath};
 
 use crate::{
   codegen::{get_intermediate_ident, js_mod_to_token_stream},
-  BindgenResult, CallbackArg, Diagnostic, FnKind, FnSelf, NapiFn, NapiFnArgKind, TryToTokens,
-  TYPEDARRAY_SLICE_TYPES,
+  impl_stream_item, BindgenResult, CallbackArg, Diagnostic, FnKind, FnSelf, NapiFn, NapiFnArgKind,
+  TryToTokens, TYPEDARRAY_SLICE_TYPES,
 };
 
 impl TryToTokens for NapiFn {
@@ -20,6 +20,7 @@ impl TryToTokens for NapiFn {
       refs,
       mut_ref_spans,
       unsafe_,
+      abortable,
     } = self.gen_arg_conversions()?;
     let attrs = &self.attrs;
     let arg_ref_count = refs.len();
@@ -135,11 +136,32 @@ impl TryToTokens for NapiFn {
         }
       }
     } else {
-      let call = if self.is_ret_result {
+      // the future is dropped once the `AbortSignal` is aborted, rather than run to completion in the background
+      let abortable_call = quote! {
+        match __abort_handle {
+          Some(handle) => handle.abortable(#receiver(#(#arg_names),*)).await,
+          None => Ok(#receiver(#(#arg_names),*).await),
+        }
+      };
+      let call = if abortable && self.is_ret_result {
+        quote! {
+          match #abortable_call {
+            Ok(ret) => ret.map_err(Into::<napi::Error>::into),
+            Err(err) => Err(err),
+          }
+        }
+      } else if abortable {
+        abortable_call
+      } else if self.is_ret_result {
         quote! { #receiver(#(#arg_names),*).await }
       } else {
         let ret_type = if let Some(t) = &self.ret {
-          quote! { #t }
+          // `impl Trait` is not allowed in the turbofish
+          if impl_stream_item(t).is_some() {
+            quote! { _ }
+          } else {
+            quote! { #t }
+          }
         } else {
           quote! { () }
         };
@@ -182,34 +204,44 @@ impl TryToTokens for NapiFn {
       } else {
         quote! { return std::ptr::null_mut(); }
       };
+      let class_js_name = self.parent_js_name.as_deref().unwrap_or(&self.js_name);
       quote! {
         // constructor function is called from class `factory`
         // so we should skip the original `constructor` logic
         if napi::__private::___CALL_FROM_FACTORY.with(|inner| inner.load(std::sync::atomic::Ordering::Relaxed)) {
             #return_from_factory
         }
+        // the `TypeError` is thrown if it's called without `new`
+        if !napi::__private::check_new_target(env, cb, #class_js_name) {
+            #return_from_factory
+        }
         #function_call_inner
       }
     } else {
       function_call_inner
     };
 
+    let function_call = if self.cache {
+      // the description of the `Symbol` key, like `Chart.options`
+      let cache_key = match &self.parent {
+        Some(parent) => format!("{}.{}\0", parent, self.js_name),
+        None => format!("{}\0", self.js_name),
+      };
+      quote! {
+        napi::__private::get_cached_getter_value(env, cb, #cache_key).and_then(|(this, cached)| match cached {
+          Some(cached) => Ok(cached),
+          None => #function_call.and_then(|value| napi::__private::set_cached_getter_value(env, this, #cache_key, value)),
+        })
+      }
+    } else {
+      function_call
+    };
+
     let function_call = if self.catch_unwind {
       quote! {
         {
-          std::panic::catch_unwind(|| { #function_call })
-            .map_err(|e| {
-              let message = {
-                if let Some(string) = e.downcast_ref::<String>() {
-                  string.clone()
-                } else if let Some(string) = e.downcast_ref::<&str>() {
-                  string.to_string()
-                } else {
-                  format!("panic from Rust code: {:?}", e)
-                }
-              };
-              napi::Error::new(napi::Status::GenericFailure, message)
-            })
+          std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| { #function_call }))
+            .map_err(napi::__private::panic_to_error)
             .and_then(|r| r)
         }
       }
@@ -250,6 +282,7 @@ impl NapiFn {
     let mut args = vec![];
     let mut refs = vec![];
     let mut mut_ref_spans = vec![];
+    let mut abortable = false;
 
     // fetch this
     if let Some(parent) = &self.parent {
@@ -278,6 +311,18 @@ impl NapiFn {
       let ident = Ident::new(&format!("arg{}", i), Span::call_site());
 
       match &arg.kind {
+        NapiFnArgKind::PatType(path) if arg.rest => {
+          let mut ty = *path.ty.clone();
+          hidden_ty_lifetime(&mut ty)?;
+          arg_conversions.push(quote! {
+            let #ident: #ty = cb
+              .get_rest_args(#i)?
+              .into_iter()
+              .map(|arg| napi::bindgen_prelude::FromNapiValue::from_napi_value(env, arg))
+              .collect::<napi::Result<_>>()?;
+          });
+          args.push(quote! { #ident });
+        }
         NapiFnArgKind::PatType(path) => {
           if &path.ty.to_token_stream().to_string() == "Env" {
             args.push(quote! { __wrapped_env });
@@ -394,6 +439,17 @@ impl NapiFn {
               continue;
             }
             arg_conversions.push(arg_conversion);
+            if self.is_async && !abortable {
+              if is_abort_signal(&path.ty) {
+                arg_conversions.push(quote! { let __abort_handle = Some(#ident.handle()); });
+                abortable = true;
+              } else if is_optional_abort_signal(&path.ty) {
+                arg_conversions.push(
+                  quote! { let __abort_handle = #ident.as_ref().map(|signal| signal.handle()); },
+                );
+                abortable = true;
+              }
+            }
             args.push(quote! { #ident });
           }
         }
@@ -410,6 +466,7 @@ impl NapiFn {
       refs,
       mut_ref_spans,
       unsafe_: self.unsafe_,
+      abortable,
     })
   }
 
@@ -632,6 +689,26 @@ impl NapiFn {
     if let Some(ty) = &self.ret {
       let ty_string = ty.into_token_stream().to_string();
       let is_return_self = ty_string == "& Self" || ty_string == "&mut Self";
+      // `#[napi(zero_copy)]` returns the `&[u8]` borrowed from `&self` as a `Buffer` which keeps the instance alive
+      let is_return_instance_data = self.zero_copy;
+      if impl_stream_item(ty).is_some() && self.kind == FnKind::Normal {
+        // `impl Stream` is returned as an async iterator
+        return if self.is_ret_result && !self.is_async {
+          Ok(quote! {
+            match #ret {
+              Ok(value) => napi::bindgen_prelude::ToNapiValue::to_napi_value(env, napi::bindgen_prelude::AsyncStream::new(value)),
+              Err(err) => {
+                napi::bindgen_prelude::AsJsError::into_js_error(err).throw_into(env);
+                Ok(std::ptr::null_mut())
+              },
+            }
+          })
+        } else {
+          Ok(quote! {
+            napi::bindgen_prelude::ToNapiValue::to_napi_value(env, napi::bindgen_prelude::AsyncStream::new(#ret))
+          })
+        };
+      }
       if self.kind == FnKind::Constructor {
         let parent = self
           .parent
@@ -647,7 +724,7 @@ impl NapiFn {
                   cb.construct::<false, _>(#js_name, value)
                 }
                 Err(err) => {
-                  napi::bindgen_prelude::JsError::from(err).throw_into(env);
+                  napi::bindgen_prelude::AsJsError::into_js_error(err).throw_into(env);
                   Ok(std::ptr::null_mut())
                 }
               }
@@ -671,7 +748,7 @@ impl NapiFn {
                   cb.factory(#js_name, value)
                 }
                 Err(err) => {
-                  napi::bindgen_prelude::JsError::from(err).throw_into(env);
+                  napi::bindgen_prelude::AsJsError::into_js_error(err).throw_into(env);
                   Ok(std::ptr::null_mut())
                 }
               }
@@ -689,12 +766,24 @@ impl NapiFn {
           })
         } else if is_return_self {
           Ok(quote! { #ret.map(|_| cb.this) })
+        } else if is_return_instance_data {
+          Ok(quote! {
+            match #ret {
+              // SAFETY: the `#[napi(zero_copy)]` method is `unsafe`, it guarantees that the data lives as long as the instance
+              Ok(value) => unsafe { napi::bindgen_prelude::BufferSlice::from_instance_data(&napi::bindgen_prelude::Env::from(env), cb.this, value) }
+                .and_then(|buf| napi::bindgen_prelude::ToNapiValue::to_napi_value(env, &buf)),
+              Err(err) => {
+                napi::bindgen_prelude::AsJsError::into_js_error(err).throw_into(env);
+                Ok(std::ptr::null_mut())
+              },
+            }
+          })
         } else {
           Ok(quote! {
             match #ret {
               Ok(value) => napi::bindgen_prelude::ToNapiValue::to_napi_value(env, value),
               Err(err) => {
-                napi::bindgen_prelude::JsError::from(err).throw_into(env);
+                napi::bindgen_prelude::AsJsError::into_js_error(err).throw_into(env);
                 Ok(std::ptr::null_mut())
               },
             }
@@ -702,6 +791,12 @@ impl NapiFn {
         }
       } else if is_return_self {
         Ok(quote! { Ok(cb.this) })
+      } else if is_return_instance_data {
+        Ok(quote! {
+          // SAFETY: the `#[napi(zero_copy)]` method is `unsafe`, it guarantees that the data lives as long as the instance
+          unsafe { napi::bindgen_prelude::BufferSlice::from_instance_data(&napi::bindgen_prelude::Env::from(env), cb.this, #ret) }
+            .and_then(|buf| napi::bindgen_prelude::ToNapiValue::to_napi_value(env, &buf))
+        })
       } else {
         let mut return_ty = ty.clone();
         hidden_ty_lifetime(&mut return_ty)?;
@@ -727,6 +822,11 @@ impl NapiFn {
       let intermediate_ident = get_intermediate_ident(&name_str);
       let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
       let cb_name = Ident::new(&format!("{}_js_function", name_str), Span::call_site());
+      let register_module_export = if self.lazy {
+        quote! { register_lazy_module_export }
+      } else {
+        quote! { register_module_export }
+      };
 
       if self.module_exports {
         return quote! {
@@ -782,7 +882,7 @@ impl NapiFn {
         #[cfg(all(not(test), not(target_family = "wasm")))]
         #[napi::ctor::ctor(crate_path=::napi::ctor)]
         fn #module_register_name() {
-          napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name, #cb_name);
+          napi::bindgen_prelude::#register_module_export(#js_mod_ident, #js_name, #cb_name);
         }
 
         #[allow(clippy::all)]
@@ -790,7 +890,7 @@ impl NapiFn {
         #[cfg(all(not(test), target_family = "wasm"))]
         #[no_mangle]
         extern "C" fn #module_register_name() {
-          napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name, #cb_name);
+          napi::bindgen_prelude::#register_module_export(#js_mod_ident, #js_name, #cb_name);
         }
       }
     }
@@ -809,12 +909,14 @@ fn hidden_ty_lifetime(ty: &mut syn::Type) -> BindgenResult<()> {
       ..
     }) = segments.last_mut()
     {
-      if let Some(syn::GenericArgument::Lifetime(lt)) = args.first_mut() {
-        *lt = syn::Lifetime::new("'_", Span::call_site());
-      }
-      for arg in args.iter_mut().skip(1) {
-        if let syn::GenericArgument::Type(ty) = arg {
-          hidden_ty_lifetime(ty)?;
+      // the lifetimes can be nested, like `Option<ClassInstance<'env, T>>`
+      for arg in args.iter_mut() {
+        match arg {
+          syn::GenericArgument::Lifetime(lt) => {
+            *lt = syn::Lifetime::new("'_", Span::call_site());
+          }
+          syn::GenericArgument::Type(ty) => hidden_ty_lifetime(ty)?,
+          _ => {}
         }
       }
     }
@@ -832,12 +934,37 @@ fn make_ref(input: TokenStream) -> TokenStream {
   }
 }
 
+fn is_abort_signal(ty: &Type) -> bool {
+  matches!(
+    ty,
+    Type::Path(TypePath { path, .. })
+      if path.segments.last().is_some_and(|segment| segment.ident == "AbortSignal")
+  )
+}
+
+fn is_optional_abort_signal(ty: &Type) -> bool {
+  match ty {
+    Type::Path(TypePath { path, .. }) => path.segments.last().is_some_and(|segment| {
+      segment.ident == "Option"
+        && matches!(
+          &segment.arguments,
+          PathArguments::AngleBracketed(args)
+            if matches!(args.args.first(), Some(GenericArgument::Type(ty)) if is_abort_signal(ty))
+        )
+    }),
+    _ => false,
+  }
+}
+
 struct ArgConversions {
   pub args: Vec<TokenStream>,
   pub arg_conversions: Vec<TokenStream>,
   pub refs: Vec<TokenStream>,
   pub mut_ref_spans: Vec<Span>,
   pub unsafe_: bool,
+  /// The async fn has an `AbortSignal` or `Option<AbortSignal>` argument,
+  /// its `Option<AbortHandle>` `__abort_handle` is created in the `arg_conversions`
+  pub abortable: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
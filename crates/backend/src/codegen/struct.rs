@@ -202,6 +202,11 @@ impl NapiStruct {
     let mut getters_setters = self.gen_default_getters_setters(class);
     getters_setters.sort_by(|a, b| a.0.cmp(&b.0));
     let register = self.gen_register(class);
+    let custom_inspect = if class.use_custom_inspect {
+      self.gen_custom_inspect()
+    } else {
+      quote! {}
+    };
 
     let getters_setters_token = getters_setters.into_iter().map(|(_, token)| token);
 
@@ -214,11 +219,32 @@ impl NapiStruct {
 
         #ctor
         #(#getters_setters_token)*
+        #custom_inspect
         #register
       }
     }
   }
 
+  fn gen_custom_inspect(&self) -> TokenStream {
+    let struct_name = &self.name;
+    quote! {
+      extern "C" fn __napi_custom_inspect(
+        env: napi::bindgen_prelude::sys::napi_env,
+        cb: napi::bindgen_prelude::sys::napi_callback_info
+      ) -> napi::bindgen_prelude::sys::napi_value {
+        napi::bindgen_prelude::CallbackInfo::<0>::new(env, cb, None, false)
+          .and_then(|mut cb| cb.unwrap_borrow::<#struct_name>())
+          .and_then(|obj| unsafe {
+            napi::bindgen_prelude::ToNapiValue::to_napi_value(env, #struct_name::inspect(obj))
+          })
+          .unwrap_or_else(|e| {
+            unsafe { napi::bindgen_prelude::JsError::from(e).throw_into(env) };
+            std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
+          })
+      }
+    }
+  }
+
   fn gen_default_ctor(&self, class: &NapiClass) -> TokenStream {
     let name = &self.name;
     let js_name_str = &self.js_name;
@@ -255,6 +281,9 @@ impl NapiStruct {
         env: napi::bindgen_prelude::sys::napi_env,
         cb: napi::bindgen_prelude::sys::napi_callback_info
       ) -> napi::bindgen_prelude::sys::napi_value {
+        if !unsafe { napi::__private::check_new_target(env, cb, #js_name_str) } {
+          return std::ptr::null_mut();
+        }
         napi::bindgen_prelude::CallbackInfo::<#fields_len>::new(env, cb, None, false)
           .and_then(|cb| #constructor)
           .unwrap_or_else(|e| {
@@ -294,29 +323,19 @@ impl NapiStruct {
     let js_name_raw = &self.js_name;
     let js_name_str = format!("{}\0", js_name_raw);
     let iterator_implementation = self.gen_iterator_property(class, name);
-    let (object_finalize_impl, to_napi_value_impl, javascript_class_ext_impl) = if self.has_lifetime
-    {
+    let (to_napi_value_impl, javascript_class_ext_impl) = if self.has_lifetime {
       let name = quote! { #name<'_javascript_function_scope> };
       (
-        quote! { impl <'_javascript_function_scope> napi::bindgen_prelude::ObjectFinalize for #name {} },
         quote! { impl <'_javascript_function_scope> napi::bindgen_prelude::ToNapiValue for #name },
         quote! { impl <'_javascript_function_scope> napi::bindgen_prelude::JavaScriptClassExt for #name },
       )
     } else {
       (
-        quote! { impl napi::bindgen_prelude::ObjectFinalize for #name {} },
         quote! { impl napi::bindgen_prelude::ToNapiValue for #name },
         quote! { impl napi::bindgen_prelude::JavaScriptClassExt for #name },
       )
     };
-    let finalize_trait = if class.use_custom_finalize {
-      quote! {}
-    } else {
-      quote! {
-        #[automatically_derived]
-        #object_finalize_impl
-      }
-    };
+    let finalize_trait = self.gen_object_finalize_impl(class);
     quote! {
       #[automatically_derived]
       #to_napi_value_impl {
@@ -325,7 +344,7 @@ impl NapiStruct {
           val: #name
         ) -> napi::Result<napi::bindgen_prelude::sys::napi_value> {
           if let Some(ctor_ref) = napi::__private::get_class_constructor(#js_name_str) {
-            let mut wrapped_value = Box::into_raw(Box::new(val));
+            let mut wrapped_value = napi::__private::alloc_class_instance(val);
             if wrapped_value as usize == 0x1 {
               wrapped_value = Box::into_raw(Box::new(0u8)).cast();
             }
@@ -348,7 +367,7 @@ impl NapiStruct {
          {
           if let Some(ctor_ref) = napi::bindgen_prelude::get_class_constructor(#js_name_str) {
             unsafe {
-              let wrapped_value = Box::into_raw(Box::new(self));
+              let wrapped_value = napi::__private::alloc_class_instance(self);
               let instance_value = napi::bindgen_prelude::new_instance::<#name>(env.raw(), wrapped_value as *mut _ as *mut std::ffi::c_void, ctor_ref)?;
               Ok(napi::bindgen_prelude::ClassInstance::new(instance_value, env.raw(), wrapped_value))
             }
@@ -362,7 +381,7 @@ impl NapiStruct {
         fn into_reference(self, env: napi::Env) -> napi::Result<napi::bindgen_prelude::Reference<Self>> {
           if let Some(ctor_ref) = napi::bindgen_prelude::get_class_constructor(#js_name_str) {
             unsafe {
-              let mut wrapped_value = Box::into_raw(Box::new(self));
+              let mut wrapped_value = napi::__private::alloc_class_instance(self);
               if wrapped_value as usize == 0x1 {
                 wrapped_value = Box::into_raw(Box::new(0u8)).cast();
               }
@@ -403,6 +422,36 @@ impl NapiStruct {
     }
   }
 
+  fn gen_object_finalize_impl(&self, class: &NapiClass) -> TokenStream {
+    if class.use_custom_finalize {
+      return quote! {};
+    }
+    let name = &self.name;
+    let (impl_generics, name) = if self.has_lifetime {
+      (
+        quote! { <'_javascript_function_scope> },
+        quote! { #name<'_javascript_function_scope> },
+      )
+    } else {
+      (quote! {}, quote! { #name })
+    };
+    let finalize_fn = if class.use_async_finalize {
+      quote! {
+        fn finalize(self, env: napi::bindgen_prelude::Env) -> napi::bindgen_prelude::Result<()> {
+          napi::bindgen_prelude::spawn_finalize_async(self, env)
+        }
+      }
+    } else {
+      quote! {}
+    };
+    quote! {
+      #[automatically_derived]
+      impl #impl_generics napi::bindgen_prelude::ObjectFinalize for #name {
+        #finalize_fn
+      }
+    }
+  }
+
   fn gen_iterator_property(&self, class: &NapiClass, name: &Ident) -> TokenStream {
     if !class.implement_iterator {
       return quote! {};
@@ -452,13 +501,7 @@ impl NapiStruct {
       }
     };
 
-    let finalize_trait = if class.use_custom_finalize {
-      quote! {}
-    } else if self.has_lifetime {
-      quote! { impl <'_javascript_function_scope> napi::bindgen_prelude::ObjectFinalize for #name<'_javascript_function_scope> {} }
-    } else {
-      quote! { impl napi::bindgen_prelude::ObjectFinalize for #name {} }
-    };
+    let finalize_trait = self.gen_object_finalize_impl(class);
 
     let to_napi_value_impl = if self.has_lifetime {
       quote! { impl <'_javascript_function_scope> napi::bindgen_prelude::ToNapiValue for #name<'_javascript_function_scope> }
@@ -864,6 +907,16 @@ impl NapiStruct {
 
       props.push(prop);
     }
+
+    if class.use_custom_inspect {
+      let attribute = super::PROPERTY_ATTRIBUTE_WRITABLE | super::PROPERTY_ATTRIBUTE_CONFIGURABLE;
+      props.push(quote! {
+        napi::bindgen_prelude::Property::new()
+          .with_symbol_for_name("nodejs.util.inspect.custom")
+          .with_method(__napi_custom_inspect)
+          .with_property_attributes(napi::bindgen_prelude::PropertyAttributes::from_bits(#attribute).unwrap())
+      });
+    }
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
     quote! {
       #[allow(non_snake_case)]
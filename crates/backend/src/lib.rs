@@ -102,6 +102,8 @@ pub(crate) static PRIMITIVE_TYPES: &[(&str, (&str, bool, bool))] = &[
   ("str", ("string", false, false)),
   ("Latin1String", ("string", false, false)),
   ("Utf16String", ("string", false, false)),
+  ("Latin1Bytes", ("string", false, false)),
+  ("Utf8Bytes", ("string", false, false)),
   ("char", ("string", false, false)),
   ("Null", ("null", false, false)),
   ("JsNull", ("null", false, false)),
@@ -125,3 +127,26 @@ pub(crate) static TYPEDARRAY_SLICE_TYPES: LazyLock<HashMap<&str, &str>> = LazyLo
     ("i64", "BigInt64Array"),
   ])
 });
+
+/// Returns the `Item` type of `impl Stream<Item = ...>`, which is returned to JavaScript as an async iterator
+pub(crate) fn impl_stream_item(ty: &syn::Type) -> Option<&syn::Type> {
+  let syn::Type::ImplTrait(impl_trait) = ty else {
+    return None;
+  };
+  impl_trait.bounds.iter().find_map(|bound| {
+    let syn::TypeParamBound::Trait(trait_bound) = bound else {
+      return None;
+    };
+    let segment = trait_bound.path.segments.last()?;
+    if segment.ident != "Stream" {
+      return None;
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+      return None;
+    };
+    arguments.args.iter().find_map(|arg| match arg {
+      syn::GenericArgument::AssocType(assoc) if assoc.ident == "Item" => Some(&assoc.ty),
+      _ => None,
+    })
+  })
+}
@@ -141,8 +141,11 @@ static KNOWN_TYPES: LazyLock<HashMap<&'static str, (&'static str, bool, bool)>>
     ("IndexMap", ("Record<{}, {}>", false, false)),
     ("HashSet", ("Set<{}>", false, false)),
     ("BTreeSet", ("Set<{}>", false, false)),
+    ("JsMap", ("Map<any, any>", false, false)),
+    ("JsSet", ("Set<any>", false, false)),
     ("ArrayBuffer", ("ArrayBuffer", false, false)),
     ("JsArrayBuffer", ("ArrayBuffer", false, false)),
+    ("SharedArrayBuffer", ("SharedArrayBuffer", false, false)),
     ("Int8Array", ("Int8Array", false, false)),
     ("Uint8Array", ("Uint8Array", false, false)),
     ("Uint8ClampedArray", ("Uint8ClampedArray", false, false)),
@@ -156,6 +159,7 @@ static KNOWN_TYPES: LazyLock<HashMap<&'static str, (&'static str, bool, bool)>>
     ("BigInt64Array", ("BigInt64Array", false, false)),
     ("BigUint64Array", ("BigUint64Array", false, false)),
     ("DataView", ("DataView", false, false)),
+    ("JsDataView", ("DataView", false, false)),
     ("DateTime", ("Date", false, false)),
     ("NaiveDateTime", ("Date", false ,false)),
     ("Date", ("Date", false, false)),
@@ -173,6 +177,7 @@ static KNOWN_TYPES: LazyLock<HashMap<&'static str, (&'static str, bool, bool)>>
     ("Function", ("({}) => {}", true, false)),
     ("FunctionRef", ("({}) => {}", true, false)),
     ("ReadableStream", ("ReadableStream<{}>", false, false)),
+    ("IteratorGenerator", ("IterableIterator<{}>", false, false)),
     ("Either", ("{} | {}", false, true)),
     ("Either3", ("{} | {} | {}", false, true)),
     ("Either4", ("{} | {} | {} | {}", false, true)),
@@ -253,12 +258,14 @@ fn is_ts_union_type(rust_ty: &str) -> bool {
 }
 
 const TSFN_RUST_TY: &str = "ThreadsafeFunction";
+const COALESCING_TSFN_RUST_TY: &str = "CoalescingThreadsafeFunction";
 const FUNCTION_TY: &str = "Function";
 const FUNCTION_ARG_TY: &str = "FnArgs";
 const FUNCTION_REF_TY: &str = "FunctionRef";
 
 fn is_generic_function_type(rust_ty: &str) -> bool {
   rust_ty == TSFN_RUST_TY
+    || rust_ty == COALESCING_TSFN_RUST_TY
     || rust_ty == FUNCTION_TY
     || rust_ty == FUNCTION_ARG_TY
     || rust_ty == FUNCTION_REF_TY
@@ -496,6 +503,13 @@ pub fn ty_to_ts_type(
               false,
             ))
           }
+        } else if rust_ty == COALESCING_TSFN_RUST_TY {
+          let fn_args = args.first().map(|(arg, _)| arg).unwrap();
+          let return_ty = args
+            .get(1)
+            .map(|(ty, _)| ty.clone())
+            .unwrap_or("any".to_owned());
+          Some((format!("(({fn_args}) => {return_ty})"), false))
         } else {
           // there should be runtime registered type in else
           let type_alias = ALIAS.with(|aliases| {
@@ -537,6 +551,15 @@ pub fn ty_to_ts_type(
       )
     }
     Type::Group(g) => ty_to_ts_type(&g.elem, is_return_ty, is_struct_field, false),
+    Type::ImplTrait(_) => {
+      if let Some(item) = crate::impl_stream_item(ty) {
+        // the `Err` of `Result` items rejects the `next()` promise
+        let (item_type, _) = ty_to_ts_type(item, true, false, false);
+        (format!("AsyncIterableIterator<{}>", item_type), false)
+      } else {
+        ("any".to_owned(), false)
+      }
+    }
     Type::Array(a) => {
       let (element_type, is_optional) =
         ty_to_ts_type(&a.elem, is_return_ty, is_struct_field, false);
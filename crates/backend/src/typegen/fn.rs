@@ -31,7 +31,8 @@ impl Display for FnArgList {
         && self
           .last_required
           .map_or(true, |last_required| i > last_required);
-      if is_optional {
+      // the rest parameter `...args` can be omitted but can't be marked with `?`
+      if is_optional && !arg.arg.starts_with("...") {
         write!(f, "{}?: {}", arg.arg, arg.ts_type)?;
       } else {
         write!(f, "{}: {}", arg.arg, arg.ts_type)?;
@@ -105,6 +106,24 @@ impl ToTypeDef for NapiFn {
   }
 }
 
+impl NapiFn {
+  /// The getter as a `readonly` property like `readonly name: string`, `None` if the whole type is overridden with `ts_type`
+  pub(crate) fn to_readonly_property_def(&self) -> Option<String> {
+    if self.ts_type.is_some() {
+      return None;
+    }
+    Some(format!(
+      "readonly {name}{ret}",
+      name = self.js_name,
+      ret = self
+        .ts_return_type
+        .clone()
+        .map(|t| format!(": {}", t))
+        .unwrap_or_else(|| self.gen_ts_func_ret()),
+    ))
+  }
+}
+
 fn gen_callback_type(callback: &CallbackArg) -> String {
   format!(
     "({args}) => {ret}",
@@ -258,6 +277,13 @@ impl NapiFn {
 
             let (ts_type, is_optional) = ty_to_ts_type(&path.ty, false, false, false);
             let ts_type = arg.use_overridden_type_or(|| ts_type);
+            if arg.rest {
+              return Some(FnArg {
+                arg: format!("...{}", gen_ts_func_arg(&path.pat)),
+                ts_type,
+                is_optional: true,
+              });
+            }
             let arg = gen_ts_func_arg(&path.pat);
             Some(FnArg {
               arg,
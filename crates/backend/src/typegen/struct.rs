@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::{cell::RefCell, iter};
 
 use super::{add_alias, ToTypeDef, TypeDef};
 use crate::{
-  js_doc_from_comments, ty_to_ts_type, NapiImpl, NapiStruct, NapiStructField, NapiStructKind,
+  js_doc_from_comments, ty_to_ts_type, FnKind, NapiImpl, NapiStruct, NapiStructField,
+  NapiStructKind,
 };
 
 thread_local! {
@@ -79,6 +80,12 @@ impl ToTypeDef for NapiImpl {
         js_doc: "".to_string(),
       })
     } else {
+      let setters = self
+        .items
+        .iter()
+        .filter(|f| f.kind == FnKind::Setter)
+        .map(|f| f.js_name.as_str())
+        .collect::<HashSet<_>>();
       Some(TypeDef {
         kind: "impl".to_owned(),
         name: self.js_name.to_owned(),
@@ -90,11 +97,19 @@ impl ToTypeDef for NapiImpl {
             if f.skip_typescript {
               None
             } else {
+              // the getter without a setter is a `readonly` property
+              let readonly_def =
+                if f.kind == FnKind::Getter && !setters.contains(f.js_name.as_str()) {
+                  f.to_readonly_property_def()
+                } else {
+                  None
+                };
               Some(format!(
                 "{}{}",
                 js_doc_from_comments(&f.comments),
-                f.to_type_def()
-                  .map_or(String::default(), |type_def| type_def.def)
+                readonly_def.unwrap_or_else(|| f
+                  .to_type_def()
+                  .map_or(String::default(), |type_def| type_def.def))
               ))
             }
           })
@@ -51,6 +51,8 @@ macro_rules! attrgen {
   ($mac:ident) => {
     $mac! {
       (catch_unwind, CatchUnwind(Span)),
+      (no_catch_unwind, NoCatchUnwind(Span)),
+      (lazy, Lazy(Span)),
       (async_runtime, AsyncRuntime(Span)),
       (module_exports, ModuleExports(Span)),
       (js_name, JsName(Span, String, Span)),
@@ -58,6 +60,8 @@ macro_rules! attrgen {
       (factory, Factory(Span)),
       (getter, Getter(Span, Option<Ident>)),
       (setter, Setter(Span, Option<Ident>)),
+      (cache, Cache(Span)),
+      (zero_copy, ZeroCopy(Span)),
       (readonly, Readonly(Span)),
       (enumerable, Enumerable(Span, Option<bool>), true),
       (writable, Writable(Span, Option<bool>), true),
@@ -69,6 +73,8 @@ macro_rules! attrgen {
       (object_from_js, ObjectFromJs(Span, Option<bool>), true),
       (object_to_js, ObjectToJs(Span, Option<bool>), true),
       (custom_finalize, CustomFinalize(Span)),
+      (async_finalize, AsyncFinalize(Span)),
+      (custom_inspect, CustomInspect(Span)),
       (namespace, Namespace(Span, String, Span)),
       (iterator, Iterator(Span)),
       (ts_args_type, TsArgsType(Span, String, Span)),
@@ -86,7 +86,7 @@ pub trait ParseNapi {
 
 /// This function does a few things:
 /// - parses the tokens for the given argument `p` to find the `#[napi(ts_arg_type = "MyType")]`
-///   attribute and return the manually overridden type.
+///   attribute and return the manually overridden type, and whether the argument is marked with `#[napi(rest)]`.
 /// - If both the `ts_args_type` override and the `ts_arg_type` override are present, bail
 ///   since it should only allow one at a time.
 /// - Bails if it finds the `#[napi...]` attribute but it has the wrong data.
@@ -95,26 +95,20 @@ pub trait ParseNapi {
 ///   `  `turns into
 ///   `pub fn add(u: u32, f: String)`
 ///   `  `otherwise it won't compile
-fn find_ts_arg_type_and_remove_attribute(
+fn find_arg_attrs_and_remove_attribute(
   p: &mut PatType,
   ts_args_type: Option<&(&str, Span)>,
-) -> BindgenResult<Option<String>> {
-  let mut ts_type_attr: Option<(usize, String)> = None;
+) -> BindgenResult<(Option<String>, bool)> {
+  let mut napi_attr_idx = None;
+  let mut ts_type_attr: Option<String> = None;
+  let mut rest = false;
   for (idx, attr) in p.attrs.iter().enumerate() {
     if attr.path().is_ident("napi") {
-      if let Some((ts_args_type, _)) = ts_args_type {
-        bail_span!(
-          attr,
-          "Found a 'ts_args_type'=\"{}\" override. Cannot use 'ts_arg_type' at the same time since they are mutually exclusive.",
-          ts_args_type
-        );
-      }
-
       match &attr.meta {
         syn::Meta::Path(_) | syn::Meta::NameValue(_) => {
           bail_span!(
             attr,
-            "Expects an assignment #[napi(ts_arg_type = \"MyType\")]"
+            "Expects an assignment #[napi(ts_arg_type = \"MyType\")] or #[napi(rest)]"
           )
         }
         syn::Meta::List(list) => {
@@ -127,7 +121,16 @@ fn find_ts_arg_type_and_remove_attribute(
               let list = tokens.parse_terminated(Meta::parse, Token![,])?;
 
               for meta in list {
-                if meta.path().is_ident("ts_arg_type") {
+                if meta.path().is_ident("rest") {
+                  if !matches!(meta, Meta::Path(_)) {
+                    return Err(syn::Error::new(
+                      meta.path().span(),
+                      "Expects a flag without value #[napi(rest)]",
+                    ));
+                  }
+                  found = true;
+                  rest = true;
+                } else if meta.path().is_ident("ts_arg_type") {
                   match meta {
                     Meta::Path(_) | Meta::List(_) => {
                       return Err(syn::Error::new(
@@ -142,7 +145,7 @@ fn find_ts_arg_type_and_remove_attribute(
                       }) => {
                         let value = str.value();
                         found = true;
-                        ts_type_attr = Some((idx, value));
+                        ts_type_attr = Some(value);
                       }
                       _ => {
                         return Err(syn::Error::new(
@@ -160,19 +163,25 @@ fn find_ts_arg_type_and_remove_attribute(
             .map_err(Diagnostic::from)?;
 
           if !found {
-            bail_span!(attr, "Expects a 'ts_arg_type'");
+            bail_span!(attr, "Expects a 'ts_arg_type' or 'rest'");
           }
+          if let (Some((ts_args_type, _)), Some(_)) = (ts_args_type, &ts_type_attr) {
+            bail_span!(
+              attr,
+              "Found a 'ts_args_type'=\"{}\" override. Cannot use 'ts_arg_type' at the same time since they are mutually exclusive.",
+              ts_args_type
+            );
+          }
+          napi_attr_idx = Some(idx);
         }
       }
     }
   }
 
-  if let Some((idx, value)) = ts_type_attr {
+  if let Some(idx) = napi_attr_idx {
     p.attrs.remove(idx);
-    Ok(Some(value))
-  } else {
-    Ok(None)
   }
+  Ok((ts_type_attr, rest))
 }
 
 fn find_enum_value_and_remove_attribute(v: &mut syn::Variant) -> BindgenResult<Option<String>> {
@@ -584,6 +593,13 @@ fn napi_fn_from_decl(
 ) -> BindgenResult<NapiFn> {
   let mut errors = vec![];
 
+  if opts.lazy().is_some() && (parent.is_some() || opts.module_exports().is_some()) {
+    bail_span!(
+      sig.ident,
+      "#[napi(lazy)] can only be applied to a function or const."
+    );
+  }
+
   let syn::Signature {
     ident,
     asyncness,
@@ -600,11 +616,13 @@ fn napi_fn_from_decl(
     .iter_mut()
     .filter_map(|arg| match arg {
       syn::FnArg::Typed(ref mut p) => {
-        let ts_arg_type = find_ts_arg_type_and_remove_attribute(p, opts.ts_args_type().as_ref())
-          .unwrap_or_else(|e| {
-            errors.push(e);
-            None
-          });
+        let (ts_arg_type, rest) =
+          find_arg_attrs_and_remove_attribute(p, opts.ts_args_type().as_ref()).unwrap_or_else(
+            |e| {
+              errors.push(e);
+              (None, false)
+            },
+          );
 
         let ty_str = p.ty.to_token_stream().to_string();
         if let Some(path_arguments) = callback_traits.get(&ty_str) {
@@ -616,6 +634,7 @@ fn napi_fn_from_decl(
                 ret: fn_ret,
               })),
               ts_arg_type,
+              rest,
             }),
             Err(e) => {
               errors.push(e);
@@ -628,6 +647,7 @@ fn napi_fn_from_decl(
           Some(NapiFnArg {
             kind: NapiFnArgKind::PatType(Box::new(p.clone())),
             ts_arg_type,
+            rest,
           })
         }
       }
@@ -652,6 +672,36 @@ fn napi_fn_from_decl(
     })
     .collect::<Vec<_>>();
 
+  for (i, arg) in args.iter().enumerate() {
+    if !arg.rest {
+      continue;
+    }
+    match &arg.kind {
+      NapiFnArgKind::PatType(pat) if i + 1 == args.len() => {
+        let is_vec = matches!(
+          pat.ty.as_ref(),
+          syn::Type::Path(syn::TypePath { path, .. })
+            if path.segments.last().is_some_and(|segment| segment.ident == "Vec")
+        );
+        if !is_vec {
+          errors.push(err_span!(
+            pat.ty,
+            "#[napi(rest)] argument must be a `Vec<T>`"
+          ));
+        } else if opts.strict().is_some() || opts.return_if_invalid().is_some() {
+          errors.push(err_span!(
+            pat.ty,
+            "#[napi(rest)] argument can't be used with strict or return_if_invalid"
+          ));
+        }
+      }
+      _ => errors.push(err_span!(
+        sig.ident,
+        "#[napi(rest)] can only be applied to the last argument"
+      )),
+    }
+  }
+
   let (ret, is_ret_result) = match output {
     syn::ReturnType::Default => (None, false),
     syn::ReturnType::Type(_, ty) => {
@@ -867,6 +917,40 @@ fn napi_fn_from_decl(
       bail_span!(sig.ident, "Constructor don't support asynchronous function");
     }
 
+    if opts.cache().is_some() {
+      if !matches!(kind, FnKind::Getter) || fn_self.is_none() {
+        bail_span!(
+          sig.ident,
+          "Only getter of the class instance can be marked as cache"
+        );
+      }
+      if asyncness.is_some() {
+        bail_span!(
+          sig.ident,
+          "Cached getter don't support asynchronous function"
+        );
+      }
+    }
+
+    if opts.zero_copy().is_some() {
+      if fn_self != Some(FnSelf::Ref)
+        || asyncness.is_some()
+        || ret.as_ref().map(|ty| ty.to_token_stream().to_string()) != Some("& [u8]".to_owned())
+      {
+        bail_span!(
+          sig.ident,
+          "#[napi(zero_copy)] can only be applied to a synchronous method returning `&[u8]` from `&self`"
+        );
+      }
+      // the returned data must outlive the call, which the compiler can't check
+      if sig.unsafety.is_none() {
+        bail_span!(
+          sig.ident,
+          "#[napi(zero_copy)] method must be marked as `unsafe`, the returned data must not be mutated, moved or freed while the instance is alive"
+        );
+      }
+    }
+
     Ok(NapiFn {
       name: ident.clone(),
       js_name,
@@ -880,6 +964,7 @@ fn napi_fn_from_decl(
       kind,
       fn_self,
       parent: parent.cloned(),
+      parent_js_name: None,
       comments: extract_doc_comments(&attrs),
       attrs,
       strict: opts.strict().is_some(),
@@ -894,7 +979,11 @@ fn napi_fn_from_decl(
       writable: opts.writable(),
       enumerable: opts.enumerable(),
       configurable: opts.configurable(),
-      catch_unwind: opts.catch_unwind().is_some(),
+      catch_unwind: opts.catch_unwind().is_some()
+        || (cfg!(feature = "catch-unwind") && opts.no_catch_unwind().is_none()),
+      lazy: opts.lazy().is_some(),
+      cache: opts.cache().is_some(),
+      zero_copy: opts.zero_copy().is_some(),
       unsafe_: sig.unsafety.is_some(),
       register_name: get_register_ident(ident.to_string().as_str()),
     })
@@ -964,9 +1053,27 @@ impl ParseNapi for syn::ItemStruct {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.lazy().is_some() {
+      bail_span!(
+        self,
+        "#[napi(lazy)] can only be applied to a function or const."
+      );
+    }
     if opts.object().is_some() && opts.custom_finalize().is_some() {
       bail_span!(self, "Custom finalize is not supported for #[napi(object)]");
     }
+    if opts.object().is_some() && opts.async_finalize().is_some() {
+      bail_span!(self, "Async finalize is not supported for #[napi(object)]");
+    }
+    if opts.object().is_some() && opts.custom_inspect().is_some() {
+      bail_span!(self, "Custom inspect is not supported for #[napi(object)]");
+    }
+    if opts.custom_finalize().is_some() && opts.async_finalize().is_some() {
+      bail_span!(
+        self,
+        "#[napi(custom_finalize)] and #[napi(async_finalize)] can't be used together"
+      );
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
@@ -999,6 +1106,12 @@ impl ParseNapi for syn::ItemImpl {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.lazy().is_some() {
+      bail_span!(
+        self,
+        "#[napi(lazy)] can only be applied to a function or const."
+      );
+    }
     // #[napi] macro will be remove from impl items after converted to ast
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
@@ -1031,6 +1144,12 @@ impl ParseNapi for syn::ItemEnum {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.lazy().is_some() {
+      bail_span!(
+        self,
+        "#[napi(lazy)] can only be applied to a function or const."
+      );
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
@@ -1090,6 +1209,12 @@ impl ParseNapi for syn::ItemType {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.lazy().is_some() {
+      bail_span!(
+        self,
+        "#[napi(lazy)] can only be applied to a function or const."
+      );
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
     napi
@@ -1311,6 +1436,8 @@ impl ConvertToAST for syn::ItemStruct {
         implement_iterator,
         is_tuple,
         use_custom_finalize: opts.custom_finalize().is_some(),
+        use_async_finalize: opts.async_finalize().is_some(),
+        use_custom_inspect: opts.custom_inspect().is_some(),
       })
     };
 
@@ -1427,13 +1554,16 @@ impl ConvertToAST for syn::ItemImpl {
           }
         }
 
-        let func = napi_fn_from_decl(
+        let mut func = napi_fn_from_decl(
           &mut method.sig,
           &opts,
           method.attrs.clone(),
           vis,
           Some(&struct_name),
         )?;
+        if opts.constructor().is_some() {
+          func.parent_js_name = Some(struct_js_name.clone());
+        }
 
         items.push(func);
       }
@@ -1654,6 +1784,7 @@ impl ConvertToAST for syn::ItemConst {
           js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
           comments: extract_doc_comments(&self.attrs),
           skip_typescript: opts.skip_typescript().is_some(),
+          lazy: opts.lazy().is_some(),
           register_name: get_register_ident(self.ident.to_string().as_str()),
         }),
       }),
@@ -7,7 +7,7 @@ use std::sync::atomic::{AtomicU8, Ordering};
 
 use crate::{
   bindgen_runtime::{PromiseRaw, ToNapiValue},
-  check_status, sys, Env, Error, JsError, Result, Status, Task,
+  check_status, sys, Env, Error, JsError, ProgressReporter, Result, Status, Task,
 };
 
 struct AsyncWork<T: Task> {
@@ -16,6 +16,7 @@ struct AsyncWork<T: Task> {
   value: mem::MaybeUninit<Result<T::Output>>,
   napi_async_work: sys::napi_async_work,
   status: Rc<AtomicU8>,
+  progress: Option<ProgressReporter>,
 }
 
 pub struct AsyncWorkPromise<T> {
@@ -49,6 +50,15 @@ pub fn run<T: Task>(
   env: sys::napi_env,
   task: T,
   abort_status: Option<Rc<AtomicU8>>,
+) -> Result<AsyncWorkPromise<T::JsValue>> {
+  run_with_progress(env, task, abort_status, None)
+}
+
+pub(crate) fn run_with_progress<T: Task>(
+  env: sys::napi_env,
+  task: T,
+  abort_status: Option<Rc<AtomicU8>>,
+  progress: Option<ProgressReporter>,
 ) -> Result<AsyncWorkPromise<T::JsValue>> {
   let mut undefined = ptr::null_mut();
   check_status!(
@@ -68,6 +78,7 @@ pub fn run<T: Task>(
     value: mem::MaybeUninit::uninit(),
     napi_async_work: ptr::null_mut(),
     status: task_status.clone(),
+    progress,
   }));
   check_status!(
     unsafe {
@@ -103,7 +114,10 @@ unsafe impl<T: Task + Sync> Sync for AsyncWork<T> {}
 /// So it actually could do nothing here, because `execute` function is called in the other thread mostly.
 unsafe extern "C" fn execute<T: Task>(_env: sys::napi_env, data: *mut c_void) {
   let work = Box::leak(unsafe { Box::from_raw(data as *mut AsyncWork<T>) });
-  let value = work.inner_task.compute();
+  // drop the reporter after computing, so it doesn't keep the event loop alive
+  let progress = work.progress.take().unwrap_or_default();
+  let value = work.inner_task.compute_with_progress(&progress);
+  drop(progress);
   work.value.write(value);
 }
 
@@ -437,3 +437,264 @@ extern "C" fn generator_throw<T: AsyncGenerator>(
     }
   }
 }
+
+#[cfg(feature = "web_stream")]
+pub use stream::AsyncStream;
+
+#[cfg(feature = "web_stream")]
+mod stream {
+  use std::pin::Pin;
+  use std::ptr;
+  use std::sync::{Arc, Mutex};
+
+  use futures_core::Stream;
+  use tokio::sync::oneshot;
+  use tokio_stream::StreamExt;
+
+  use crate::{
+    bindgen_runtime::{
+      iterator::{create_iterator_method, get_iterator_state},
+      JsObjectValue, Object, ToNapiValue, TypeName, Unknown,
+    },
+    check_status, sys, Env, JsError, JsValue, Result, ValueType,
+  };
+
+  type StreamSlot<S> = Arc<Mutex<Option<Pin<Box<S>>>>>;
+
+  /// Expose a `Stream` to JavaScript as an async iterator, which can be consumed with `for await`.
+  ///
+  /// The next item is pulled from the `Stream` only when `next()` is called in JavaScript,
+  /// and the calls of `next()` and `return()` are settled in order, like an `AsyncGenerator`.
+  /// The iteration is done after the `Stream` ends, yields an `Err` or `return()` is called, the `Stream` is dropped then.
+  ///
+  /// A `#[napi]` function returning `impl Stream<Item = Result<T>>` is converted into it automatically.
+  pub struct AsyncStream<S> {
+    inner: S,
+  }
+
+  impl<S> AsyncStream<S> {
+    pub fn new(inner: S) -> Self {
+      Self { inner }
+    }
+  }
+
+  struct AsyncStreamState<S> {
+    stream: StreamSlot<S>,
+    /// Closed after the previous `next()` or `return()` call is settled
+    previous: Option<oneshot::Receiver<()>>,
+  }
+
+  impl<S> AsyncStreamState<S> {
+    /// Returns the receiver to wait for the previous call, and the sender to notify the next one
+    fn enqueue(&mut self) -> (Option<oneshot::Receiver<()>>, oneshot::Sender<()>) {
+      let (settled, next) = oneshot::channel();
+      (self.previous.replace(next), settled)
+    }
+  }
+
+  impl<S> TypeName for AsyncStream<S> {
+    fn type_name() -> &'static str {
+      "AsyncIterableIterator"
+    }
+
+    fn value_type() -> ValueType {
+      ValueType::Object
+    }
+  }
+
+  impl<T, S> ToNapiValue for AsyncStream<S>
+  where
+    T: ToNapiValue + Send + 'static,
+    S: Stream<Item = Result<T>> + Send + 'static,
+  {
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+      let env = Env::from_raw(env);
+      let mut iterator = Object::new(&env)?;
+      iterator.set_named_property(
+        "next",
+        create_iterator_method(env.raw(), iterator.raw(), c"next", next::<T, S>)?,
+      )?;
+      iterator.set_named_property(
+        "return",
+        create_iterator_method(env.raw(), iterator.raw(), c"return", complete::<S>)?,
+      )?;
+      let async_iterator_symbol = env
+        .get_global_object("Symbol")?
+        .get_named_property_unchecked::<Unknown>("asyncIterator")?;
+      let return_this = create_function(&env, "[Symbol.asyncIterator]", return_this)?;
+      check_status!(
+        unsafe {
+          sys::napi_set_property(
+            env.raw(),
+            iterator.raw(),
+            async_iterator_symbol.raw(),
+            return_this,
+          )
+        },
+        "Failed to set Symbol.asyncIterator on the async iterator"
+      )?;
+      let state = Box::new(AsyncStreamState {
+        stream: Arc::new(Mutex::new(Some(Box::pin(val.inner)))),
+        previous: None,
+      });
+      check_status!(
+        unsafe {
+          sys::napi_wrap(
+            env.raw(),
+            iterator.raw(),
+            Box::into_raw(state).cast(),
+            Some(finalize_state::<S>),
+            ptr::null_mut(),
+            ptr::null_mut(),
+          )
+        },
+        "Failed to wrap the Stream into the async iterator"
+      )?;
+      Ok(iterator.raw())
+    }
+  }
+
+  fn create_function(
+    env: &Env,
+    name: &str,
+    cb: unsafe extern "C" fn(sys::napi_env, sys::napi_callback_info) -> sys::napi_value,
+  ) -> Result<sys::napi_value> {
+    let mut function = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_function(
+          env.raw(),
+          name.as_ptr().cast(),
+          name.len() as isize,
+          Some(cb),
+          ptr::null_mut(),
+          &mut function,
+        )
+      },
+      "Failed to create {} function of the async iterator",
+      name
+    )?;
+    Ok(function)
+  }
+
+  fn get_this(env: sys::napi_env, info: sys::napi_callback_info) -> Result<sys::napi_value> {
+    let mut this = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_cb_info(
+          env,
+          info,
+          ptr::null_mut(),
+          ptr::null_mut(),
+          &mut this,
+          ptr::null_mut(),
+        )
+      },
+      "Get callback info from async iterator function failed"
+    )?;
+    Ok(this)
+  }
+
+  fn iterator_result<T: ToNapiValue>(env: &Env, value: Option<T>) -> Result<sys::napi_value> {
+    let mut result = Object::new(env)?;
+    if let Some(value) = value {
+      result.set("value", value)?;
+      result.set("done", false)?;
+    } else {
+      result.set("value", ())?;
+      result.set("done", true)?;
+    }
+    Ok(result.raw())
+  }
+
+  fn throw_or_return(env: sys::napi_env, value: Result<sys::napi_value>) -> sys::napi_value {
+    match value {
+      Ok(value) => value,
+      Err(err) => unsafe {
+        let js_error: JsError = err.into();
+        js_error.throw_into(env);
+        ptr::null_mut()
+      },
+    }
+  }
+
+  extern "C" fn next<T, S>(env: sys::napi_env, info: sys::napi_callback_info) -> sys::napi_value
+  where
+    T: ToNapiValue + Send + 'static,
+    S: Stream<Item = Result<T>> + Send + 'static,
+  {
+    throw_or_return(env, next_impl::<T, S>(env, info))
+  }
+
+  fn next_impl<T, S>(env: sys::napi_env, info: sys::napi_callback_info) -> Result<sys::napi_value>
+  where
+    T: ToNapiValue + Send + 'static,
+    S: Stream<Item = Result<T>> + Send + 'static,
+  {
+    let state = get_iterator_state::<AsyncStreamState<S>>(env, info)?;
+    let (previous, settled) = state.enqueue();
+    let slot = state.stream.clone();
+    let env = Env::from_raw(env);
+    let promise = env.spawn_future_with_callback(
+      async move {
+        if let Some(previous) = previous {
+          let _ = previous.await;
+        }
+        let mut stream = slot.lock().unwrap_or_else(|e| e.into_inner()).take();
+        let item = match stream.as_mut() {
+          Some(stream) => stream.next().await.transpose(),
+          None => Ok(None),
+        };
+        // The `Stream` is dropped after it ends or yields an `Err`
+        if let Ok(Some(_)) = &item {
+          *slot.lock().unwrap_or_else(|e| e.into_inner()) = stream;
+        }
+        drop(settled);
+        item
+      },
+      |env, item| iterator_result(&env, item),
+    )?;
+    Ok(promise.inner)
+  }
+
+  extern "C" fn complete<S: Send + 'static>(
+    env: sys::napi_env,
+    info: sys::napi_callback_info,
+  ) -> sys::napi_value {
+    throw_or_return(env, complete_impl::<S>(env, info))
+  }
+
+  fn complete_impl<S: Send + 'static>(
+    env: sys::napi_env,
+    info: sys::napi_callback_info,
+  ) -> Result<sys::napi_value> {
+    let state = get_iterator_state::<AsyncStreamState<S>>(env, info)?;
+    let (previous, settled) = state.enqueue();
+    let slot = state.stream.clone();
+    let env = Env::from_raw(env);
+    let promise = env.spawn_future_with_callback(
+      async move {
+        if let Some(previous) = previous {
+          let _ = previous.await;
+        }
+        drop(slot.lock().unwrap_or_else(|e| e.into_inner()).take());
+        drop(settled);
+        Ok(())
+      },
+      |env, _| iterator_result::<()>(&env, None),
+    )?;
+    Ok(promise.inner)
+  }
+
+  extern "C" fn return_this(env: sys::napi_env, info: sys::napi_callback_info) -> sys::napi_value {
+    throw_or_return(env, get_this(env, info))
+  }
+
+  unsafe extern "C" fn finalize_state<S>(
+    _env: sys::napi_env,
+    finalize_data: *mut std::ffi::c_void,
+    _finalize_hint: *mut std::ffi::c_void,
+  ) {
+    drop(unsafe { Box::from_raw(finalize_data.cast::<AsyncStreamState<S>>()) });
+  }
+}
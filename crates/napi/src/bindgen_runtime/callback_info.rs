@@ -1,3 +1,4 @@
+use std::any::TypeId;
 use std::cell::Cell;
 use std::ffi::c_void;
 use std::ptr;
@@ -18,8 +19,11 @@ struct EmptyStructPlaceholder(u8);
 #[doc(hidden)]
 pub struct CallbackInfo<const N: usize> {
   env: sys::napi_env,
+  callback_info: sys::napi_callback_info,
   pub this: sys::napi_value,
   pub args: [sys::napi_value; N],
+  /// The number of the arguments passed from JavaScript, it may be larger than `N`
+  argc: usize,
   this_reference: sys::napi_ref,
 }
 
@@ -74,8 +78,10 @@ impl<const N: usize> CallbackInfo<N> {
 
     Ok(Self {
       env,
+      callback_info,
       this,
       args,
+      argc,
       this_reference,
     })
   }
@@ -84,6 +90,34 @@ impl<const N: usize> CallbackInfo<N> {
     self.args[index]
   }
 
+  /// The arguments from the `start` index to the last one, for the `#[napi(rest)]` argument.
+  ///
+  /// They are not limited by `N`, the arguments beyond `N` are read again into a buffer fits all of them.
+  pub fn get_rest_args(&self, start: usize) -> Result<Vec<sys::napi_value>> {
+    if self.argc <= start {
+      return Ok(Vec::new());
+    }
+    if self.argc <= N {
+      return Ok(self.args[start..self.argc].to_vec());
+    }
+    let mut argc = self.argc;
+    let mut args = vec![ptr::null_mut(); argc];
+    check_status!(
+      unsafe {
+        sys::napi_get_cb_info(
+          self.env,
+          self.callback_info,
+          &mut argc,
+          args.as_mut_ptr(),
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Failed to get the rest arguments"
+    )?;
+    Ok(args.split_off(start))
+  }
+
   pub fn this(&self) -> sys::napi_value {
     self.this
   }
@@ -93,9 +127,8 @@ impl<const N: usize> CallbackInfo<N> {
     js_name: &str,
     obj: T,
   ) -> Result<(sys::napi_value, *mut T)> {
-    let obj = Box::new(obj);
     let this = self.this();
-    let mut value_ref = Box::into_raw(obj);
+    let mut value_ref = crate::js_values::alloc_native_object(obj);
     // for empty struct like `#[napi] struct A;`, the `value_ref` will be `0x1`
     // and it will be overwritten by the others instance of the same class
     if IsEmptyStructHint || value_ref as usize == 0x1 {
@@ -121,7 +154,12 @@ impl<const N: usize> CallbackInfo<N> {
     Reference::<T>::add_ref(
       self.env,
       value_ref.cast(),
-      (value_ref.cast(), object_ref, finalize_callbacks_ptr),
+      (
+        value_ref.cast(),
+        object_ref,
+        finalize_callbacks_ptr,
+        TypeId::of::<T>(),
+      ),
     );
     Ok((this, value_ref))
   }
@@ -196,11 +234,10 @@ impl<const N: usize> CallbackInfo<N> {
       return Ok((ptr::null_mut(), ptr::null_mut()));
     }
     check_status!(status, "Failed to create instance of class `{}`", js_name)?;
-    let obj = Box::new(obj);
     let initial_finalize: Box<dyn FnOnce()> = Box::new(|| {});
     let finalize_callbacks_ptr = Rc::into_raw(Rc::new(Cell::new(Box::into_raw(initial_finalize))));
     let mut object_ref = ptr::null_mut();
-    let mut value_ref = Box::into_raw(obj);
+    let mut value_ref = crate::js_values::alloc_native_object(obj);
 
     // for empty struct like `#[napi] struct A;`, the `value_ref` will be `0x1`
     // and it will be overwritten by the others instance of the same class
@@ -225,7 +262,12 @@ impl<const N: usize> CallbackInfo<N> {
     Reference::<T>::add_ref(
       self.env,
       value_ref.cast(),
-      (value_ref.cast(), object_ref, finalize_callbacks_ptr),
+      (
+        value_ref.cast(),
+        object_ref,
+        finalize_callbacks_ptr,
+        TypeId::of::<T>(),
+      ),
     );
     Ok((instance, value_ref))
   }
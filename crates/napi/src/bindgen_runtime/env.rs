@@ -1,6 +1,11 @@
-use crate::{sys, JsGlobal, Result};
+use crate::{sys, Error, JsGlobal, JsValue, Result, Status, Task, Unknown, ValueType};
 
-use super::Array;
+#[cfg(feature = "napi5")]
+use super::Function;
+use super::{
+  AbortSignal, Array, AsyncTask, Buffer, BufferSlice, EscapableHandleScope, FromNapiValue,
+  JsObjectValue, Object, ToNapiValue,
+};
 
 pub use crate::Env;
 
@@ -9,6 +14,278 @@ impl Env {
     Array::new(self.0, len)
   }
 
+  /// Create an `Array` from the `items`
+  ///
+  /// The `Array` is allocated with the length of `items` up front.
+  /// It stops at the first item that can't be converted into a JavaScript value, and the index of it is in the error.
+  pub fn create_array_from<T: ToNapiValue>(&self, items: Vec<T>) -> Result<Array<'_>> {
+    let array = Array::new(self.0, items.len() as u32)?;
+    for (index, item) in items.into_iter().enumerate() {
+      let value = unsafe { T::to_napi_value(self.0, item) }.map_err(|err| {
+        Error::new(
+          err.status,
+          format!(
+            "Failed to convert element with index `{}`: {}",
+            index, err.reason
+          ),
+        )
+      })?;
+      crate::check_status!(
+        unsafe { sys::napi_set_element(self.0, array.inner, index as u32, value) },
+        "Failed to set element with index `{}`",
+        index,
+      )?;
+    }
+    Ok(array)
+  }
+
+  /// Run `f` in a new escapable handle scope, the JavaScript values created in `f` are released when it returns,
+  /// so the handles don't pile up until the native call returns when creating many temporary values in a loop.
+  ///
+  /// The values created in `f` must not be used after the scope is closed, except the one escaped with `EscapableHandleScope::escape`.
+  /// Only one value can be escaped from a scope, return multiple values in an `Array` or `Object` and escape it instead.
+  ///
+  /// ```no_run
+  /// use napi::bindgen_prelude::*;
+  ///
+  /// #[napi]
+  /// pub fn create_last_object(env: &Env, count: u32) -> Result<Object<'_>> {
+  ///   env.with_escapable_scope(|scope| {
+  ///     let mut last = Object::new(env)?;
+  ///     for i in 0..count {
+  ///       // the objects of the previous iterations are released when the scope is closed
+  ///       last = Object::new(env)?;
+  ///       last.set("index", i)?;
+  ///     }
+  ///     scope.escape(last)
+  ///   })
+  /// }
+  /// ```
+  pub fn with_escapable_scope<'env, T>(
+    &'env self,
+    f: impl FnOnce(&EscapableHandleScope<'env>) -> Result<T>,
+  ) -> Result<T> {
+    let mut scope = std::ptr::null_mut();
+    crate::check_status!(
+      unsafe { sys::napi_open_escapable_handle_scope(self.0, &mut scope) },
+      "Failed to open escapable handle scope"
+    )?;
+    let scope = EscapableHandleScope {
+      scope,
+      env: self.0,
+      phantom: std::marker::PhantomData,
+    };
+    // the scope is closed when it's dropped
+    f(&scope)
+  }
+
+  /// Deep copy the `value` with the structured clone algorithm, like `structuredClone(value)` in JavaScript.
+  ///
+  /// It fails if `structuredClone` is not available in the current JavaScript context,
+  /// or the `value` can't be cloned, for example a function or an object holding a function.
+  pub fn structured_clone(&self, value: Unknown) -> Result<Unknown<'_>> {
+    let global = self.global()?;
+    let structured_clone = self.get_global_function("structuredClone")?;
+    let cloned = self.call_catching(
+      global.raw(),
+      structured_clone.raw(),
+      value.value().value,
+      "structured clone the value",
+    )?;
+    Ok(unsafe { Unknown::from_raw_unchecked(self.0, cloned) })
+  }
+
+  /// Serialize the `value` into bytes with the V8 serialization API, like `v8.serialize(value)` in Node.js.
+  ///
+  /// The returned `Buffer` owns a copy of the bytes and doesn't reference the current `Env`,
+  /// so it can be sent to other threads and deserialized in another `Env` with `Env::from_transferable`.
+  /// The same values as `structuredClone` are supported, it fails for the values can't be serialized, for example a function.
+  ///
+  /// The `v8` module is loaded with `process.getBuiltinModule`, which is available since Node.js 20.16.0 and 22.3.0.
+  pub fn to_transferable(&self, value: Unknown) -> Result<Buffer> {
+    let v8 = self.get_builtin_module("v8")?;
+    let serialize = v8.get_named_property_unchecked::<Unknown>("serialize")?;
+    let serialized = self.call_catching(
+      v8.raw(),
+      serialize.raw(),
+      value.raw(),
+      "serialize the value",
+    )?;
+    let serialized = unsafe { BufferSlice::from_napi_value(self.0, serialized)? };
+    Ok(Buffer::from(serialized.to_vec()))
+  }
+
+  /// Deserialize the `bytes` created by `Env::to_transferable` into a JavaScript value, like `v8.deserialize(bytes)` in Node.js.
+  ///
+  /// The `bytes` can come from another `Env`, the value is created in the current `Env`.
+  pub fn from_transferable(&self, bytes: &[u8]) -> Result<Unknown<'_>> {
+    let v8 = self.get_builtin_module("v8")?;
+    let deserialize = v8.get_named_property_unchecked::<Unknown>("deserialize")?;
+    let buffer =
+      unsafe { BufferSlice::to_napi_value(self.0, BufferSlice::copy_from(self, bytes)?)? };
+    let value = self.call_catching(v8.raw(), deserialize.raw(), buffer, "deserialize the value")?;
+    Ok(unsafe { Unknown::from_raw_unchecked(self.0, value) })
+  }
+
+  /// Same as `JSON.stringify(value)` in JavaScript
+  ///
+  /// Returns `None` if the `value` can't be represented in JSON, for example `undefined`, a function or a symbol,
+  /// which `JSON.stringify` returns `undefined` for.
+  /// It fails if `JSON.stringify` throws, for example the `value` contains a circular reference or a `BigInt`.
+  pub fn json_stringify(&self, value: Unknown) -> Result<Option<String>> {
+    let json = self.get_global_object("JSON")?;
+    let stringify = json.get_named_property_unchecked::<Unknown>("stringify")?;
+    let result = self.call_catching(
+      json.raw(),
+      stringify.raw(),
+      value.raw(),
+      "stringify the value",
+    )?;
+    let result = unsafe { Unknown::from_raw_unchecked(self.0, result) };
+    if result.get_type()? == ValueType::Undefined {
+      return Ok(None);
+    }
+    unsafe { String::from_napi_value(self.0, result.raw()) }.map(Some)
+  }
+
+  /// Same as `JSON.parse(s)` in JavaScript, it fails if the `s` is not valid JSON
+  pub fn json_parse(&self, s: &str) -> Result<Unknown<'_>> {
+    let json = self.get_global_object("JSON")?;
+    let parse = json.get_named_property_unchecked::<Unknown>("parse")?;
+    let s = self.create_string(s)?;
+    let value = self.call_catching(json.raw(), parse.raw(), s.raw(), "parse the JSON")?;
+    Ok(unsafe { Unknown::from_raw_unchecked(self.0, value) })
+  }
+
+  /// Wrap the `task` into an `AsyncTask`, its `compute` runs on the libuv thread pool once the `AsyncTask` is returned to JavaScript,
+  /// so the CPU-bound work doesn't block the JavaScript thread, and the `Promise` resolves with the output of `resolve`.
+  ///
+  /// The `signal` is the JavaScript argument of the `AbortSignal`, the task is aborted by it like `AsyncTask::with_signal`.
+  /// `undefined` and `null` mean there is no signal, and the other values which are not an `AbortSignal` are rejected.
+  pub fn spawn_task<T: Task>(&self, task: T, signal: Option<Unknown>) -> Result<AsyncTask<T>> {
+    let signal = match signal {
+      Some(signal) => match signal.get_type()? {
+        ValueType::Undefined | ValueType::Null => None,
+        ValueType::Object if signal.instanceof(self.get_global_function("AbortSignal")?)? => {
+          Some(unsafe { AbortSignal::from_napi_value(self.0, signal.raw())? })
+        }
+        value_type => {
+          return Err(Error::new(
+            Status::InvalidArg,
+            format!("Expected an AbortSignal, got {}", value_type),
+          ))
+        }
+      },
+      None => None,
+    };
+    Ok(AsyncTask::with_optional_signal(task, signal))
+  }
+
+  /// Same as `process.getBuiltinModule(name)` in Node.js
+  fn get_builtin_module(&self, name: &str) -> Result<Object<'_>> {
+    if let Ok(process) = self.process() {
+      let get_builtin_module =
+        process.get_named_property_unchecked::<Unknown>("getBuiltinModule")?;
+      if get_builtin_module.get_type()? == ValueType::Function {
+        let name = self.create_string(name)?;
+        let module = self.call_catching(
+          process.raw(),
+          get_builtin_module.raw(),
+          name.raw(),
+          "load the builtin module",
+        )?;
+        return Ok(Object::from_raw(self.0, module));
+      }
+    }
+    Err(Error::new(
+      Status::GenericFailure,
+      "process.getBuiltinModule is not available in the current context".to_owned(),
+    ))
+  }
+
+  /// Call `func` with one argument, the exception thrown by it is cleared and returned as an `InvalidArg` error
+  fn call_catching(
+    &self,
+    recv: sys::napi_value,
+    func: sys::napi_value,
+    arg: sys::napi_value,
+    action: &str,
+  ) -> Result<sys::napi_value> {
+    let mut result = std::ptr::null_mut();
+    let status = unsafe { sys::napi_call_function(self.0, recv, func, 1, &arg, &mut result) };
+    // For example the `DataCloneError` thrown by `structuredClone` for the values can't be cloned
+    if status == sys::Status::napi_pending_exception {
+      let mut exception = std::ptr::null_mut();
+      crate::check_status!(
+        unsafe { sys::napi_get_and_clear_last_exception(self.0, &mut exception) },
+        "Get and clear last exception failed"
+      )?;
+      let reason = unsafe { Unknown::from_raw_unchecked(self.0, exception) }
+        .coerce_to_string()
+        .and_then(|reason| reason.into_utf8())
+        .and_then(|reason| reason.into_owned())?;
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Failed to {}: {}", action, reason),
+      ));
+    }
+    crate::check_status!(status, "Failed to {}", action)?;
+    Ok(result)
+  }
+
+  #[cfg(feature = "napi5")]
+  /// Call `cb` with the `held` value after the `target` object is garbage collected,
+  /// with a `FinalizationRegistry` in JavaScript.
+  ///
+  /// Unlike the finalizer of the wrapped class, the `target` can be any JavaScript object, and `cb` can call into JavaScript.
+  ///
+  /// The timing is not deterministic: `cb` is called some time after the `target` is collected, in a later task of the event loop,
+  /// and may never be called if the `target` is still alive when the program exits.
+  /// The `held` value is dropped without calling `cb` in that case.
+  /// The `held` value must not reference the `target`, otherwise the `target` can't be collected.
+  pub fn register_finalizer<T: 'static, F: FnOnce(T) + 'static>(
+    &self,
+    target: Object,
+    held: T,
+    cb: F,
+  ) -> Result<()> {
+    let registry_class = self.get_global_function("FinalizationRegistry")?;
+    // The registry must be kept alive until the `target` is collected, otherwise `cb` is never called.
+    let registry_ref =
+      std::rc::Rc::new(std::cell::Cell::<sys::napi_ref>::new(std::ptr::null_mut()));
+    let held = std::cell::Cell::new(Some((held, cb)));
+    let cleanup = self.create_function_from_closure::<(), (), _>("cleanup", {
+      let registry_ref = registry_ref.clone();
+      move |ctx| {
+        let registry_ref = registry_ref.replace(std::ptr::null_mut());
+        if !registry_ref.is_null() {
+          crate::check_status!(
+            unsafe { sys::napi_delete_reference(ctx.env.raw(), registry_ref) },
+            "Failed to delete the reference of FinalizationRegistry"
+          )?;
+        }
+        if let Some((held, cb)) = held.take() {
+          cb(held);
+        }
+        Ok(())
+      }
+    })?;
+    let registry_class: Function<Function<(), ()>, Unknown> =
+      unsafe { Function::from_napi_value(self.0, registry_class.raw())? };
+    let registry = registry_class.new_instance(cleanup)?;
+    let registry = Object::from_raw(self.0, registry.raw());
+    let register =
+      registry.get_named_property_unchecked::<Function<Object, Unknown>>("register")?;
+    register.apply(registry, target)?;
+    let mut raw_ref = std::ptr::null_mut();
+    crate::check_status!(
+      unsafe { sys::napi_create_reference(self.0, registry.raw(), 1, &mut raw_ref) },
+      "Failed to create the reference of FinalizationRegistry"
+    )?;
+    registry_ref.set(raw_ref);
+    Ok(())
+  }
+
   pub fn get_global(&self) -> Result<JsGlobal> {
     let mut global = std::ptr::null_mut();
     crate::check_status!(
@@ -24,4 +301,62 @@ impl Env {
       std::marker::PhantomData,
     ))
   }
+
+  /// The `globalThis` as an `Object`
+  pub fn global(&self) -> Result<Object<'_>> {
+    let global = self.get_global()?;
+    Ok(Object::from_raw(self.0, global.raw()))
+  }
+
+  /// The `process` of Node.js, it fails in the environments without it, like the browsers
+  pub fn process(&self) -> Result<Object<'_>> {
+    self.get_global_object("process")
+  }
+
+  /// The `console` of the current JavaScript context, to call `console.log` or `console.error` from native
+  pub fn console(&self) -> Result<Object<'_>> {
+    self.get_global_object("console")
+  }
+
+  /// The `globalThis` property of the global object, it fails in the engines without it.
+  ///
+  /// Use `Env::global` to get the global object in any engine.
+  pub fn global_this(&self) -> Result<Object<'_>> {
+    self.get_global_object("globalThis")
+  }
+
+  /// The `globalThis[name]`, the functions like `globalThis.Array` are also objects.
+  ///
+  /// It fails if the global is missing, or it's not an object.
+  pub fn get_global_object(&self, name: &str) -> Result<Object<'_>> {
+    let value = self
+      .global()?
+      .get_named_property_unchecked::<Unknown>(name)?;
+    match value.get_type()? {
+      ValueType::Object | ValueType::Function => Ok(Object::from_raw(self.0, value.raw())),
+      ValueType::Undefined => Err(Error::new(
+        Status::GenericFailure,
+        format!("`{}` is not available in the current context", name),
+      )),
+      value_type => Err(Error::new(
+        Status::ObjectExpected,
+        format!(
+          "Expected the global `{}` to be an object, got {}",
+          name, value_type
+        ),
+      )),
+    }
+  }
+
+  /// Same as `get_global_object`, for the global functions and classes like `globalThis.Promise`
+  pub(crate) fn get_global_function(&self, name: &str) -> Result<Object<'_>> {
+    let func = self.get_global_object(name)?;
+    if func.to_unknown().get_type()? != ValueType::Function {
+      return Err(Error::new(
+        Status::GenericFailure,
+        format!("`{}` is not available in the current context", name),
+      ));
+    }
+    Ok(func)
+  }
 }
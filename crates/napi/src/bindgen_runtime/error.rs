@@ -8,3 +8,51 @@ macro_rules! check_status_or_throw {
     }
   };
 }
+
+#[cfg(debug_assertions)]
+thread_local! {
+  static PANIC_BACKTRACE: std::cell::RefCell<Option<std::backtrace::Backtrace>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Record the backtrace of the panics on the current thread, so they can be attached to the JavaScript `Error`
+///
+/// The previous panic hook is still called after recording.
+#[cfg(all(debug_assertions, not(feature = "noop")))]
+pub(crate) fn record_panic_backtrace() {
+  static SET_HOOK: std::sync::Once = std::sync::Once::new();
+  SET_HOOK.call_once(|| {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+      let _ = PANIC_BACKTRACE.try_with(|backtrace| {
+        backtrace.replace(Some(std::backtrace::Backtrace::capture()));
+      });
+      previous_hook(info);
+    }));
+  });
+}
+
+#[doc(hidden)]
+/// Convert the payload of a caught panic into `Error`
+///
+/// The backtrace of the panic is appended to the message in debug builds if it's enabled by `RUST_BACKTRACE`.
+pub fn panic_to_error(payload: Box<dyn std::any::Any + Send>) -> crate::Error {
+  let message = if let Some(string) = payload.downcast_ref::<String>() {
+    string.clone()
+  } else if let Some(string) = payload.downcast_ref::<&str>() {
+    string.to_string()
+  } else {
+    format!("panic from Rust code: {:?}", payload)
+  };
+  #[cfg(debug_assertions)]
+  let message = match PANIC_BACKTRACE
+    .try_with(|backtrace| backtrace.take())
+    .ok()
+    .flatten()
+  {
+    Some(backtrace) if backtrace.status() == std::backtrace::BacktraceStatus::Captured => {
+      format!("{}\n\nRust backtrace:\n{}", message, backtrace)
+    }
+    _ => message,
+  };
+  crate::Error::new(crate::Status::GenericFailure, message)
+}
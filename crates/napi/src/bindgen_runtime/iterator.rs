@@ -1,10 +1,13 @@
-use std::ffi::c_void;
+use std::ffi::{c_void, CStr};
 use std::ptr;
 
 use crate::Value;
-use crate::{bindgen_runtime::Unknown, check_status_or_throw, sys, Env};
+use crate::{
+  bindgen_runtime::Unknown, check_status, check_status_or_throw, sys, Env, Error, JsError,
+  JsTypeError, JsValue, Status,
+};
 
-use super::{FromNapiValue, ToNapiValue};
+use super::{FromNapiValue, JsObjectValue, ToNapiValue, TypeName};
 
 const GENERATOR_STATE_KEY: &str = "[[GeneratorState]]\0";
 
@@ -557,3 +560,320 @@ fn set_generator_value<V: ToNapiValue>(env: sys::napi_env, result: sys::napi_val
     }
   }
 }
+
+/// Expose a Rust `Iterator` to JavaScript as an iterator object.
+///
+/// Each `next()` call from JavaScript pulls one item from the underlying iterator and returns `{ value, done }`.
+/// An `Err` item is thrown as a JavaScript exception, and the iterator is completed after that.
+///
+/// ```rust
+/// use napi::bindgen_prelude::*;
+///
+/// #[napi]
+/// pub fn lines(input: String) -> IteratorGenerator<String> {
+///   IteratorGenerator::new(input.lines().map(|line| Ok(line.to_owned())).collect::<Vec<_>>())
+/// }
+/// ```
+pub struct IteratorGenerator<T: ToNapiValue + 'static> {
+  inner: Box<dyn Iterator<Item = crate::Result<T>>>,
+}
+
+impl<T: ToNapiValue + 'static> IteratorGenerator<T> {
+  pub fn new<I>(iter: I) -> Self
+  where
+    I: IntoIterator<Item = crate::Result<T>>,
+    I::IntoIter: 'static,
+  {
+    Self {
+      inner: Box::new(iter.into_iter()),
+    }
+  }
+}
+
+impl<T: ToNapiValue + 'static> TypeName for IteratorGenerator<T> {
+  fn type_name() -> &'static str {
+    "IteratorGenerator"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Object
+  }
+}
+
+struct IteratorGeneratorState<T: ToNapiValue + 'static> {
+  inner: Box<dyn Iterator<Item = crate::Result<T>>>,
+  done: bool,
+}
+
+impl<T: ToNapiValue + 'static> ToNapiValue for IteratorGenerator<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
+    let mut generator_object = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_object(env, &mut generator_object) },
+      "Create IteratorGenerator object failed"
+    )?;
+    let state_ptr = Box::into_raw(Box::new(IteratorGeneratorState {
+      inner: val.inner,
+      done: false,
+    }));
+    if let Err(err) = check_status!(
+      unsafe {
+        sys::napi_wrap(
+          env,
+          generator_object,
+          state_ptr.cast(),
+          Some(iterator_generator_finalize::<T>),
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Wrap IteratorGenerator state failed"
+    ) {
+      drop(unsafe { Box::from_raw(state_ptr) });
+      return Err(err);
+    }
+    let next_function =
+      create_iterator_method(env, generator_object, c"next", iterator_generator_next::<T>)?;
+    check_status!(
+      unsafe {
+        sys::napi_set_named_property(
+          env,
+          generator_object,
+          c"next".as_ptr().cast(),
+          next_function,
+        )
+      },
+      "Set next function on IteratorGenerator object failed"
+    )?;
+    // make it work with `for...of` and the spread syntax
+    let js_env = Env::from_raw(env);
+    let iterator_symbol = js_env
+      .get_global_object("Symbol")?
+      .get_named_property_unchecked::<Unknown>("iterator")?
+      .raw();
+    let mut iterator_function = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_function(
+          env,
+          c"[Symbol.iterator]".as_ptr().cast(),
+          c"[Symbol.iterator]".count_bytes() as isize,
+          Some(iterator_generator_self),
+          ptr::null_mut(),
+          &mut iterator_function,
+        )
+      },
+      "Create iterator function failed"
+    )?;
+    check_status!(
+      unsafe { sys::napi_set_property(env, generator_object, iterator_symbol, iterator_function) },
+      "Set Symbol.iterator on IteratorGenerator object failed"
+    )?;
+    Ok(generator_object)
+  }
+}
+
+/// Create the method `name` of the native iterator object `iterator`, the state of it is got with `get_iterator_state`.
+///
+/// The method only holds a weak reference to the `iterator`, so it can be detached from the `iterator` and outlive it.
+pub(crate) fn create_iterator_method(
+  env: sys::napi_env,
+  iterator: sys::napi_value,
+  name: &CStr,
+  cb: unsafe extern "C" fn(sys::napi_env, sys::napi_callback_info) -> sys::napi_value,
+) -> crate::Result<sys::napi_value> {
+  let mut iterator_ref = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_create_reference(env, iterator, 0, &mut iterator_ref) },
+    "Failed to create reference of the iterator"
+  )?;
+  let iterator_ref = Box::into_raw(Box::new(iterator_ref));
+  let mut method = ptr::null_mut();
+  let result = check_status!(
+    unsafe {
+      sys::napi_create_function(
+        env,
+        name.as_ptr(),
+        name.count_bytes() as isize,
+        Some(cb),
+        iterator_ref.cast(),
+        &mut method,
+      )
+    },
+    "Failed to create {:?} method of the iterator",
+    name
+  )
+  .and_then(|_| {
+    // the reference is deleted along with the method
+    check_status!(
+      unsafe {
+        sys::napi_wrap(
+          env,
+          method,
+          iterator_ref.cast(),
+          Some(iterator_method_finalize),
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Failed to wrap the reference of the iterator into its {:?} method",
+      name
+    )
+  });
+  if let Err(err) = result {
+    unsafe { iterator_method_finalize(env, iterator_ref.cast(), ptr::null_mut()) };
+    return Err(err);
+  }
+  Ok(method)
+}
+
+/// Get the state wrapped in the iterator object of the method created by `create_iterator_method`.
+///
+/// Throws a `TypeError` if the `this` of the method is not the iterator, for example the method is detached from it,
+/// because the state is freed along with the iterator.
+pub(crate) fn get_iterator_state<'a, S>(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> crate::Result<&'a mut S> {
+  let mut this = ptr::null_mut();
+  let mut iterator_ref = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_cb_info(
+        env,
+        info,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        &mut this,
+        &mut iterator_ref,
+      )
+    },
+    "Get callback info from iterator method failed"
+  )?;
+  let mut iterator = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_reference_value(env, *iterator_ref.cast::<sys::napi_ref>(), &mut iterator)
+    },
+    "Failed to get the iterator from its reference"
+  )?;
+  let mut is_iterator = false;
+  // the iterator is garbage collected if it's null
+  if !iterator.is_null() {
+    check_status!(
+      unsafe { sys::napi_strict_equals(env, this, iterator, &mut is_iterator) },
+      "Failed to compare the `this` of iterator method with the iterator"
+    )?;
+  }
+  if !is_iterator {
+    let err = Error::new(
+      Status::InvalidArg,
+      "The iterator method is called on an object which is not the iterator",
+    );
+    unsafe { JsTypeError::from(err).throw_into(env) };
+    return Err(Error::new(
+      Status::PendingException,
+      "The iterator method is called on an object which is not the iterator",
+    ));
+  }
+  let mut state = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_unwrap(env, this, &mut state) },
+    "Failed to unwrap the state of the iterator"
+  )?;
+  Ok(unsafe { &mut *state.cast::<S>() })
+}
+
+unsafe extern "C" fn iterator_method_finalize(
+  env: sys::napi_env,
+  finalize_data: *mut c_void,
+  _finalize_hint: *mut c_void,
+) {
+  let iterator_ref = unsafe { Box::from_raw(finalize_data.cast::<sys::napi_ref>()) };
+  unsafe { sys::napi_delete_reference(env, *iterator_ref) };
+}
+
+unsafe extern "C" fn iterator_generator_finalize<T: ToNapiValue + 'static>(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  _finalize_hint: *mut c_void,
+) {
+  drop(unsafe { Box::from_raw(finalize_data.cast::<IteratorGeneratorState<T>>()) });
+}
+
+unsafe extern "C" fn iterator_generator_self(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let mut this = ptr::null_mut();
+  check_status_or_throw!(
+    env,
+    unsafe {
+      sys::napi_get_cb_info(
+        env,
+        info,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        &mut this,
+        ptr::null_mut(),
+      )
+    },
+    "Get callback info from iterator function failed"
+  );
+  this
+}
+
+unsafe extern "C" fn iterator_generator_next<T: ToNapiValue + 'static>(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let state = match get_iterator_state::<IteratorGeneratorState<T>>(env, info) {
+    Ok(state) => state,
+    Err(err) => {
+      unsafe { JsError::from(err).throw_into(env) };
+      return ptr::null_mut();
+    }
+  };
+  let mut result = ptr::null_mut();
+  check_status_or_throw!(
+    env,
+    unsafe { sys::napi_create_object(env, &mut result) },
+    "Failed to create iterator result object",
+  );
+  if !state.done {
+    let value = match state.inner.next() {
+      Some(Ok(item)) => unsafe { ToNapiValue::to_napi_value(env, item) }.map(Some),
+      Some(Err(err)) => Err(err),
+      None => Ok(None),
+    };
+    match value {
+      Ok(Some(value)) => {
+        check_status_or_throw!(
+          env,
+          unsafe { sys::napi_set_named_property(env, result, c"value".as_ptr().cast(), value) },
+          "Failed to set iterator result value",
+        );
+      }
+      Ok(None) => {
+        state.done = true;
+      }
+      Err(err) => {
+        state.done = true;
+        unsafe { JsError::from(err).throw_into(env) };
+        return ptr::null_mut();
+      }
+    }
+  }
+  let mut done = ptr::null_mut();
+  check_status_or_throw!(
+    env,
+    unsafe { sys::napi_get_boolean(env, state.done, &mut done) },
+    "Failed to create completed value"
+  );
+  check_status_or_throw!(
+    env,
+    unsafe { sys::napi_set_named_property(env, result, c"done".as_ptr().cast(), done) },
+    "Failed to set iterator result done",
+  );
+  result
+}
@@ -13,6 +13,7 @@ mod bigint;
 mod boolean;
 mod buffer;
 mod class;
+mod dataview;
 #[cfg(all(feature = "chrono_date", feature = "napi5"))]
 mod date;
 mod either;
@@ -29,6 +30,8 @@ mod scope;
 #[cfg(feature = "serde-json")]
 mod serde;
 mod set;
+mod shared_arraybuffer;
+mod shared_state;
 #[cfg(feature = "web_stream")]
 mod stream;
 mod string;
@@ -45,6 +48,7 @@ pub use arraybuffer::*;
 pub use bigint::*;
 pub use buffer::*;
 pub use class::*;
+pub use dataview::*;
 pub use either::*;
 pub use external::*;
 pub use function::*;
@@ -54,6 +58,8 @@ pub use object::*;
 pub use promise::*;
 pub use promise_raw::*;
 pub use scope::*;
+pub use shared_arraybuffer::*;
+pub use shared_state::*;
 #[cfg(feature = "web_stream")]
 pub use stream::*;
 pub use string::*;
@@ -85,6 +85,81 @@ impl<'env> Array<'env> {
     Ok(())
   }
 
+  /// Convert all elements of the `Array` into a `Vec<T>`
+  ///
+  /// The capacity of the `Vec` is reserved from the length of the `Array` up front.
+  /// It stops at the first element that can't be converted into `T`, and the index of it is in the error.
+  /// Holes in sparse arrays like `[1, , 3]` are rejected rather than converted from `undefined`.
+  pub fn to_vec_with<T: FromNapiValue>(&self) -> Result<Vec<T>> {
+    let mut vec = Vec::with_capacity(self.len as usize);
+    for index in 0..self.len {
+      let mut has_element = false;
+      check_status!(
+        unsafe { sys::napi_has_element(self.env, self.inner, index, &mut has_element) },
+        "Failed to check element with index `{}`",
+        index,
+      )?;
+      if !has_element {
+        return Err(Error::new(
+          Status::InvalidArg,
+          format!("Found a hole at index `{}` of the Array", index),
+        ));
+      }
+      let mut element = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_get_element(self.env, self.inner, index, &mut element) },
+        "Failed to get element with index `{}`",
+        index,
+      )?;
+      let value = unsafe { T::from_napi_value(self.env, element) }.map_err(|err| {
+        Error::new(
+          err.status,
+          format!(
+            "Failed to convert element with index `{}`: {}",
+            index, err.reason
+          ),
+        )
+      })?;
+      vec.push(value);
+    }
+    Ok(vec)
+  }
+
+  /// Convert each element into `T` and transform it with `f` in Rust, collect the `Some` results into a new `Array`
+  ///
+  /// The elements `f` returns `None` for are skipped, like `filter` and `map` in JavaScript but without calling a JavaScript callback for each element.
+  /// It stops at the first element that can't be converted into `T` or `f` fails on, and the index of it is in the error.
+  pub fn map_collect<'a, T: FromNapiValue, U: ToNapiValue>(
+    &self,
+    env: &'a Env,
+    mut f: impl FnMut(T) -> Result<Option<U>>,
+  ) -> Result<Array<'a>> {
+    let mut collected = Array::new(env.raw(), 0)?;
+    for index in 0..self.len {
+      let mut element = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_get_element(self.env, self.inner, index, &mut element) },
+        "Failed to get element with index `{}`",
+        index,
+      )?;
+      let mapped = unsafe { T::from_napi_value(self.env, element) }
+        .and_then(&mut f)
+        .map_err(|err| {
+          Error::new(
+            err.status,
+            format!(
+              "Failed to map element with index `{}`: {}",
+              index, err.reason
+            ),
+          )
+        })?;
+      if let Some(mapped) = mapped {
+        collected.insert(mapped)?;
+      }
+    }
+    Ok(collected)
+  }
+
   #[allow(clippy::len_without_is_empty)]
   pub fn len(&self) -> u32 {
     self.len
@@ -282,7 +357,7 @@ where
 {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
     let arr = unsafe { Array::from_napi_value(env, napi_val)? };
-    let mut vec = vec![];
+    let mut vec = Vec::with_capacity(arr.len() as usize);
 
     for i in 0..arr.len() {
       if let Some(val) = arr.get::<T>(i)? {
@@ -341,10 +416,10 @@ macro_rules! tuple_from_napi_value {
         )
         .to_owned(),
       ));
-      if arr.len() < $total {
+      if arr.len() != $total {
         return Err(Error::new(
             Status::InvalidArg,
-            format!("Array length < {}",$total).to_owned(),
+            format!("Expected an Array with length {} for the tuple, got {}", $total, arr.len()),
         ));
       }
       Ok(($(arr_get!(arr,$n,err)),+))
@@ -85,6 +85,56 @@ impl From<TypedArrayType> for sys::napi_typedarray_type {
   }
 }
 
+fn typed_array_type_mismatch(expected: &str, actual: sys::napi_typedarray_type) -> Error {
+  Error::new(
+    Status::InvalidArg,
+    format!(
+      "Expected {}, got {}Array",
+      expected,
+      TypedArrayType::from(actual).as_ref()
+    ),
+  )
+}
+
+/// Validate that `napi_val` is a TypedArray with the `expected` element type
+unsafe fn validate_typed_array_type(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+  expected: TypedArrayType,
+  expected_name: &str,
+) -> Result<()> {
+  let mut is_typed_array = false;
+  check_status!(
+    unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typed_array) },
+    "Failed to check if value is typed array"
+  )?;
+  if !is_typed_array {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Expected a TypedArray value".to_owned(),
+    ));
+  }
+  let mut typed_array_type = 0;
+  check_status!(
+    unsafe {
+      sys::napi_get_typedarray_info(
+        env,
+        napi_val,
+        &mut typed_array_type,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    },
+    "Get TypedArray info failed"
+  )?;
+  if typed_array_type != expected as i32 {
+    return Err(typed_array_type_mismatch(expected_name, typed_array_type));
+  }
+  Ok(())
+}
+
 #[cfg(target_family = "wasm")]
 extern "C" {
   fn emnapi_sync_memory(
@@ -137,6 +187,8 @@ impl FromNapiValue for ArrayBuffer<'_> {
 impl Deref for ArrayBuffer<'_> {
   type Target = [u8];
 
+  /// The data when this `ArrayBuffer` was created, it's dangling once the `ArrayBuffer` is detached,
+  /// including detached from JavaScript, use `as_slice` if it could have been detached since then.
   fn deref(&self) -> &Self::Target {
     self.data
   }
@@ -309,6 +361,8 @@ impl<'env> ArrayBuffer<'env> {
   /// The engine may impose additional conditions on whether an ArrayBuffer is detachable.
   ///
   /// For example, V8 requires that the ArrayBuffer be external, that is, created with napi_create_external_arraybuffer
+  ///
+  /// The copies of this `ArrayBuffer` must not be dereferenced after detached, `as_slice` / `as_mut_slice` return an error for them.
   pub fn detach(self) -> Result<()> {
     check_status!(unsafe { sys::napi_detach_arraybuffer(self.value.env, self.value.value) })
   }
@@ -324,6 +378,59 @@ impl<'env> ArrayBuffer<'env> {
     })?;
     Ok(is_detached)
   }
+
+  /// View of the underlying data, read again from the `ArrayBuffer` rather than the data when this `ArrayBuffer` was created.
+  ///
+  /// Unlike `Deref`, it returns an `InvalidArg` error rather than dangling if the `ArrayBuffer` has been detached since then,
+  /// for example transferred to a worker with `postMessage`.
+  ///
+  /// The returned slice must not be held across calls into JavaScript, which could detach the `ArrayBuffer` again.
+  pub fn as_slice(&self) -> Result<&[u8]> {
+    let (data, len) = self.current_data()?;
+    if data.is_null() {
+      return Ok(&[]);
+    }
+    Ok(unsafe { std::slice::from_raw_parts(data, len) })
+  }
+
+  /// Mutable view of the underlying data, the changes are visible from JavaScript without copying.
+  ///
+  /// Same as `as_slice`, it returns an `InvalidArg` error if the `ArrayBuffer` has been detached.
+  pub fn as_mut_slice(&mut self) -> Result<&mut [u8]> {
+    let (data, len) = self.current_data()?;
+    if data.is_null() {
+      return Ok(&mut []);
+    }
+    Ok(unsafe { std::slice::from_raw_parts_mut(data, len) })
+  }
+
+  fn current_data(&self) -> Result<(*mut u8, usize)> {
+    let mut data = ptr::null_mut();
+    let mut byte_length = 0;
+    check_status!(
+      unsafe {
+        sys::napi_get_arraybuffer_info(
+          self.value.env,
+          self.value.value,
+          &mut data,
+          &mut byte_length,
+        )
+      },
+      "Get ArrayBuffer info failed"
+    )?;
+    #[cfg(feature = "napi7")]
+    let is_detached = self.is_detached()?;
+    // the data of a detached `ArrayBuffer` is null
+    #[cfg(not(feature = "napi7"))]
+    let is_detached = data.is_null() && !self.data.is_empty();
+    if is_detached {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "The ArrayBuffer has been detached".to_owned(),
+      ));
+    }
+    Ok((data.cast(), byte_length))
+  }
 }
 
 trait Finalizer {
@@ -514,6 +621,139 @@ macro_rules! impl_typed_array {
 
         unsafe { std::slice::from_raw_parts_mut(self.data, self.length) }
       }
+
+      /// Mutable view of the underlying `ArrayBuffer`, the changes are visible from JavaScript without copying.
+      ///
+      /// Unlike `as_mut`, the TypedArray info is read again if the value is from JavaScript,
+      /// so it returns an `InvalidArg` error rather than dangling if the `ArrayBuffer` has been detached since then.
+      ///
+      /// The returned slice must not be held across calls into JavaScript,
+      /// which could detach or resize the `ArrayBuffer` again and leave the slice dangling.
+      pub fn as_mut_slice(&mut self, env: &crate::Env) -> Result<&mut [$rust_type]> {
+        if let Some((ref_, _)) = self.raw {
+          if !ref_.is_null() {
+            let mut value = ptr::null_mut();
+            check_status!(
+              unsafe { sys::napi_get_reference_value(env.raw(), ref_, &mut value) },
+              "Failed to get reference value from {}",
+              stringify!($name)
+            )?;
+            let mut data = ptr::null_mut();
+            let mut length = 0;
+            check_status!(
+              unsafe {
+                sys::napi_get_typedarray_info(
+                  env.raw(),
+                  value,
+                  ptr::null_mut(),
+                  &mut length,
+                  &mut data,
+                  ptr::null_mut(),
+                  ptr::null_mut(),
+                )
+              },
+              "Get TypedArray info failed"
+            )?;
+            if data.is_null() && self.length != 0 {
+              return Err(Error::new(
+                Status::InvalidArg,
+                format!("The ArrayBuffer of {} has been detached", stringify!($name)),
+              ));
+            }
+            self.data = data.cast();
+            self.length = length;
+          }
+        }
+        Ok(unsafe { self.as_mut() })
+      }
+
+      /// Create a view of the elements from `begin` to `end` (exclusive) over the same `ArrayBuffer`, without copying.
+      ///
+      /// Unlike `subarray` in JavaScript, the negative or out of range `begin` and `end` are not clamped,
+      /// it returns an `InvalidArg` error if `begin > end` or `end` is greater than the length.
+      /// Only the TypedArray from JavaScript has a `ArrayBuffer` to share, it returns an `InvalidArg` error for the one created from Rust data.
+      pub fn subarray(&self, begin: usize, end: usize) -> Result<Self> {
+        let (ref_, env) = match self.raw {
+          Some((ref_, env)) if !ref_.is_null() && !env.is_null() => (ref_, env),
+          _ => {
+            return Err(Error::new(
+              Status::InvalidArg,
+              format!(
+                "Only the {} from JavaScript can create subarray",
+                stringify!($name)
+              ),
+            ))
+          }
+        };
+        let mut value = ptr::null_mut();
+        check_status!(
+          unsafe { sys::napi_get_reference_value(env, ref_, &mut value) },
+          "Failed to get reference value from {}",
+          stringify!($name)
+        )?;
+        let mut length = 0;
+        let mut array_buffer = ptr::null_mut();
+        let mut byte_offset = 0;
+        check_status!(
+          unsafe {
+            sys::napi_get_typedarray_info(
+              env,
+              value,
+              ptr::null_mut(),
+              &mut length,
+              ptr::null_mut(),
+              &mut array_buffer,
+              &mut byte_offset,
+            )
+          },
+          "Get TypedArray info failed"
+        )?;
+        if begin > end || end > length {
+          return Err(Error::new(
+            Status::InvalidArg,
+            format!(
+              "Subarray range {}..{} is out of range for {} with length {}",
+              begin,
+              end,
+              stringify!($name),
+              length
+            ),
+          ));
+        }
+        let mut subarray = ptr::null_mut();
+        check_status!(
+          unsafe {
+            sys::napi_create_typedarray(
+              env,
+              $typed_array_type as i32,
+              end - begin,
+              array_buffer,
+              byte_offset + begin * mem::size_of::<$rust_type>(),
+              &mut subarray,
+            )
+          },
+          "Create subarray of {} failed",
+          stringify!($name)
+        )?;
+        unsafe { Self::from_napi_value(env, subarray) }
+      }
+
+      /// Copy the elements into a fixed size array, for example a 4x4 matrix as `[f64; 16]`, without allocating on the heap.
+      ///
+      /// Returns an `InvalidArg` error if the length is not exactly `N`.
+      pub fn to_array<const N: usize>(&self) -> Result<[$rust_type; N]> {
+        <[$rust_type; N]>::try_from(self.as_ref()).map_err(|_| {
+          Error::new(
+            Status::InvalidArg,
+            format!(
+              "Expected {} with length {}, but got length {}",
+              stringify!($name),
+              N,
+              self.as_ref().len()
+            ),
+          )
+        })
+      }
     }
 
     impl Deref for $name {
@@ -549,17 +789,7 @@ macro_rules! impl_typed_array {
         env: sys::napi_env,
         napi_val: sys::napi_value,
       ) -> Result<crate::sys::napi_value> {
-        let mut is_typed_array = false;
-        check_status!(
-          unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typed_array) },
-          "Failed to check if value is typed array"
-        )?;
-        if !is_typed_array {
-          return Err(Error::new(
-            Status::InvalidArg,
-            "Expected a TypedArray value".to_owned(),
-          ));
-        }
+        unsafe { validate_typed_array_type(env, napi_val, $typed_array_type, stringify!($name))? };
         Ok(ptr::null_mut())
       }
     }
@@ -571,11 +801,6 @@ macro_rules! impl_typed_array {
         let mut data = ptr::null_mut();
         let mut array_buffer = ptr::null_mut();
         let mut byte_offset = 0;
-        let mut ref_ = ptr::null_mut();
-        check_status!(
-          unsafe { sys::napi_create_reference(env, napi_val, 1, &mut ref_) },
-          "Failed to create reference from Buffer"
-        )?;
         check_status!(
           unsafe {
             sys::napi_get_typedarray_info(
@@ -591,15 +816,16 @@ macro_rules! impl_typed_array {
           "Get TypedArray info failed"
         )?;
         if typed_array_type != $typed_array_type as i32 {
-          return Err(Error::new(
-            Status::InvalidArg,
-            format!(
-              "Expected {}, got {}Array",
-              stringify!($name),
-              TypedArrayType::from(typed_array_type).as_ref()
-            ),
+          return Err(typed_array_type_mismatch(
+            stringify!($name),
+            typed_array_type,
           ));
         }
+        let mut ref_ = ptr::null_mut();
+        check_status!(
+          unsafe { sys::napi_create_reference(env, napi_val, 1, &mut ref_) },
+          "Failed to create reference from Buffer"
+        )?;
         Ok($name {
           data: data.cast(),
           length,
@@ -1113,10 +1339,7 @@ macro_rules! impl_from_slice {
           "Get TypedArray info failed"
         )?;
         if typed_array_type != $typed_array_type as i32 {
-          return Err(Error::new(
-            Status::InvalidArg,
-            format!("Expected $name, got {}", typed_array_type),
-          ));
+          return Err(typed_array_type_mismatch(stringify!($name), typed_array_type));
         }
         // From the docs of `napi_get_typedarray_info`:
         // > [out] data: The underlying data buffer of the node::Buffer. If length is 0, this may be
@@ -1151,17 +1374,9 @@ macro_rules! impl_from_slice {
 
     impl ValidateNapiValue for $slice_type<'_> {
       unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
-        let mut is_typed_array = false;
-        check_status!(
-          unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typed_array) },
-          "Failed to validate napi typed array"
-        )?;
-        if !is_typed_array {
-          return Err(Error::new(
-            Status::InvalidArg,
-            "Expected a TypedArray value".to_owned(),
-          ));
-        }
+        unsafe {
+          validate_typed_array_type(env, napi_val, $typed_array_type, stringify!($name))?
+        };
         Ok(ptr::null_mut())
       }
     }
@@ -1208,10 +1423,7 @@ macro_rules! impl_from_slice {
           "Get TypedArray info failed"
         )?;
         if typed_array_type != $typed_array_type as i32 {
-          return Err(Error::new(
-            Status::InvalidArg,
-            format!("Expected $name, got {}", typed_array_type),
-          ));
+          return Err(typed_array_type_mismatch(stringify!($name), typed_array_type));
         }
         Ok(if length == 0 {
           &mut []
@@ -1243,10 +1455,7 @@ macro_rules! impl_from_slice {
           "Get TypedArray info failed"
         )?;
         if typed_array_type != $typed_array_type as i32 {
-          return Err(Error::new(
-            Status::InvalidArg,
-            format!("Expected $name, got {}", typed_array_type),
-          ));
+          return Err(typed_array_type_mismatch(stringify!($name), typed_array_type));
         }
         Ok(if length == 0 {
           &[]
@@ -1278,34 +1487,18 @@ macro_rules! impl_from_slice {
 
     impl ValidateNapiValue for &[$rust_type] {
       unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
-        let mut is_typed_array = false;
-        check_status!(
-          unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typed_array) },
-          "Failed to validate napi typed array"
-        )?;
-        if !is_typed_array {
-          return Err(Error::new(
-            Status::InvalidArg,
-            "Expected a TypedArray value".to_owned(),
-          ));
-        }
+        unsafe {
+          validate_typed_array_type(env, napi_val, $typed_array_type, stringify!($name))?
+        };
         Ok(ptr::null_mut())
       }
     }
 
     impl ValidateNapiValue for &mut [$rust_type] {
       unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
-        let mut is_typed_array = false;
-        check_status!(
-          unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typed_array) },
-          "Failed to validate napi typed array"
-        )?;
-        if !is_typed_array {
-          return Err(Error::new(
-            Status::InvalidArg,
-            "Expected a TypedArray value".to_owned(),
-          ));
-        }
+        unsafe {
+          validate_typed_array_type(env, napi_val, $typed_array_type, stringify!($name))?
+        };
         Ok(ptr::null_mut())
       }
     }
@@ -1428,9 +1621,9 @@ impl FromNapiValue for Uint8ClampedSlice<'_> {
       "Get TypedArray info failed"
     )?;
     if typed_array_type != TypedArrayType::Uint8Clamped as i32 {
-      return Err(Error::new(
-        Status::InvalidArg,
-        format!("Expected $name, got {}", typed_array_type),
+      return Err(typed_array_type_mismatch(
+        "Uint8ClampedArray",
+        typed_array_type,
       ));
     }
     Ok(Self {
@@ -1471,17 +1664,14 @@ impl TypeName for Uint8ClampedSlice<'_> {
 
 impl ValidateNapiValue for Uint8ClampedSlice<'_> {
   unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
-    let mut is_typedarray = false;
-    check_status!(
-      unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typedarray) },
-      "Failed to validate typed buffer"
-    )?;
-    if !is_typedarray {
-      return Err(Error::new(
-        Status::InvalidArg,
-        "Expected a TypedArray value".to_owned(),
-      ));
-    }
+    unsafe {
+      validate_typed_array_type(
+        env,
+        napi_val,
+        TypedArrayType::Uint8Clamped,
+        "Uint8ClampedArray",
+      )?
+    };
     Ok(ptr::null_mut())
   }
 }
@@ -1,5 +1,7 @@
-/// We don't implement `FromNapiValue` for `i64` `u64` `i128` `u128` `isize` `usize` here
+/// We don't implement `FromNapiValue` for `i64` `u64` `isize` `usize` here
 /// Because converting directly from `JsBigInt` to these values may result in a loss of precision and thus unintended behavior
+///
+/// `i128` and `u128` are implemented, they return an error instead if the `BigInt` is out of range
 /// ```rust
 /// use napi::{bindgen_prelude::*, JsBigint};
 ///
@@ -10,7 +12,7 @@
 /// ```
 use std::ptr;
 
-use crate::{check_status, sys};
+use crate::{check_status, sys, Error, Status};
 
 use super::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
 
@@ -185,6 +187,96 @@ pub(crate) unsafe fn u128_with_sign_to_napi_value(
   Ok(raw_value)
 }
 
+/// Read the `BigInt` as (sign_bit, value), return `None` if it doesn't fit into 128 bits
+unsafe fn bigint_to_u128_with_sign(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+) -> crate::Result<Option<(bool, u128)>> {
+  let mut word_count = 0usize;
+  check_status!(
+    unsafe {
+      sys::napi_get_value_bigint_words(
+        env,
+        napi_val,
+        ptr::null_mut(),
+        &mut word_count,
+        ptr::null_mut(),
+      )
+    },
+    "Failed to get the word count of BigInt"
+  )?;
+  if word_count > 2 {
+    return Ok(None);
+  }
+  let mut words = [0u64; 2];
+  let mut sign_bit = 0;
+  check_status!(
+    unsafe {
+      sys::napi_get_value_bigint_words(
+        env,
+        napi_val,
+        &mut sign_bit,
+        &mut word_count,
+        words.as_mut_ptr(),
+      )
+    },
+    "Failed to get the words of BigInt"
+  )?;
+  Ok(Some((
+    sign_bit == 1,
+    words[0] as u128 + ((words[1] as u128) << 64),
+  )))
+}
+
+impl TypeName for i128 {
+  fn type_name() -> &'static str {
+    "i128"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::BigInt
+  }
+}
+
+impl ValidateNapiValue for i128 {}
+
+impl FromNapiValue for i128 {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
+    match unsafe { bigint_to_u128_with_sign(env, napi_val)? } {
+      Some((false, val)) if val <= i128::MAX as u128 => Ok(val as i128),
+      Some((true, val)) if val <= i128::MIN.unsigned_abs() => Ok((val as i128).wrapping_neg()),
+      _ => Err(Error::new(
+        Status::BigintExpected,
+        "BigInt is out of the range of i128".to_owned(),
+      )),
+    }
+  }
+}
+
+impl TypeName for u128 {
+  fn type_name() -> &'static str {
+    "u128"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::BigInt
+  }
+}
+
+impl ValidateNapiValue for u128 {}
+
+impl FromNapiValue for u128 {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
+    match unsafe { bigint_to_u128_with_sign(env, napi_val)? } {
+      Some((false, val)) => Ok(val),
+      _ => Err(Error::new(
+        Status::BigintExpected,
+        "BigInt is out of the range of u128".to_owned(),
+      )),
+    }
+  }
+}
+
 impl ToNapiValue for i128 {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
     let sign_bit = i32::from(val <= 0);
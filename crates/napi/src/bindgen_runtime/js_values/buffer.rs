@@ -1,12 +1,12 @@
+use std::cell::RefCell;
 #[cfg(all(debug_assertions, not(windows)))]
 use std::collections::HashSet;
 use std::ffi::c_void;
-use std::mem;
+use std::mem::{self, ManuallyDrop};
 use std::ops::{Deref, DerefMut};
 use std::ptr::{self, NonNull};
 use std::slice;
-#[cfg(all(debug_assertions, not(windows)))]
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 #[cfg(all(feature = "napi4", not(feature = "noop")))]
 use crate::bindgen_prelude::{CUSTOM_GC_TSFN, CUSTOM_GC_TSFN_DESTROYED, THREADS_CAN_ACCESS_ENV};
@@ -19,6 +19,159 @@ thread_local! {
   pub (crate) static BUFFER_DATA: Mutex<HashSet<*mut u8>> = Default::default();
 }
 
+/// Same as the `Buffer.poolSize` in Node.js
+const BUFFER_POOL_SIZE: usize = 8 * 1024;
+
+thread_local! {
+  /// The chunk that `Buffer::from_vec_pooled` is currently allocating from, and the next free offset in it
+  static BUFFER_POOL: RefCell<Option<(Arc<BufferPoolChunk>, usize)>> = const { RefCell::new(None) };
+}
+
+/// A fixed size allocation shared by many small pooled `Buffer`s.
+///
+/// Every pooled `Buffer` holds an `Arc` to its chunk, the memory is released after all of them are dropped or finalized.
+pub(crate) struct BufferPoolChunk {
+  ptr: NonNull<u8>,
+  capacity: usize,
+}
+
+unsafe impl Send for BufferPoolChunk {}
+unsafe impl Sync for BufferPoolChunk {}
+
+impl BufferPoolChunk {
+  fn new() -> Self {
+    let mut data = ManuallyDrop::new(Vec::<u8>::with_capacity(BUFFER_POOL_SIZE));
+    Self {
+      // SAFETY: the capacity is not zero, so the `Vec` is allocated
+      ptr: unsafe { NonNull::new_unchecked(data.as_mut_ptr()) },
+      capacity: data.capacity(),
+    }
+  }
+}
+
+impl Drop for BufferPoolChunk {
+  fn drop(&mut self) {
+    unsafe { Vec::from_raw_parts(self.ptr.as_ptr(), 0, self.capacity) };
+  }
+}
+
+/// Release the chunk that the current thread is allocating from.
+///
+/// The memory is freed once the `Buffer`s living in it are finalized.
+#[cfg(not(feature = "noop"))]
+pub(crate) fn flush_buffer_pool() {
+  BUFFER_POOL.with(|pool| pool.borrow_mut().take());
+}
+
+/// A bounded pool of the `Vec<u8>` allocations, reused for the `Buffer`s passed to JavaScript repeatedly.
+///
+/// Unlike `Buffer::from_vec_pooled`, which copies the small data into a chunk shared by many buffers,
+/// the allocation checked out from the pool is handed to JavaScript as is,
+/// and it's returned to the pool when the JavaScript `Buffer` is garbage collected, or when the `PooledVec` is dropped.
+/// So the streaming addons which pass a large `Buffer` per chunk don't allocate for every chunk.
+///
+/// It can be cloned and shared between threads, the clones share the same allocations.
+#[derive(Clone)]
+pub struct BufferPool(Arc<BufferPoolInner>);
+
+struct BufferPoolInner {
+  vecs: Mutex<Vec<Vec<u8>>>,
+  max_pooled: usize,
+}
+
+impl BufferPool {
+  /// Keep at most `max_pooled` allocations in the pool, the others returned to a full pool are freed
+  pub fn new(max_pooled: usize) -> Self {
+    Self(Arc::new(BufferPoolInner {
+      vecs: Mutex::new(Vec::with_capacity(max_pooled)),
+      max_pooled,
+    }))
+  }
+
+  /// Check out an empty `Vec<u8>` which can hold at least `capacity` bytes without reallocating.
+  ///
+  /// A new one is allocated if there is no allocation in the pool large enough.
+  pub fn checkout(&self, capacity: usize) -> PooledVec {
+    let pooled = {
+      let mut vecs = self
+        .0
+        .vecs
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+      vecs
+        .iter()
+        .position(|vec| vec.capacity() >= capacity)
+        .map(|index| vecs.swap_remove(index))
+    };
+    PooledVec {
+      data: pooled.unwrap_or_else(|| Vec::with_capacity(capacity)),
+      pool: self.clone(),
+    }
+  }
+
+  /// The number of the allocations waiting in the pool
+  pub fn pooled_count(&self) -> usize {
+    self
+      .0
+      .vecs
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .len()
+  }
+
+  fn recycle(&self, mut data: Vec<u8>) {
+    // the empty `Vec` is not allocated
+    if data.capacity() == 0 {
+      return;
+    }
+    data.clear();
+    let mut vecs = self
+      .0
+      .vecs
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if vecs.len() < self.0.max_pooled {
+      vecs.push(data);
+    }
+  }
+}
+
+/// A `Vec<u8>` checked out from the `BufferPool`, fill it and convert it into a `Buffer`.
+///
+/// It's returned to the pool if it's dropped without being converted.
+pub struct PooledVec {
+  data: Vec<u8>,
+  pool: BufferPool,
+}
+
+impl Deref for PooledVec {
+  type Target = Vec<u8>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.data
+  }
+}
+
+impl DerefMut for PooledVec {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.data
+  }
+}
+
+impl Drop for PooledVec {
+  fn drop(&mut self) {
+    self.pool.recycle(mem::take(&mut self.data));
+  }
+}
+
+impl From<PooledVec> for Buffer {
+  fn from(mut vec: PooledVec) -> Self {
+    let mut buffer = Buffer::from(mem::take(&mut vec.data));
+    buffer.recycle = Some(vec.pool.clone());
+    buffer
+  }
+}
+
 /// Zero copy buffer slice shared between Rust and Node.js.
 ///
 /// It can only be used in non-async context and the lifetime is bound to the fn closure.
@@ -55,13 +208,13 @@ impl<'env> BufferSlice<'env> {
         env.0,
         len,
         inner_ptr.cast(),
-        Some(drop_buffer_slice),
-        Box::into_raw(Box::new(len)).cast(),
+        Some(drop_buffer_vec),
+        data.capacity() as *mut c_void,
         &mut buf,
       )
     };
-    status = if status == sys::Status::napi_no_external_buffers_allowed {
-      unsafe {
+    if status == sys::Status::napi_no_external_buffers_allowed {
+      status = unsafe {
         sys::napi_create_buffer_copy(
           env.0,
           len,
@@ -69,11 +222,11 @@ impl<'env> BufferSlice<'env> {
           ptr::null_mut(),
           &mut buf,
         )
-      }
+      };
     } else {
-      status
-    };
-    mem::forget(data);
+      // the data is owned by the external buffer now
+      mem::forget(data);
+    }
     check_status!(status, "Failed to create buffer slice from data")?;
 
     Ok(Self {
@@ -184,6 +337,41 @@ impl<'env> BufferSlice<'env> {
     })
   }
 
+  /// Create a zero copy `BufferSlice` over the data borrowed from the class instance `this`.
+  ///
+  /// The `Buffer` holds a reference to `this`, so the instance will not be garbage collected before the `Buffer`.
+  ///
+  /// It's used by the `#[napi(zero_copy)]` getters and methods returning `&[u8]` from `&self`.
+  ///
+  /// ## Safety
+  ///
+  /// `data` must be owned by the native object wrapped in `this`,
+  /// and it must not be moved, resized or freed while the instance is alive,
+  /// for example the `Vec<u8>` of a field which is never mutated after the construction.
+  ///
+  /// JavaScript can write into the returned `Buffer`, which changes the `data` in place.
+  pub unsafe fn from_instance_data(env: &Env, this: sys::napi_value, data: &[u8]) -> Result<Self> {
+    if data.is_empty() {
+      return Self::copy_from(env, data);
+    }
+    let mut this_ref = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_reference(env.0, this, 1, &mut this_ref) },
+      "Failed to create reference of the class instance"
+    )?;
+    unsafe {
+      Self::from_external(
+        env,
+        data.as_ptr().cast_mut(),
+        data.len(),
+        this_ref,
+        |env, this_ref| {
+          sys::napi_delete_reference(env.0, this_ref);
+        },
+      )
+    }
+  }
+
   /// Convert a `BufferSlice` to a `Buffer`
   ///
   /// This will perform a `napi_create_reference` internally.
@@ -238,6 +426,13 @@ impl ToNapiValue for &BufferSlice<'_> {
   }
 }
 
+/// The `&[u8]` is copied into a new `Buffer`, mark the method with `#[napi(zero_copy)]` to return it without copying
+impl ToNapiValue for &[u8] {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    BufferSlice::copy_from(&Env::from_raw(env), val).map(|buf| buf.raw_value)
+  }
+}
+
 impl TypeName for BufferSlice<'_> {
   fn type_name() -> &'static str {
     "Buffer"
@@ -297,6 +492,10 @@ pub struct Buffer {
   pub(crate) len: usize,
   pub(crate) capacity: usize,
   raw: Option<(sys::napi_ref, sys::napi_env)>,
+  pool: Option<Arc<BufferPoolChunk>>,
+  zero_copy: bool,
+  /// The allocation is returned to the `BufferPool` when it's dropped
+  recycle: Option<BufferPool>,
 }
 
 impl Drop for Buffer {
@@ -346,8 +545,11 @@ impl Drop for Buffer {
         unsafe { sys::napi_delete_reference(env, ref_) },
         "Failed to delete Buffer reference in drop"
       );
-    } else {
-      unsafe { Vec::from_raw_parts(self.inner.as_ptr(), self.len, self.capacity) };
+    } else if self.pool.is_none() {
+      let data = unsafe { Vec::from_raw_parts(self.inner.as_ptr(), self.len, self.capacity) };
+      if let Some(pool) = self.recycle.take() {
+        pool.recycle(data);
+      }
     }
   }
 }
@@ -387,10 +589,69 @@ impl From<Vec<u8>> for Buffer {
       len,
       capacity,
       raw: None,
+      pool: None,
+      zero_copy: false,
+      recycle: None,
     }
   }
 }
 
+impl Buffer {
+  /// Create a `Buffer` from a `Vec<u8>`, allocating small data from a shared pool.
+  ///
+  /// `Buffer::from(Vec<u8>)` hands the `Vec` allocation to Node.js as is, so every buffer costs one allocation for
+  /// the data plus one boxed finalize hint. Like `Buffer.allocUnsafe` in Node.js, data smaller than half of the
+  /// 8 KiB pool size is instead copied into a chunk shared by many buffers, and the `Vec` is freed right away.
+  /// The chunk is released once all the buffers in it are dropped or garbage collected.
+  ///
+  /// Larger and empty data fall back to `Buffer::from`.
+  pub fn from_vec_pooled(data: Vec<u8>) -> Self {
+    let len = data.len();
+    if len == 0 || len >= BUFFER_POOL_SIZE >> 1 {
+      return Buffer::from(data);
+    }
+    BUFFER_POOL.with(|pool| {
+      let mut pool = pool.borrow_mut();
+      let (chunk, offset) = match pool.as_mut() {
+        Some((chunk, offset)) if *offset + len <= chunk.capacity => (chunk, offset),
+        _ => {
+          let (chunk, offset) = pool.insert((Arc::new(BufferPoolChunk::new()), 0));
+          (chunk, offset)
+        }
+      };
+      // SAFETY: the range `offset..offset + len` is in bounds of the chunk and not used by any other `Buffer`
+      let inner = unsafe {
+        let start = chunk.ptr.as_ptr().add(*offset);
+        ptr::copy_nonoverlapping(data.as_ptr(), start, len);
+        NonNull::new_unchecked(start)
+      };
+      // keep the next buffer 8 bytes aligned
+      *offset = (*offset + len + 7) & !7;
+      Buffer {
+        inner,
+        len,
+        capacity: len,
+        raw: None,
+        pool: Some(chunk.clone()),
+        zero_copy: false,
+        recycle: None,
+      }
+    })
+  }
+
+  /// Create a `Buffer` that takes over the allocation of the `Vec<u8>` without copying the data.
+  ///
+  /// The `Vec` is rebuilt with its original capacity and dropped in the finalizer of the JavaScript `Buffer`,
+  /// the capacity is passed as the finalize hint so nothing else is allocated for the finalizer.
+  /// Unlike `Buffer::from`, it never falls back to copying the data: converting it into a JavaScript value
+  /// fails in the runtimes which don't allow external buffers, like Electron.
+  pub fn from_vec_zero_copy(data: Vec<u8>) -> Self {
+    let mut buffer = Buffer::from(data);
+    buffer.zero_copy = true;
+    buffer
+  }
+}
+
 impl From<Buffer> for Vec<u8> {
   fn from(buf: Buffer) -> Self {
     buf.as_ref().to_vec()
@@ -481,6 +742,9 @@ impl FromNapiValue for Buffer {
       len,
       capacity: len,
       raw: Some((ref_, env)),
+      pool: None,
+      zero_copy: false,
+      recycle: None,
     })
   }
 }
@@ -512,6 +776,17 @@ impl ToNapiValue for Buffer {
         unsafe { sys::napi_create_buffer(env, len, ptr::null_mut(), &mut ret) }
       } else {
         let value_ptr = val.inner.as_ptr();
+        if let Some(chunk) = val.pool.take() {
+          // The chunk is kept alive by the `Arc` passed as finalize hint, nothing left to drop in `val`
+          mem::forget(val);
+          return unsafe { create_pooled_buffer(env, value_ptr, len, chunk) };
+        }
+        if val.zero_copy {
+          let capacity = val.capacity;
+          // The `Vec` is rebuilt and dropped in `drop_buffer_vec`
+          mem::forget(val);
+          return unsafe { create_zero_copy_buffer(env, value_ptr, len, capacity) };
+        }
         let val_box_ptr = Box::into_raw(Box::new(val));
         let mut status = unsafe {
           sys::napi_create_external_buffer(
@@ -560,3 +835,69 @@ impl ValidateNapiValue for Buffer {
     Ok(ptr::null_mut())
   }
 }
+
+unsafe fn create_pooled_buffer(
+  env: sys::napi_env,
+  data: *mut u8,
+  len: usize,
+  chunk: Arc<BufferPoolChunk>,
+) -> Result<sys::napi_value> {
+  let mut ret = ptr::null_mut();
+  let chunk_ptr = Arc::into_raw(chunk);
+  let mut status = unsafe {
+    sys::napi_create_external_buffer(
+      env,
+      len,
+      data.cast(),
+      Some(drop_pooled_buffer),
+      chunk_ptr as *mut c_void,
+      &mut ret,
+    )
+  };
+  if status == napi_sys::Status::napi_no_external_buffers_allowed {
+    let chunk = unsafe { Arc::from_raw(chunk_ptr) };
+    status = unsafe {
+      sys::napi_create_buffer_copy(env, len, data as *const c_void, ptr::null_mut(), &mut ret)
+    };
+    drop(chunk);
+  }
+  check_status!(status, "Failed to create napi buffer")?;
+  Ok(ret)
+}
+
+unsafe fn create_zero_copy_buffer(
+  env: sys::napi_env,
+  data: *mut u8,
+  len: usize,
+  capacity: usize,
+) -> Result<sys::napi_value> {
+  let mut ret = ptr::null_mut();
+  let status = unsafe {
+    sys::napi_create_external_buffer(
+      env,
+      len,
+      data.cast(),
+      Some(drop_buffer_vec),
+      capacity as *mut c_void,
+      &mut ret,
+    )
+  };
+  if status == napi_sys::Status::napi_no_external_buffers_allowed {
+    drop(unsafe { Vec::from_raw_parts(data, len, capacity) });
+    return Err(Error::new(
+      Status::GenericFailure,
+      "External buffers are not allowed in the current runtime, the data can't be passed without copying"
+        .to_owned(),
+    ));
+  }
+  check_status!(status, "Failed to create napi buffer")?;
+  Ok(ret)
+}
+
+unsafe extern "C" fn drop_pooled_buffer(
+  _env: sys::napi_env,
+  _finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  drop(unsafe { Arc::from_raw(finalize_hint as *const BufferPoolChunk) });
+}
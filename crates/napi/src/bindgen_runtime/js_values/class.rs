@@ -1,5 +1,5 @@
-use std::any::type_name;
-use std::ffi::CString;
+use std::any::{type_name, TypeId};
+use std::ffi::{c_void, CString};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
@@ -7,9 +7,10 @@ use std::ptr;
 use crate::{
   bindgen_runtime::{
     raw_finalize_unchecked, FromNapiValue, JsObjectValue, Object, ObjectFinalize, Reference,
-    Result, TypeName, ValidateNapiValue,
+    Result, TypeName, ValidateNapiValue, REFERENCE_MAP,
   },
-  check_status, sys, Env, JsValue, Property, PropertyAttributes, Value, ValueType,
+  check_status, sys, type_of, Env, Error, JsSymbol, JsValue, Property, PropertyAttributes, Status,
+  Value, ValueType,
 };
 
 #[derive(Clone, Copy)]
@@ -47,6 +48,34 @@ impl<'env, T: JsValue<'env>> JsValue<'env> for This<'_, T> {
   }
 }
 
+impl<'env, T: JsValue<'env>> This<'_, T> {
+  /// Get the named property from `This` and convert it into the native type
+  ///
+  /// Returns an error if the property is `undefined` and `V` can't be created from `undefined`.
+  pub fn get_named<V: FromNapiValue>(&self, env: &Env, name: &str) -> Result<V> {
+    let key = CString::new(name)?;
+    let mut raw_value = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_named_property(env.raw(), self.object.raw(), key.as_ptr(), &mut raw_value)
+      },
+      "Failed to get property `{}` from this",
+      name
+    )?;
+    let value_type = type_of!(env.raw(), raw_value)?;
+    unsafe { V::from_napi_value(env.raw(), raw_value) }.map_err(|err| {
+      if value_type == ValueType::Undefined {
+        Error::new(
+          Status::InvalidArg,
+          format!("Property `{}` on this is undefined", name),
+        )
+      } else {
+        err
+      }
+    })
+  }
+}
+
 impl<T: FromNapiValue> FromNapiValue for This<'_, T> {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
     Ok(Self {
@@ -59,7 +88,7 @@ impl<T: FromNapiValue> FromNapiValue for This<'_, T> {
 #[derive(Clone, Copy)]
 pub struct ClassInstance<'env, T: 'env> {
   pub value: sys::napi_value,
-  env: sys::napi_env,
+  env: Env,
   inner: *mut T,
   _phantom: &'env PhantomData<()>,
 }
@@ -67,7 +96,7 @@ pub struct ClassInstance<'env, T: 'env> {
 impl<'env, T: 'env> JsValue<'env> for ClassInstance<'env, T> {
   fn value(&self) -> Value {
     Value {
-      env: self.env,
+      env: self.env.0,
       value: self.value,
       value_type: ValueType::Object,
     }
@@ -81,12 +110,17 @@ impl<'env, T: 'env> ClassInstance<'env, T> {
   pub unsafe fn new(value: sys::napi_value, env: sys::napi_env, inner: *mut T) -> Self {
     Self {
       value,
-      env,
+      env: Env::from_raw(env),
       inner: unsafe { &mut *inner },
       _phantom: &PhantomData,
     }
   }
 
+  /// Get the `Env` that this `ClassInstance` belongs to
+  pub fn env(&self) -> &Env {
+    &self.env
+  }
+
   pub fn as_object<'a>(&self, env: &'a Env) -> Object<'a> {
     Object(
       Value {
@@ -98,6 +132,81 @@ impl<'env, T: 'env> ClassInstance<'env, T> {
     )
   }
 
+  /// Whether the two `ClassInstance`s wrap the same native object.
+  ///
+  /// Only the pointers of the wrapped native objects are compared, no JavaScript is involved.
+  /// Use `js_eq` to check whether they are the same JavaScript object.
+  pub fn ptr_eq(&self, other: &ClassInstance<T>) -> bool {
+    ptr::eq(self.inner, other.inner)
+  }
+
+  /// Replace the wrapped native object with `value` and return the old one, the JavaScript object is kept as is.
+  ///
+  /// The old value is dropped when the returned value is dropped, and the new value is dropped when the JavaScript object is garbage collected.
+  /// The replacement is visible from everywhere holding the same JavaScript object,
+  /// including the other `ClassInstance`s and `Reference`s of it and the methods called from JavaScript.
+  pub fn replace(&mut self, value: T) -> T {
+    std::mem::replace(unsafe { &mut *self.inner }, value)
+  }
+
+  /// The raw pointer of the native object wrapped into the JavaScript object by `napi_wrap`,
+  /// which is what `napi_unwrap` returns for this instance.
+  ///
+  /// It can be passed to the foreign code that needs the native object, such as C libraries.
+  ///
+  /// # Safety
+  ///
+  /// The pointer is only valid while the JavaScript object is alive, it's dangling after the object is garbage collected.
+  /// Hold a `Reference` of the instance if the foreign code keeps the pointer.
+  /// The native object is still owned by the JavaScript object, the foreign code must not free it,
+  /// and it must not be read or written while it's borrowed on the Rust side.
+  pub unsafe fn as_raw_wrapped(&self) -> *mut c_void {
+    self.inner.cast()
+  }
+
+  /// Whether the two `ClassInstance`s are the same JavaScript object, like `a === b` in JavaScript.
+  ///
+  /// Unlike `ptr_eq`, it compares the JavaScript values instead of the wrapped native objects.
+  pub fn js_eq(&self, env: &Env, other: &ClassInstance<T>) -> Result<bool> {
+    let mut result = false;
+    check_status!(
+      unsafe { sys::napi_strict_equals(env.raw(), self.value, other.value, &mut result) },
+      "Failed to compare ClassInstance<{}>",
+      type_name::<T>(),
+    )?;
+    Ok(result)
+  }
+
+  /// Downcast this `ClassInstance` to the `ClassInstance` of another wrapped native type.
+  ///
+  /// Succeeds only if the native object wrapped in the JavaScript value is exactly `U`.
+  pub fn downcast<U: 'static>(&self) -> Result<ClassInstance<'env, U>> {
+    let mut wrapped_value = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_unwrap(self.env.0, self.value, &mut wrapped_value) },
+      "Unwrap value [{}] from class failed",
+      type_name::<U>(),
+    )?;
+    let type_id = REFERENCE_MAP.with(|cell| {
+      cell.borrow_mut(|map| map.get(&wrapped_value).map(|(_, _, _, type_id)| *type_id))
+    });
+    if type_id != Some(TypeId::of::<U>()) {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Invalid argument, {} on unwrap is not the type of wrapped object",
+          type_name::<U>()
+        ),
+      ));
+    }
+    Ok(ClassInstance {
+      value: self.value,
+      env: self.env,
+      inner: wrapped_value.cast(),
+      _phantom: &PhantomData,
+    })
+  }
+
   /// Assign this `ClassInstance` to another `This` object
   ///
   /// Extends the lifetime of `ClassInstance` to `This`.
@@ -113,7 +222,7 @@ impl<'env, T: 'env> ClassInstance<'env, T> {
     let name = CString::new(name)?;
     check_status!(
       unsafe {
-        sys::napi_set_named_property(self.env, this.object.raw(), name.as_ptr(), self.value)
+        sys::napi_set_named_property(self.env.0, this.object.raw(), name.as_ptr(), self.value)
       },
       "Failed to assign ClassInstance<{}> to this",
       std::any::type_name::<T>()
@@ -127,6 +236,32 @@ impl<'env, T: 'env> ClassInstance<'env, T> {
     Ok(val)
   }
 
+  /// Assign this `ClassInstance` to another `This` object under a `Symbol` key
+  ///
+  /// Extends the lifetime of `ClassInstance` to `This`.
+  pub fn assign_to_this_symbol<'a, 'this, U>(
+    &'a self,
+    key: &JsSymbol,
+    this: &'a mut This<U>,
+  ) -> Result<ClassInstance<'this, T>>
+  where
+    'this: 'env,
+    U: FromNapiValue + JsValue<'this>,
+  {
+    check_status!(
+      unsafe { sys::napi_set_property(self.env.0, this.object.raw(), key.raw(), self.value) },
+      "Failed to assign ClassInstance<{}> to this",
+      std::any::type_name::<T>()
+    )?;
+    let val: ClassInstance<'this, T> = ClassInstance {
+      value: self.value,
+      env: self.env,
+      inner: self.inner,
+      _phantom: &PhantomData,
+    };
+    Ok(val)
+  }
+
   /// Assign this `ClassInstance` to another `This` object with `PropertyAttributes`.
   ///
   /// Extends the lifetime of `ClassInsatnce` to `This`.
@@ -148,7 +283,7 @@ impl<'env, T: 'env> ClassInstance<'env, T> {
     check_status!(
       unsafe {
         sys::napi_define_properties(
-          self.env,
+          self.env.0,
           this.object.value().value,
           1,
           [property.raw()].as_ptr(),
@@ -204,7 +339,7 @@ impl<'env, T: 'env> FromNapiValue for ClassInstance<'env, T> {
     Ok(Self {
       value: napi_val,
       inner: Box::leak(value),
-      env,
+      env: Env::from_raw(env),
       _phantom: &PhantomData,
     })
   }
@@ -234,6 +369,26 @@ pub trait JavaScriptClassExt: Sized {
   fn into_instance(self, env: &Env) -> Result<ClassInstance<Self>>;
   fn into_reference(self, env: Env) -> Result<Reference<Self>>;
   fn instance_of<'env, V: JsValue<'env>>(env: &Env, value: &V) -> Result<bool>;
+
+  /// Cast the `value` into `ClassInstance<Self>` if it's an instance of this class
+  ///
+  /// Returns an `InvalidArg` error naming `Self` if it isn't.
+  fn cast_instance<'env, V: JsValue<'env>>(
+    env: &'env Env,
+    value: &V,
+  ) -> Result<ClassInstance<'env, Self>>
+  where
+    Self: 'env,
+  {
+    if Self::instance_of(env, value)? {
+      unsafe { ClassInstance::from_napi_value(env.raw(), value.raw()) }
+    } else {
+      Err(Error::new(
+        Status::InvalidArg,
+        format!("Value is not instanceof class `{}`", type_name::<Self>()),
+      ))
+    }
+  }
 }
 
 /// # Safety
@@ -282,7 +437,12 @@ pub unsafe fn new_instance<T: 'static + ObjectFinalize>(
   Reference::<T>::add_ref(
     env,
     wrapped_value,
-    (wrapped_value, object_ref, finalize_callbacks_ptr),
+    (
+      wrapped_value,
+      object_ref,
+      finalize_callbacks_ptr,
+      TypeId::of::<T>(),
+    ),
   );
   Ok(result)
 }
@@ -0,0 +1,268 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::{
+  bindgen_prelude::{
+    ArrayBuffer, FromNapiValue, JsObjectValue, JsValue, TypeName, ValidateNapiValue,
+  },
+  check_status, sys, Env, Error, Result, Status, Value, ValueType,
+};
+
+#[derive(Clone, Copy)]
+/// Represents a JavaScript DataView
+///
+/// The multi-bytes values are read and written at any byte offset with the explicit endianness,
+/// which is handy to parse the binary protocols.
+pub struct DataView<'env> {
+  pub(crate) value: Value,
+  data: *mut u8,
+  byte_offset: usize,
+  length: usize,
+  _marker: PhantomData<&'env ()>,
+}
+
+impl<'env> JsValue<'env> for DataView<'env> {
+  fn value(&self) -> Value {
+    self.value
+  }
+}
+
+impl<'env> JsObjectValue<'env> for DataView<'env> {}
+
+impl TypeName for DataView<'_> {
+  fn type_name() -> &'static str {
+    "DataView"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for DataView<'_> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    let mut is_dataview = false;
+    check_status!(
+      unsafe { sys::napi_is_dataview(env, napi_val, &mut is_dataview) },
+      "Failed to check if value is DataView"
+    )?;
+    if !is_dataview {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a DataView value".to_owned(),
+      ));
+    }
+    Ok(ptr::null_mut())
+  }
+}
+
+impl FromNapiValue for DataView<'_> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { Self::validate(env, napi_val)? };
+    let mut length = 0;
+    let mut data = ptr::null_mut();
+    let mut byte_offset = 0;
+    check_status!(
+      unsafe {
+        sys::napi_get_dataview_info(
+          env,
+          napi_val,
+          &mut length,
+          &mut data,
+          ptr::null_mut(),
+          &mut byte_offset,
+        )
+      },
+      "Get DataView info failed"
+    )?;
+    Ok(Self {
+      value: Value {
+        env,
+        value: napi_val,
+        value_type: ValueType::Object,
+      },
+      data: data.cast(),
+      byte_offset,
+      length,
+      _marker: PhantomData,
+    })
+  }
+}
+
+macro_rules! impl_dataview_accessors {
+  ($($ty:ident: $get:ident, $set:ident, $js_name:literal;)*) => {
+    impl DataView<'_> {
+      $(
+        #[doc = concat!("Read a `", stringify!($ty), "` at `byte_offset`, like `DataView.prototype.get", $js_name, "` in JavaScript.")]
+        pub fn $get(&self, byte_offset: usize, little_endian: bool) -> Result<$ty> {
+          let mut bytes = [0; size_of::<$ty>()];
+          bytes.copy_from_slice(self.bytes_at(byte_offset, size_of::<$ty>())?);
+          Ok(if little_endian {
+            $ty::from_le_bytes(bytes)
+          } else {
+            $ty::from_be_bytes(bytes)
+          })
+        }
+
+        #[doc = concat!("Write a `", stringify!($ty), "` at `byte_offset`, like `DataView.prototype.set", $js_name, "` in JavaScript.")]
+        pub fn $set(&mut self, byte_offset: usize, value: $ty, little_endian: bool) -> Result<()> {
+          let bytes = if little_endian {
+            value.to_le_bytes()
+          } else {
+            value.to_be_bytes()
+          };
+          self
+            .bytes_at_mut(byte_offset, size_of::<$ty>())?
+            .copy_from_slice(&bytes);
+          Ok(())
+        }
+      )*
+    }
+  };
+}
+
+impl_dataview_accessors! {
+  u16: get_u16, set_u16, "Uint16";
+  i16: get_i16, set_i16, "Int16";
+  u32: get_u32, set_u32, "Uint32";
+  i32: get_i32, set_i32, "Int32";
+  f32: get_f32, set_f32, "Float32";
+  f64: get_f64, set_f64, "Float64";
+  u64: get_u64, set_u64, "BigUint64";
+  i64: get_i64, set_i64, "BigInt64";
+}
+
+impl<'env> DataView<'env> {
+  /// Create a `DataView` over `length` bytes of the `arraybuffer` starting from `byte_offset`,
+  /// like `new DataView(arraybuffer, byteOffset, length)` in JavaScript.
+  pub fn new(
+    env: &'env Env,
+    arraybuffer: &ArrayBuffer<'env>,
+    byte_offset: usize,
+    length: usize,
+  ) -> Result<Self> {
+    let mut value = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_dataview(
+          env.raw(),
+          length,
+          arraybuffer.value().value,
+          byte_offset,
+          &mut value,
+        )
+      },
+      "Failed to create DataView"
+    )?;
+    unsafe { Self::from_napi_value(env.raw(), value) }
+  }
+
+  /// Create a `DataView` over a new `ArrayBuffer` holding the `data`.
+  pub fn from_data<D: Into<Vec<u8>>>(env: &'env Env, data: D) -> Result<Self> {
+    let arraybuffer = ArrayBuffer::from_data(env, data)?;
+    Self::new(env, &arraybuffer, 0, arraybuffer.len())
+  }
+
+  /// The `ArrayBuffer` referenced by this `DataView`
+  pub fn arraybuffer(&self) -> Result<ArrayBuffer<'env>> {
+    let mut arraybuffer = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_dataview_info(
+          self.value.env,
+          self.value.value,
+          ptr::null_mut(),
+          ptr::null_mut(),
+          &mut arraybuffer,
+          ptr::null_mut(),
+        )
+      },
+      "Get DataView info failed"
+    )?;
+    unsafe { ArrayBuffer::from_napi_value(self.value.env, arraybuffer) }
+  }
+
+  /// The offset of this `DataView` from the start of its `ArrayBuffer` in bytes
+  pub fn byte_offset(&self) -> usize {
+    self.byte_offset
+  }
+
+  /// The length of this `DataView` in bytes
+  pub fn len(&self) -> usize {
+    self.length
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.length == 0
+  }
+
+  /// The bytes viewed by this `DataView`, starting from its `byte_offset`
+  ///
+  /// # Safety
+  ///
+  /// The same bytes can be written through the copies of this `DataView`, the other views of the `ArrayBuffer` and JavaScript.
+  /// The caller must make sure nothing writes the bytes while the returned slice is alive.
+  pub unsafe fn as_slice(&self) -> &[u8] {
+    if self.data.is_null() || self.length == 0 {
+      return &[];
+    }
+    unsafe { std::slice::from_raw_parts(self.data, self.length) }
+  }
+
+  /// Mutable bytes viewed by this `DataView`, the changes are visible from JavaScript without copying
+  ///
+  /// # Safety
+  ///
+  /// The caller must make sure nothing else reads or writes the bytes while the returned slice is alive,
+  /// including the copies of this `DataView`, the other views of the `ArrayBuffer` and JavaScript.
+  pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+    if self.data.is_null() || self.length == 0 {
+      return &mut [];
+    }
+    unsafe { std::slice::from_raw_parts_mut(self.data, self.length) }
+  }
+
+  /// Same as `DataView.prototype.getUint8` in JavaScript
+  pub fn get_u8(&self, byte_offset: usize) -> Result<u8> {
+    Ok(self.bytes_at(byte_offset, 1)?[0])
+  }
+
+  /// Same as `DataView.prototype.setUint8` in JavaScript
+  pub fn set_u8(&mut self, byte_offset: usize, value: u8) -> Result<()> {
+    self.bytes_at_mut(byte_offset, 1)?[0] = value;
+    Ok(())
+  }
+
+  /// Same as `DataView.prototype.getInt8` in JavaScript
+  pub fn get_i8(&self, byte_offset: usize) -> Result<i8> {
+    Ok(self.get_u8(byte_offset)? as i8)
+  }
+
+  /// Same as `DataView.prototype.setInt8` in JavaScript
+  pub fn set_i8(&mut self, byte_offset: usize, value: i8) -> Result<()> {
+    self.set_u8(byte_offset, value as u8)
+  }
+
+  // the returned bytes are only alive in the getters and setters
+  fn bytes_at(&self, byte_offset: usize, size: usize) -> Result<&[u8]> {
+    unsafe { self.as_slice() }
+      .get(byte_offset..byte_offset.saturating_add(size))
+      .ok_or_else(|| out_of_bounds(byte_offset, size, self.length))
+  }
+
+  fn bytes_at_mut(&mut self, byte_offset: usize, size: usize) -> Result<&mut [u8]> {
+    let length = self.length;
+    unsafe { self.as_mut_slice() }
+      .get_mut(byte_offset..byte_offset.saturating_add(size))
+      .ok_or_else(|| out_of_bounds(byte_offset, size, length))
+  }
+}
+
+fn out_of_bounds(byte_offset: usize, size: usize, length: usize) -> Error {
+  Error::new(
+    Status::InvalidArg,
+    format!(
+      "Reading or writing {size} bytes at offset {byte_offset} is outside the bounds of the DataView with length {length}"
+    ),
+  )
+}
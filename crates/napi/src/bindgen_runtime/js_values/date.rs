@@ -29,6 +29,7 @@ impl<Tz: TimeZone> ValidateNapiValue for DateTime<Tz> {
   }
 }
 
+/// The `NaiveDateTime` is treated as UTC, and the sub-millisecond part is truncated.
 impl ToNapiValue for NaiveDateTime {
   unsafe fn to_napi_value(env: sys::napi_env, val: NaiveDateTime) -> Result<sys::napi_value> {
     let mut ptr = std::ptr::null_mut();
@@ -120,6 +121,8 @@ impl FromNapiValue for NaiveDateTime {
   }
 }
 
+/// JavaScript `Date` only has millisecond precision,
+/// the sub-millisecond part of the `DateTime` is truncated.
 impl<Tz: TimeZone> ToNapiValue for DateTime<Tz> {
   unsafe fn to_napi_value(env: sys::napi_env, val: DateTime<Tz>) -> Result<sys::napi_value> {
     let mut ptr = std::ptr::null_mut();
@@ -134,6 +137,7 @@ impl<Tz: TimeZone> ToNapiValue for DateTime<Tz> {
   }
 }
 
+/// Returns `DateExpected` error for the invalid `Date`, whose time value is `NaN`.
 impl<Tz: TimeZone> FromNapiValue for DateTime<Tz>
 where
   DateTime<Tz>: From<DateTime<Local>>,
@@ -146,6 +150,13 @@ where
       "Failed to convert napi value into rust type `DateTime`",
     )?;
 
+    if milliseconds_since_epoch_utc.is_nan() {
+      return Err(Error::new(
+        Status::DateExpected,
+        "Found invalid date".to_owned(),
+      ));
+    }
+
     match Local.timestamp_millis_opt(milliseconds_since_epoch_utc as i64) {
       LocalResult::Single(dt) => Ok(dt.into()),
       _ => Err(Error::new(
@@ -22,13 +22,60 @@ impl<T> From<Either<T, Null>> for Option<T> {
   }
 }
 
+/// Which variant of `Either`, `Either3` ... `Either26` is holding the value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherVariant {
+  A,
+  B,
+  C,
+  D,
+  E,
+  F,
+  G,
+  H,
+  I,
+  J,
+  K,
+  L,
+  M,
+  N,
+  O,
+  P,
+  Q,
+  R,
+  S,
+  T,
+  U,
+  V,
+  W,
+  X,
+  Y,
+  Z,
+}
+
 macro_rules! either_n {
-  ( $either_name:ident, $( $parameter:ident ),+ $( , )* ) => {
+  ( $either_name:ident, $( $parameter:ident => $is_parameter:ident ),+ $( , )* ) => {
     #[derive(Debug, Clone, Copy)]
     pub enum $either_name< $( $parameter ),+ > {
       $( $parameter ( $parameter ) ),+
     }
 
+    impl< $( $parameter ),+ > $either_name < $( $parameter ),+ > {
+      /// Get the variant of the value sent from JavaScript, without extracting it
+      pub fn variant(&self) -> EitherVariant {
+        match &self {
+          $( Self:: $parameter (_) => EitherVariant:: $parameter ),+
+        }
+      }
+
+      $(
+        #[doc = concat!("Whether the value is `", stringify!($either_name), "::", stringify!($parameter), "`")]
+        pub fn $is_parameter(&self) -> bool {
+          matches!(self, Self:: $parameter (_))
+        }
+      )+
+    }
+
     impl< $( $parameter ),+ > TypeName for $either_name < $( $parameter ),+ >
       where $( $parameter: TypeName ),+
     {
@@ -153,31 +200,31 @@ macro_rules! either_n {
   };
 }
 
-either_n!(Either, A, B);
-either_n!(Either3, A, B, C);
-either_n!(Either4, A, B, C, D);
-either_n!(Either5, A, B, C, D, E);
-either_n!(Either6, A, B, C, D, E, F);
-either_n!(Either7, A, B, C, D, E, F, G);
-either_n!(Either8, A, B, C, D, E, F, G, H);
-either_n!(Either9, A, B, C, D, E, F, G, H, I);
-either_n!(Either10, A, B, C, D, E, F, G, H, I, J);
-either_n!(Either11, A, B, C, D, E, F, G, H, I, J, K);
-either_n!(Either12, A, B, C, D, E, F, G, H, I, J, K, L);
-either_n!(Either13, A, B, C, D, E, F, G, H, I, J, K, L, M);
-either_n!(Either14, A, B, C, D, E, F, G, H, I, J, K, L, M, N);
-either_n!(Either15, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
-either_n!(Either16, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
-either_n!(Either17, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q);
-either_n!(Either18, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R);
-either_n!(Either19, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S);
-either_n!(Either20, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T);
-either_n!(Either21, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U);
-either_n!(Either22, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V);
-either_n!(Either23, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W);
-either_n!(Either24, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X);
-either_n!(Either25, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y);
-either_n!(Either26, A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z);
+either_n!(Either, A => is_a, B => is_b);
+either_n!(Either3, A => is_a, B => is_b, C => is_c);
+either_n!(Either4, A => is_a, B => is_b, C => is_c, D => is_d);
+either_n!(Either5, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e);
+either_n!(Either6, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f);
+either_n!(Either7, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g);
+either_n!(Either8, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h);
+either_n!(Either9, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i);
+either_n!(Either10, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j);
+either_n!(Either11, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k);
+either_n!(Either12, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l);
+either_n!(Either13, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m);
+either_n!(Either14, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n);
+either_n!(Either15, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o);
+either_n!(Either16, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p);
+either_n!(Either17, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q);
+either_n!(Either18, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r);
+either_n!(Either19, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s);
+either_n!(Either20, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s, T => is_t);
+either_n!(Either21, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s, T => is_t, U => is_u);
+either_n!(Either22, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s, T => is_t, U => is_u, V => is_v);
+either_n!(Either23, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s, T => is_t, U => is_u, V => is_v, W => is_w);
+either_n!(Either24, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s, T => is_t, U => is_u, V => is_v, W => is_w, X => is_x);
+either_n!(Either25, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s, T => is_t, U => is_u, V => is_v, W => is_w, X => is_x, Y => is_y);
+either_n!(Either26, A => is_a, B => is_b, C => is_c, D => is_d, E => is_e, F => is_f, G => is_g, H => is_h, I => is_i, J => is_j, K => is_k, L => is_l, M => is_m, N => is_n, O => is_o, P => is_p, Q => is_q, R => is_r, S => is_s, T => is_t, U => is_u, V => is_v, W => is_w, X => is_x, Y => is_y, Z => is_z);
 
 fn silence_rejected_promise(env: sys::napi_env, promise: sys::napi_value) -> crate::Result<()> {
   let mut catch_method = std::ptr::null_mut();
@@ -11,7 +11,7 @@ use crate::{check_status, sys, Env, Error, Result, Status};
 #[repr(C)]
 pub struct External<T: 'static> {
   type_id: TypeId,
-  obj: T,
+  obj: Option<T>,
   size_hint: usize,
   pub adjusted_size: i64,
 }
@@ -41,7 +41,7 @@ impl<T: 'static> External<T> {
   pub fn new(value: T) -> Self {
     Self {
       type_id: TypeId::of::<T>(),
-      obj: value,
+      obj: Some(value),
       size_hint: 0,
       adjusted_size: 0,
     }
@@ -65,20 +65,24 @@ impl<T: 'static> External<T> {
 
   /// Turn a raw pointer (from napi) pointing to an External into a mutable reference to the inner object.
   ///
+  /// Return `None` if the inner object has been taken by `External::take`.
+  ///
   /// # Safety
   /// The `unknown_tagged_object` raw pointer must point to an `External<T>` struct.
   pub unsafe fn inner_from_raw_mut(
     unknown_tagged_object: *mut std::ffi::c_void,
   ) -> Option<&'static mut T> {
-    Self::from_raw_impl(unknown_tagged_object).map(|external| &mut external.obj)
+    Self::from_raw_impl(unknown_tagged_object).and_then(|external| external.obj.as_mut())
   }
 
   /// Turn a raw pointer (from napi) pointing to an External into a reference inner object.
   ///
+  /// Return `None` if the inner object has been taken by `External::take`.
+  ///
   /// # Safety
   /// The `unknown_tagged_object` raw pointer must point to an `External<T>` struct.
   pub unsafe fn inner_from_raw(unknown_tagged_object: *mut std::ffi::c_void) -> Option<&'static T> {
-    Self::from_raw_impl(unknown_tagged_object).map(|external| &external.obj)
+    Self::from_raw_impl(unknown_tagged_object).and_then(|external| external.obj.as_ref())
   }
 
   /// `size_hint` is a value to tell Node.js GC how much memory is used by this `External` object.
@@ -89,12 +93,44 @@ impl<T: 'static> External<T> {
   pub fn new_with_size_hint(value: T, size_hint: usize) -> Self {
     Self {
       type_id: TypeId::of::<T>(),
-      obj: value,
+      obj: Some(value),
       size_hint,
       adjusted_size: 0,
     }
   }
 
+  /// Take the inner object out, so it can be dropped eagerly instead of waiting for the GC.
+  ///
+  /// The JavaScript `External` value is still alive after this, but it's invalidated,
+  /// converting it back to `External<T>` will return an error. The finalizer only frees the empty `External` later.
+  /// The memory reported by `new_with_size_hint` is released here, so the finalizer doesn't release it again.
+  pub fn take(&mut self, env: &Env) -> Result<T> {
+    let obj = self.obj.take().ok_or_else(taken_error::<T>)?;
+    #[cfg(not(target_family = "wasm"))]
+    if self.size_hint != 0 {
+      let mut adjusted = 0i64;
+      check_status!(
+        unsafe { sys::napi_adjust_external_memory(env.0, -(self.size_hint as i64), &mut adjusted) },
+        "Adjust external memory failed"
+      )?;
+      self.size_hint = 0;
+      self.adjusted_size = adjusted;
+    }
+    #[cfg(target_family = "wasm")]
+    let _ = env;
+    Ok(obj)
+  }
+
+  /// Get a reference to the inner object, return an error if it has been taken by `External::take`.
+  pub fn get(&self) -> Result<&T> {
+    self.obj.as_ref().ok_or_else(taken_error::<T>)
+  }
+
+  /// Get a mutable reference to the inner object, return an error if it has been taken by `External::take`.
+  pub fn get_mut(&mut self) -> Result<&mut T> {
+    self.obj.as_mut().ok_or_else(taken_error::<T>)
+  }
+
   /// convert `External<T>` to `Unknown`
   pub fn into_unknown(self, env: &Env) -> Result<Unknown> {
     let napi_value = unsafe { ToNapiValue::to_napi_value(env.0, self)? };
@@ -114,6 +150,7 @@ impl<T: 'static> FromNapiMutRef for External<T> {
     )?;
 
     match Self::from_raw_impl(unknown_tagged_object) {
+      Some(external) if external.obj.is_none() => Err(taken_error::<T>()),
       Some(external) => Ok(external),
       None => Err(Error::new(
         Status::InvalidArg,
@@ -147,15 +184,33 @@ impl<T: 'static> FromNapiValue for &External<T> {
   }
 }
 
+fn taken_error<T>() -> Error {
+  Error::new(
+    Status::InvalidArg,
+    format!(
+      "<{}> on `External` has been taken",
+      std::any::type_name::<T>()
+    ),
+  )
+}
+
+/// Panics if the inner object has been taken by `External::take`, use `External::get` to check it.
 impl<T: 'static> AsRef<T> for External<T> {
   fn as_ref(&self) -> &T {
-    &self.obj
+    self
+      .obj
+      .as_ref()
+      .expect("The inner object of `External` has been taken")
   }
 }
 
+/// Panics if the inner object has been taken by `External::take`, use `External::get_mut` to check it.
 impl<T: 'static> AsMut<T> for External<T> {
   fn as_mut(&mut self) -> &mut T {
-    &mut self.obj
+    self
+      .obj
+      .as_mut()
+      .expect("The inner object of `External` has been taken")
   }
 }
 
@@ -177,15 +232,14 @@ impl<T: 'static> ToNapiValue for External<T> {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
     let mut napi_value = std::ptr::null_mut();
     let size_hint = val.size_hint as i64;
-    let size_hint_ptr = Box::into_raw(Box::new(size_hint));
     let obj_ptr = Box::into_raw(Box::new(val));
     check_status!(
       unsafe {
         sys::napi_create_external(
           env,
           obj_ptr.cast(),
-          Some(crate::raw_finalize::<External<T>>),
-          size_hint_ptr.cast(),
+          Some(finalize_external::<T>),
+          std::ptr::null_mut(),
           &mut napi_value,
         )
       },
@@ -216,3 +270,26 @@ impl<T: 'static> ToNapiValue for External<T> {
     Ok(napi_value)
   }
 }
+
+/// The memory reported by `size_hint` is released from the `External` itself,
+/// because `External::take` may have released it already.
+unsafe extern "C" fn finalize_external<T: 'static>(
+  env: sys::napi_env,
+  finalize_data: *mut std::ffi::c_void,
+  _finalize_hint: *mut std::ffi::c_void,
+) {
+  let external = unsafe { Box::from_raw(finalize_data as *mut External<T>) };
+  #[cfg(not(target_family = "wasm"))]
+  if external.size_hint != 0 {
+    let mut adjusted = 0i64;
+    let status =
+      unsafe { sys::napi_adjust_external_memory(env, -(external.size_hint as i64), &mut adjusted) };
+    debug_assert!(
+      status == sys::Status::napi_ok,
+      "Calling napi_adjust_external_memory failed"
+    );
+  }
+  #[cfg(target_family = "wasm")]
+  let _ = env;
+  drop(external);
+}
@@ -286,6 +286,74 @@ impl<Args: JsValuesTupleIntoVec, Return: FromNapiValue> Function<'_, Args, Retur
     unsafe { Return::from_napi_value(self.env, raw_return) }
   }
 
+  /// Call the JavaScript function with the `args` spread as the arguments, like `fn.apply(this, args)` in JavaScript.
+  ///
+  /// It's for calling with the arguments count only known at runtime, the `Args` of this `Function` is ignored.
+  pub fn apply_spread<Context: ToNapiValue, T: ToNapiValue>(
+    &self,
+    this: Context,
+    args: Vec<T>,
+  ) -> Result<Return> {
+    let raw_this = unsafe { Context::to_napi_value(self.env, this) }?;
+    let raw_args = args
+      .into_iter()
+      .map(|arg| unsafe { T::to_napi_value(self.env, arg) })
+      .collect::<Result<Vec<_>>>()?;
+    self.call_with_raw_args(raw_this, &raw_args)
+  }
+
+  /// Same as `apply_spread`, but `this` in the JavaScript function will be `undefined`.
+  pub fn call_spread<T: ToNapiValue>(&self, args: Vec<T>) -> Result<Return> {
+    self.apply_spread((), args)
+  }
+
+  /// Call the JavaScript function without arguments, `this` in the JavaScript function will be `undefined`.
+  ///
+  /// `call0`, `call1` and `call2` ignore the `Args` of this `Function`, and don't allocate the arguments on the heap.
+  pub fn call0(&self) -> Result<Return> {
+    let raw_this = unsafe { <()>::to_napi_value(self.env, ()) }?;
+    self.call_with_raw_args(raw_this, &[])
+  }
+
+  /// Call the JavaScript function with one argument, `this` in the JavaScript function will be `undefined`.
+  pub fn call1<A: ToNapiValue>(&self, a: A) -> Result<Return> {
+    let raw_this = unsafe { <()>::to_napi_value(self.env, ()) }?;
+    let raw_args = [unsafe { A::to_napi_value(self.env, a) }?];
+    self.call_with_raw_args(raw_this, &raw_args)
+  }
+
+  /// Call the JavaScript function with two arguments, `this` in the JavaScript function will be `undefined`.
+  pub fn call2<A: ToNapiValue, B: ToNapiValue>(&self, a: A, b: B) -> Result<Return> {
+    let raw_this = unsafe { <()>::to_napi_value(self.env, ()) }?;
+    let raw_args = [unsafe { A::to_napi_value(self.env, a) }?, unsafe {
+      B::to_napi_value(self.env, b)
+    }?];
+    self.call_with_raw_args(raw_this, &raw_args)
+  }
+
+  fn call_with_raw_args(
+    &self,
+    raw_this: sys::napi_value,
+    raw_args: &[sys::napi_value],
+  ) -> Result<Return> {
+    let mut raw_return = ptr::null_mut();
+    check_pending_exception!(
+      self.env,
+      unsafe {
+        sys::napi_call_function(
+          self.env,
+          raw_this,
+          self.value,
+          raw_args.len(),
+          raw_args.as_ptr(),
+          &mut raw_return,
+        )
+      },
+      "Call Function failed"
+    )?;
+    unsafe { Return::from_napi_value(self.env, raw_return) }
+  }
+
   /// Call `Function.bind`
   pub fn bind<T: ToNapiValue>(&self, this: T) -> Result<Function<'_, Args, Return>> {
     let raw_this = unsafe { T::to_napi_value(self.env, this) }?;
@@ -1,4 +1,5 @@
 use std::any::{type_name, TypeId};
+use std::collections::HashMap;
 #[cfg(feature = "napi6")]
 use std::convert::TryFrom;
 use std::ffi::{c_void, CString};
@@ -6,11 +7,11 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::{
-  bindgen_prelude::*, check_status, raw_finalize, sys, type_of, Callback, JsValue, Ref,
-  TaggedObject, Value, ValueType,
+  bindgen_prelude::*, check_pending_exception, check_status, raw_finalize_tagged_object, sys,
+  type_of, Callback, JsValue, Ref, TaggedObject, Value, ValueType,
 };
 #[cfg(feature = "napi5")]
-use crate::{Env, PropertyClosures};
+use crate::{Env, PropertyClosuresData};
 
 pub trait JsObjectValue<'env>: JsValue<'env> {
   fn set_property<'k, 'v, K, V>(&mut self, key: K, value: V) -> Result<()>
@@ -213,6 +214,51 @@ pub trait JsObjectValue<'env>: JsValue<'env> {
     Ok(result)
   }
 
+  /// Read back the own property `key` like `Object.getOwnPropertyDescriptor` in JavaScript.
+  ///
+  /// Returns `None` if this object doesn't have the own property `key`, the properties from the prototype chain are not included.
+  fn get_property_descriptor(&self, key: &str) -> Result<Option<PropertyDescriptor>> {
+    let env = self.value().env;
+    let js_env = Env::from_raw(env);
+    let object_class = js_env.get_global_object("Object")?;
+    let get_own_property_descriptor =
+      object_class.get_named_property_unchecked::<Unknown>("getOwnPropertyDescriptor")?;
+    let mut js_key = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_create_string_utf8(env, key.as_ptr().cast(), key.len() as isize, &mut js_key)
+    })?;
+    let mut descriptor = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_call_function(
+          env,
+          object_class.raw(),
+          get_own_property_descriptor.raw(),
+          2,
+          [self.value().value, js_key].as_ptr(),
+          &mut descriptor,
+        )
+      },
+      "Failed to get the property descriptor of `{}`",
+      key
+    )?;
+    if type_of!(env, descriptor)? == ValueType::Undefined {
+      return Ok(None);
+    }
+    let descriptor = Object::from_raw(env, descriptor);
+    let has_getter = descriptor.get::<Unknown>("get")?.is_some();
+    let has_setter = descriptor.get::<Unknown>("set")?.is_some();
+    Ok(Some(PropertyDescriptor {
+      enumerable: descriptor.get_named_property_unchecked("enumerable")?,
+      configurable: descriptor.get_named_property_unchecked("configurable")?,
+      writable: descriptor.get::<bool>("writable")?.unwrap_or(false),
+      // `{ get: undefined }` is an accessor property too
+      is_accessor: descriptor.has_own_property("get")? || descriptor.has_own_property("set")?,
+      has_getter,
+      has_setter,
+    }))
+  }
+
   fn get_property_names(&self) -> Result<Object<'env>> {
     let mut raw_value = ptr::null_mut();
     let env = self.value().env;
@@ -227,11 +273,7 @@ pub trait JsObjectValue<'env>: JsValue<'env> {
     let env = self.value().env;
     let mut raw_ref = ptr::null_mut();
     check_status!(unsafe { sys::napi_create_reference(env, self.value().value, 1, &mut raw_ref) })?;
-    Ok(Ref {
-      raw_ref,
-      taken: false,
-      _phantom: PhantomData,
-    })
+    Ok(Ref::from_raw(env, raw_ref))
   }
 
   /// <https://nodejs.org/api/n-api.html#n_api_napi_get_all_property_names>
@@ -258,6 +300,35 @@ pub trait JsObjectValue<'env>: JsValue<'env> {
     Ok(Object::from_raw(env, properties_value))
   }
 
+  /// Same as `get_all_property_names`, but collect the names into a `Vec<String>`
+  ///
+  /// Numeric keys are converted to strings with `KeyConversion::NumbersToStrings`.
+  /// `Symbol` keys can't be represented as `String`, so they are skipped, use `get_all_property_names` to get them.
+  #[cfg(feature = "napi6")]
+  fn property_names(&self, mode: KeyCollectionMode, filter: KeyFilter) -> Result<Vec<String>> {
+    let env = self.value().env;
+    let names = self.get_all_property_names(mode, filter, KeyConversion::NumbersToStrings)?;
+    let mut len = 0;
+    check_status!(
+      unsafe { sys::napi_get_array_length(env, names.0.value, &mut len) },
+      "Get length of property names failed"
+    )?;
+    let mut ret = Vec::with_capacity(len as usize);
+    for i in 0..len {
+      let mut name = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_get_element(env, names.0.value, i, &mut name) },
+        "Get property name at index {} failed",
+        i
+      )?;
+      if type_of!(env, name)? != ValueType::String {
+        continue;
+      }
+      ret.push(unsafe { String::from_napi_value(env, name)? });
+    }
+    Ok(ret)
+  }
+
   /// This returns the equivalent of `Object.getPrototypeOf` (which is not the same as the function's prototype property).
   fn get_prototype(&self) -> Result<Unknown<'env>> {
     let mut result = ptr::null_mut();
@@ -313,37 +384,40 @@ pub trait JsObjectValue<'env>: JsValue<'env> {
   }
 
   /// This method allows the efficient definition of multiple properties on a given object.
+  ///
+  /// All the properties are defined in one `napi_define_properties` call, in the order they are given.
   fn define_properties(&mut self, properties: &[Property]) -> Result<()> {
-    let properties_iter = properties.iter().map(|property| property.raw());
+    let raw_properties = properties
+      .iter()
+      .map(|property| property.raw())
+      .collect::<Vec<sys::napi_property_descriptor>>();
     let env = self.value().env;
     #[cfg(feature = "napi5")]
     {
-      let mut closures = properties_iter
-        .clone()
+      let closures = raw_properties
+        .iter()
         .map(|p| p.data)
         .filter(|data| !data.is_null())
-        .collect::<Vec<*mut std::ffi::c_void>>();
+        .collect::<Vec<*mut std::ffi::c_void>>()
+        .into_boxed_slice();
       let len = Box::into_raw(Box::new(closures.len()));
       check_status!(unsafe {
         sys::napi_add_finalizer(
           env,
           self.value().value,
-          closures.as_mut_ptr().cast(),
+          Box::into_raw(closures).cast(),
           Some(finalize_closures),
           len.cast(),
           ptr::null_mut(),
         )
       })?;
-      std::mem::forget(closures);
     }
     check_status!(unsafe {
       sys::napi_define_properties(
         env,
         self.value().value,
-        properties.len(),
-        properties_iter
-          .collect::<Vec<sys::napi_property_descriptor>>()
-          .as_ptr(),
+        raw_properties.len(),
+        raw_properties.as_ptr(),
       )
     })
   }
@@ -375,8 +449,8 @@ pub trait JsObjectValue<'env>: JsValue<'env> {
       sys::napi_wrap(
         env,
         value,
-        Box::into_raw(Box::new(TaggedObject::new(native_object))).cast(),
-        Some(raw_finalize::<TaggedObject<T>>),
+        TaggedObject::into_raw(native_object).cast(),
+        Some(raw_finalize_tagged_object::<T>),
         Box::into_raw(Box::new(size_hint.unwrap_or(0) as i64)).cast(),
         ptr::null_mut(),
       )
@@ -423,7 +497,7 @@ pub trait JsObjectValue<'env>: JsValue<'env> {
       ))?;
       let type_id = unknown_tagged_object as *const TypeId;
       if *type_id == TypeId::of::<T>() {
-        drop(Box::from_raw(unknown_tagged_object as *mut TaggedObject<T>));
+        TaggedObject::<T>::drop_raw(unknown_tagged_object.cast());
         Ok(())
       } else {
         Err(Error::new(
@@ -475,16 +549,80 @@ pub trait JsObjectValue<'env>: JsValue<'env> {
   }
 
   #[cfg(feature = "napi8")]
+  /// Freeze the object like `Object.freeze` in JavaScript.
+  ///
+  /// The property values can't be changed and no property can be added or removed afterwards,
+  /// the writes are ignored in sloppy mode and throw a `TypeError` in strict mode.
   fn freeze(&mut self) -> Result<()> {
     let env = self.value().env;
     check_status!(unsafe { sys::napi_object_freeze(env, self.value().value) })
   }
 
   #[cfg(feature = "napi8")]
+  /// Seal the object like `Object.seal` in JavaScript.
+  ///
+  /// No property can be added or removed afterwards, but the values of the writable properties can still be changed.
   fn seal(&mut self) -> Result<()> {
     let env = self.value().env;
     check_status!(unsafe { sys::napi_object_seal(env, self.value().value) })
   }
+
+  #[cfg(feature = "napi8")]
+  /// Associate the `tag` with this object, it can't be changed or removed afterwards.
+  ///
+  /// Fails with `InvalidArg` if the object already has a type tag.
+  fn type_tag(&mut self, tag: &TypeTag) -> Result<()> {
+    let env = self.value().env;
+    let tag = tag.to_raw();
+    check_status!(
+      unsafe { sys::napi_type_tag_object(env, self.value().value, &tag) },
+      "Failed to type tag object"
+    )
+  }
+
+  #[cfg(feature = "napi8")]
+  /// Whether this object is tagged with the `tag` by `type_tag`.
+  fn check_type_tag(&self, tag: &TypeTag) -> Result<bool> {
+    let env = self.value().env;
+    let tag = tag.to_raw();
+    let mut result = false;
+    check_status!(
+      unsafe { sys::napi_check_object_type_tag(env, self.value().value, &tag, &mut result) },
+      "Failed to check the type tag of object"
+    )?;
+    Ok(result)
+  }
+}
+
+#[cfg(feature = "napi8")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A 128-bit tag to mark the objects created by this addon, see `JsObjectValue::type_tag`.
+///
+/// Pick a random value, like a UUID, so it won't collide with the tags of the other addons.
+///
+/// ```rust
+/// use napi::bindgen_prelude::TypeTag;
+///
+/// const DATABASE_TAG: TypeTag = TypeTag::new(0x1edf75a38336451d_a5ed9ce2e4c00c38);
+/// ```
+pub struct TypeTag(u128);
+
+#[cfg(feature = "napi8")]
+impl TypeTag {
+  pub const fn new(value: u128) -> Self {
+    Self(value)
+  }
+
+  pub const fn value(&self) -> u128 {
+    self.0
+  }
+
+  fn to_raw(self) -> sys::napi_type_tag {
+    sys::napi_type_tag {
+      lower: self.0 as u64,
+      upper: (self.0 >> 64) as u64,
+    }
+  }
 }
 
 #[derive(Clone, Copy)]
@@ -642,6 +780,62 @@ impl Object<'_> {
 
     Ok(ret)
   }
+
+  /// Copy all own enumerable properties from `source` into this object, the same as `Object.assign(this, source)` in JavaScript.
+  ///
+  /// The getters on `source` are invoked and their values are copied, the properties already in this object are overwritten.
+  pub fn assign(&mut self, env: &Env, source: &Object) -> Result<()> {
+    let object_constructor = env.get_global_object("Object")?.raw();
+    let mut assign = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_named_property(env.0, object_constructor, c"assign".as_ptr(), &mut assign)
+      },
+      "Get Object.assign failed"
+    )?;
+    let args = [self.0.value, source.0.value];
+    let mut result = ptr::null_mut();
+    check_pending_exception!(
+      env.0,
+      unsafe {
+        sys::napi_call_function(
+          env.0,
+          object_constructor,
+          assign,
+          args.len(),
+          args.as_ptr(),
+          &mut result,
+        )
+      },
+      "Failed to assign properties to the object"
+    )?;
+    Ok(())
+  }
+
+  /// Convert the enumerable string keyed properties into a `HashMap`, without `serde`.
+  ///
+  /// Properties with `undefined` value are skipped, the same as the `FromNapiValue` implementation of `HashMap`.
+  pub fn to_hashmap<T: FromNapiValue>(&self) -> Result<HashMap<String, T>> {
+    let keys = Object::keys(self)?;
+    let mut map = HashMap::with_capacity(keys.len());
+    for key in keys.into_iter() {
+      let Some(value) = self.get_inner(&key)? else {
+        continue;
+      };
+      match unsafe { T::from_napi_value(self.0.env, value) } {
+        Ok(value) => {
+          map.insert(key, value);
+        }
+        Err(err) => {
+          return Err(Error::new(
+            err.status,
+            format!("Failed to convert value of key `{}`: {}", key, err.reason),
+          ))
+        }
+      }
+    }
+    Ok(map)
+  }
 }
 
 #[cfg(feature = "napi5")]
@@ -792,7 +986,7 @@ pub(crate) unsafe extern "C" fn finalize_closures(
   len: *mut c_void,
 ) {
   let length: usize = *unsafe { Box::from_raw(len.cast()) };
-  let closures: Vec<*mut PropertyClosures> =
+  let closures: Vec<*mut PropertyClosuresData> =
     unsafe { Vec::from_raw_parts(data.cast(), length, length) };
   for closure in closures.into_iter() {
     drop(unsafe { Box::from_raw(closure) });
@@ -81,6 +81,9 @@ impl<'env, 'scope: 'env> EscapableHandleScope<'scope> {
     scope_fn(scope, args)
   }
 
+  /// Escape the `value` to the outer scope, so it's still valid after this scope is closed.
+  ///
+  /// It can only be called once on a scope, the second call returns an `EscapeCalledTwice` error.
   pub fn escape<V: JsValue<'env> + FromNapiValue>(&self, value: V) -> Result<V> {
     let mut result = ptr::null_mut();
     check_status!(
@@ -0,0 +1,157 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::{
+  bindgen_prelude::{FromNapiValue, JsObjectValue, JsValue, TypeName, ValidateNapiValue},
+  check_status, sys, Env, Error, Result, Status, Value, ValueType,
+};
+
+#[derive(Clone, Copy)]
+/// Represents a JavaScript SharedArrayBuffer
+///
+/// The memory of `SharedArrayBuffer` is shared with the Worker threads it's posted to,
+/// so the data can be read and written by the other threads at the same time.
+pub struct SharedArrayBuffer<'env> {
+  pub(crate) value: Value,
+  data: *mut u8,
+  length: usize,
+  _marker: PhantomData<&'env ()>,
+}
+
+impl<'env> JsValue<'env> for SharedArrayBuffer<'env> {
+  fn value(&self) -> Value {
+    self.value
+  }
+}
+
+impl<'env> JsObjectValue<'env> for SharedArrayBuffer<'env> {}
+
+impl TypeName for SharedArrayBuffer<'_> {
+  fn type_name() -> &'static str {
+    "SharedArrayBuffer"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for SharedArrayBuffer<'_> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    let constructor = get_global_constructor(env, "SharedArrayBuffer")?;
+    let mut is_shared_array_buffer = false;
+    check_status!(
+      unsafe { sys::napi_instanceof(env, napi_val, constructor, &mut is_shared_array_buffer) },
+      "Failed to check if value is SharedArrayBuffer"
+    )?;
+    if !is_shared_array_buffer {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a SharedArrayBuffer value".to_owned(),
+      ));
+    }
+    Ok(ptr::null_mut())
+  }
+}
+
+impl FromNapiValue for SharedArrayBuffer<'_> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { Self::validate(env, napi_val)? };
+    // `napi_get_arraybuffer_info` rejects the `SharedArrayBuffer`, read the data from an `Uint8Array` view of it instead
+    let uint8_array = get_global_constructor(env, "Uint8Array")?;
+    let mut view = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_new_instance(env, uint8_array, 1, &napi_val, &mut view) },
+      "Failed to create Uint8Array from SharedArrayBuffer"
+    )?;
+    let mut data = ptr::null_mut();
+    let mut length = 0;
+    check_status!(
+      unsafe {
+        sys::napi_get_typedarray_info(
+          env,
+          view,
+          ptr::null_mut(),
+          &mut length,
+          &mut data,
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Get SharedArrayBuffer info failed"
+    )?;
+    Ok(Self {
+      value: Value {
+        env,
+        value: napi_val,
+        value_type: ValueType::Object,
+      },
+      data: data.cast(),
+      length,
+      _marker: PhantomData,
+    })
+  }
+}
+
+impl<'env> SharedArrayBuffer<'env> {
+  /// Create a new zero-filled `SharedArrayBuffer` with `length` bytes, like `new SharedArrayBuffer(length)` in JavaScript.
+  ///
+  /// It fails if the `SharedArrayBuffer` is not available in the current JavaScript context.
+  pub fn new(env: &'env Env, length: usize) -> Result<Self> {
+    let constructor = get_global_constructor(env.raw(), "SharedArrayBuffer")?;
+    let mut js_length = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_double(env.raw(), length as f64, &mut js_length) },
+      "Failed to create the length of SharedArrayBuffer"
+    )?;
+    let mut value = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_new_instance(env.raw(), constructor, 1, &js_length, &mut value) },
+      "Failed to create SharedArrayBuffer"
+    )?;
+    unsafe { Self::from_napi_value(env.raw(), value) }
+  }
+
+  /// The length of the `SharedArrayBuffer` in bytes
+  pub fn len(&self) -> usize {
+    self.length
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.length == 0
+  }
+
+  /// View the data of the `SharedArrayBuffer` without copying.
+  ///
+  /// # Safety
+  ///
+  /// The data may be written by JavaScript or the other threads holding this `SharedArrayBuffer` at the same time,
+  /// and Rust assumes the data behind a `&[u8]` never changes.
+  /// The caller must make sure nothing writes the data while the returned slice is alive,
+  /// for example by synchronizing with `Atomics` in JavaScript.
+  pub unsafe fn as_slice(&self) -> &[u8] {
+    if self.data.is_null() {
+      return &[];
+    }
+    unsafe { std::slice::from_raw_parts(self.data, self.length) }
+  }
+
+  /// Mutable view of the data of the `SharedArrayBuffer`, the changes are visible from all threads without copying.
+  ///
+  /// # Safety
+  ///
+  /// The caller must make sure nothing else reads or writes the data while the returned slice is alive,
+  /// including JavaScript and the other threads holding this `SharedArrayBuffer`.
+  pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+    if self.data.is_null() {
+      return &mut [];
+    }
+    unsafe { std::slice::from_raw_parts_mut(self.data, self.length) }
+  }
+}
+
+fn get_global_constructor(env: sys::napi_env, name: &str) -> Result<sys::napi_value> {
+  Env::from_raw(env)
+    .get_global_function(name)
+    .map(|constructor| constructor.raw())
+}
@@ -0,0 +1,71 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+use crate::{Error, Result, Status};
+
+/// The native state shared between the class instances, for example the children share the state of their parent.
+///
+/// Unlike `Reference` and `SharedReference`, which point to the native object of one JavaScript instance,
+/// the state is owned by all the clones of it, and it's dropped after the last clone is dropped.
+///
+/// The borrows are checked at runtime like `RefCell`, but the conflicting borrows are returned as errors rather than panics,
+/// for example a method is called again from a JavaScript callback while the state is mutably borrowed.
+///
+/// ```rust
+/// use napi::bindgen_prelude::*;
+///
+/// fn increment(counter: &SharedState<u32>) -> Result<u32> {
+///   let mut count = counter.borrow_mut()?;
+///   *count += 1;
+///   Ok(*count)
+/// }
+/// ```
+pub struct SharedState<T>(Rc<RefCell<T>>);
+
+impl<T> SharedState<T> {
+  pub fn new(value: T) -> Self {
+    Self(Rc::new(RefCell::new(value)))
+  }
+
+  /// It fails if the state is mutably borrowed
+  pub fn borrow(&self) -> Result<Ref<'_, T>> {
+    self.0.try_borrow().map_err(|_| {
+      Error::new(
+        Status::GenericFailure,
+        "The shared state is already mutably borrowed",
+      )
+    })
+  }
+
+  /// It fails if the state is borrowed
+  pub fn borrow_mut(&self) -> Result<RefMut<'_, T>> {
+    self.0.try_borrow_mut().map_err(|_| {
+      Error::new(
+        Status::GenericFailure,
+        "The shared state is already borrowed",
+      )
+    })
+  }
+
+  /// Whether the two `SharedState` share the same state
+  pub fn ptr_eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.0, &other.0)
+  }
+
+  /// The number of the `SharedState` share this state
+  pub fn share_count(&self) -> usize {
+    Rc::strong_count(&self.0)
+  }
+}
+
+impl<T> Clone for SharedState<T> {
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+impl<T: Default> Default for SharedState<T> {
+  fn default() -> Self {
+    Self::new(T::default())
+  }
+}
@@ -90,6 +90,7 @@ impl ToNapiValue for &str {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
     let mut ptr = ptr::null_mut();
 
+    // Pass the length rather than a NUL terminated `CString`, so the NUL bytes in the `&str` are kept as is
     check_status!(
       unsafe {
         sys::napi_create_string_utf8(env, val.as_ptr().cast(), val.len() as isize, &mut ptr)
@@ -195,6 +196,13 @@ impl From<String> for Latin1String {
   }
 }
 
+/// Every byte is treated as a latin1 character, so the bytes in `0x80..=0xFF` become `U+0080..=U+00FF` in JavaScript.
+impl From<Vec<u8>> for Latin1String {
+  fn from(bytes: Vec<u8>) -> Self {
+    Latin1String(bytes)
+  }
+}
+
 #[cfg(feature = "latin1")]
 impl Display for Latin1String {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -271,6 +279,54 @@ impl ToNapiValue for Latin1String {
   }
 }
 
+#[derive(Debug, Clone, Copy)]
+/// Borrowed latin1 text, converted into a JavaScript string with `napi_create_string_latin1`.
+///
+/// Unlike returning `&[u8]`, which creates a `Buffer`, the JavaScript side gets a string without decoding it.
+/// Every byte is treated as a latin1 character, so the bytes in `0x80..=0xFF` become `U+0080..=U+00FF`.
+///
+/// The `Latin1Bytes` doesn't implement `FromNapiValue`, use `Latin1String` to convert a JavaScript string into latin1 bytes.
+pub struct Latin1Bytes<'a>(pub &'a [u8]);
+
+impl ToNapiValue for Latin1Bytes<'_> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut ptr = ptr::null_mut();
+
+    check_status!(
+      unsafe {
+        sys::napi_create_string_latin1(env, val.0.as_ptr().cast(), val.0.len() as isize, &mut ptr)
+      },
+      "Failed to convert rust type `&[u8]` into napi `latin1 string`"
+    )?;
+
+    Ok(ptr)
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Borrowed UTF-8 text, converted into a JavaScript string with `napi_create_string_utf8`.
+///
+/// It's useful when the bytes are known to be UTF-8 but are not validated as a `&str`.
+/// The invalid UTF-8 sequences are replaced with `U+FFFD` by the JavaScript engine.
+///
+/// The `Utf8Bytes` doesn't implement `FromNapiValue`, use `String` to convert a JavaScript string into UTF-8 bytes.
+pub struct Utf8Bytes<'a>(pub &'a [u8]);
+
+impl ToNapiValue for Utf8Bytes<'_> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut ptr = ptr::null_mut();
+
+    check_status!(
+      unsafe {
+        sys::napi_create_string_utf8(env, val.0.as_ptr().cast(), val.0.len() as isize, &mut ptr)
+      },
+      "Failed to convert rust type `&[u8]` into napi `string`"
+    )?;
+
+    Ok(ptr)
+  }
+}
+
 pub const NAPI_AUTO_LENGTH: isize = -1;
 
 #[derive(Debug)]
@@ -1,14 +1,21 @@
+use std::cell::RefCell;
 use std::ffi::c_void;
+use std::future::Future;
 use std::marker::PhantomData;
+use std::pin::Pin;
 use std::ptr;
-use std::rc::Rc;
-use std::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, Waker};
 
+#[cfg(feature = "napi4")]
+use crate::bindgen_prelude::{Function, FunctionRef};
 use crate::Value;
 use crate::{
   async_work,
   bindgen_prelude::{FromNapiValue, JsObjectValue, ToNapiValue, TypeName, Unknown},
-  check_status, sys, Env, Error, JsError, Task, ValueType,
+  check_status, sys, Env, Error, JsError, ProgressReporter, Status, Task, ValueType,
 };
 
 use super::Object;
@@ -16,6 +23,8 @@ use super::Object;
 pub struct AsyncTask<T: Task> {
   inner: T,
   abort_signal: Option<AbortSignal>,
+  #[cfg(feature = "napi4")]
+  on_progress: Option<FunctionRef<f64, ()>>,
 }
 
 impl<T: Task> TypeName for T {
@@ -33,6 +42,8 @@ impl<T: Task> AsyncTask<T> {
     Self {
       inner: task,
       abort_signal: None,
+      #[cfg(feature = "napi4")]
+      on_progress: None,
     }
   }
 
@@ -40,6 +51,8 @@ impl<T: Task> AsyncTask<T> {
     Self {
       inner: task,
       abort_signal: Some(signal),
+      #[cfg(feature = "napi4")]
+      on_progress: None,
     }
   }
 
@@ -47,14 +60,147 @@ impl<T: Task> AsyncTask<T> {
     Self {
       inner: task,
       abort_signal: signal,
+      #[cfg(feature = "napi4")]
+      on_progress: None,
     }
   }
+
+  #[cfg(feature = "napi4")]
+  /// Call the `callback` with the values reported to the `ProgressReporter` in `Task::compute_with_progress`
+  pub fn on_progress(mut self, callback: &Function<'_, f64, ()>) -> crate::Result<Self> {
+    self.on_progress = Some(callback.create_ref()?);
+    Ok(self)
+  }
 }
 
 /// <https://developer.mozilla.org/zh-CN/docs/Web/API/AbortController>
 pub struct AbortSignal {
   raw_work: Rc<AtomicPtr<sys::napi_async_work__>>,
   status: Rc<AtomicU8>,
+  /// `abort` is called, no matter the task is canceled or it's already started
+  aborted: Arc<AtomicBool>,
+  /// The `Abortable` futures waiting for the abort
+  wakers: Arc<Mutex<Vec<Waker>>>,
+  /// Listeners of the JavaScript `AbortSignal`s this signal is aborted with
+  sources: Vec<Rc<RefCell<Vec<ComposedAbortSignal>>>>,
+}
+
+/// A signal created by `AbortSignal::any`, registered in the listeners of its JavaScript sources
+struct ComposedAbortSignal {
+  raw_work: Rc<AtomicPtr<sys::napi_async_work__>>,
+  // the composed signal and its task are gone once it can't be upgraded
+  status: Weak<AtomicU8>,
+  aborted: Arc<AtomicBool>,
+  wakers: Arc<Mutex<Vec<Waker>>>,
+}
+
+impl AbortSignal {
+  /// Create an `AbortSignal` which is aborted when any of the `signals` is aborted,
+  /// like the `AbortSignal.any()` in JavaScript.
+  ///
+  /// The composed signal is removed from the `signals` once its task is completed,
+  /// so composing with a long living `AbortSignal` doesn't leak.
+  pub fn any(signals: &[AbortSignal]) -> AbortSignal {
+    let composed = AbortSignal {
+      raw_work: Rc::new(AtomicPtr::new(ptr::null_mut())),
+      status: Rc::new(AtomicU8::new(0)),
+      aborted: Arc::new(AtomicBool::new(false)),
+      wakers: Default::default(),
+      sources: Vec::new(),
+    };
+    let mut sources: Vec<Rc<RefCell<Vec<ComposedAbortSignal>>>> = Vec::new();
+    for source in signals.iter().flat_map(|signal| signal.sources.iter()) {
+      if sources.iter().any(|s| Rc::ptr_eq(s, source)) {
+        continue;
+      }
+      let mut listeners = source.borrow_mut();
+      listeners.retain(|listener| {
+        listener
+          .status
+          .upgrade()
+          .is_some_and(|status| status.load(Ordering::Relaxed) != 1)
+      });
+      listeners.push(ComposedAbortSignal {
+        raw_work: composed.raw_work.clone(),
+        status: Rc::downgrade(&composed.status),
+        aborted: composed.aborted.clone(),
+        wakers: composed.wakers.clone(),
+      });
+      drop(listeners);
+      sources.push(source.clone());
+    }
+    AbortSignal {
+      sources,
+      ..composed
+    }
+  }
+
+  /// Whether the `abort` of the JavaScript `AbortController` is called
+  pub fn is_aborted(&self) -> bool {
+    self.aborted.load(Ordering::Relaxed)
+  }
+
+  /// The handle can be sent to the other threads, to check the abort or to run a future until the abort.
+  ///
+  /// The `#[napi] async fn` with an `AbortSignal` argument is already wrapped with `AbortHandle::abortable`.
+  pub fn handle(&self) -> AbortHandle {
+    AbortHandle {
+      aborted: self.aborted.clone(),
+      wakers: self.wakers.clone(),
+    }
+  }
+}
+
+/// Created by `AbortSignal::handle`
+#[derive(Clone)]
+pub struct AbortHandle {
+  aborted: Arc<AtomicBool>,
+  wakers: Arc<Mutex<Vec<Waker>>>,
+}
+
+impl AbortHandle {
+  pub fn is_aborted(&self) -> bool {
+    self.aborted.load(Ordering::Relaxed)
+  }
+
+  /// Run the `fut` until the signal is aborted.
+  ///
+  /// The `fut` is dropped once the signal is aborted, so the work in it is stopped at the next `.await` point,
+  /// and the `Abortable` returns the `AbortError` like the aborted `AsyncTask`.
+  pub fn abortable<F: Future>(&self, fut: F) -> Abortable<F> {
+    Abortable {
+      fut: Box::pin(fut),
+      handle: self.clone(),
+    }
+  }
+}
+
+/// Created by `AbortHandle::abortable`
+pub struct Abortable<F> {
+  fut: Pin<Box<F>>,
+  handle: AbortHandle,
+}
+
+impl<F: Future> Future for Abortable<F> {
+  type Output = Result<F::Output, Error>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    {
+      // `aborted` is set with the `wakers` locked, so the abort between the check and the registration isn't missed
+      let mut wakers = self
+        .handle
+        .wakers
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+      if self.handle.aborted.load(Ordering::Relaxed) {
+        return Poll::Ready(Err(Error::new(Status::Cancelled, "AbortError".to_owned())));
+      }
+      if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+        wakers.push(cx.waker().clone());
+      }
+    }
+    self.fut.as_mut().poll(cx).map(Ok)
+  }
 }
 
 unsafe impl Send for AbortSignal {}
@@ -76,9 +222,15 @@ impl FromNapiValue for AbortSignal {
     let async_work_inner: Rc<AtomicPtr<sys::napi_async_work__>> =
       Rc::new(AtomicPtr::new(ptr::null_mut()));
     let task_status = Rc::new(AtomicU8::new(0));
+    let aborted = Arc::new(AtomicBool::new(false));
+    let wakers: Arc<Mutex<Vec<Waker>>> = Default::default();
+    let listeners = Rc::new(RefCell::new(Vec::new()));
     let abort_signal = AbortSignal {
       raw_work: async_work_inner.clone(),
       status: task_status.clone(),
+      aborted: aborted.clone(),
+      wakers: wakers.clone(),
+      sources: vec![listeners.clone()],
     };
     let js_env = Env::from_raw(env);
 
@@ -117,6 +269,9 @@ impl FromNapiValue for AbortSignal {
     Ok(AbortSignal {
       raw_work: async_work_inner,
       status: task_status,
+      aborted,
+      wakers,
+      sources: vec![listeners],
     })
   }
 }
@@ -159,19 +314,27 @@ fn on_abort_impl(
     )?;
     let abort_controller_stack = Box::leak(Box::from_raw(async_task as *mut AbortSignalStack));
     for abort_controller in abort_controller_stack.0.iter() {
-      // Task Completed, return now
-      if abort_controller.status.load(Ordering::Relaxed) == 1 {
-        return Ok(ptr::null_mut());
-      }
-      let raw_async_work = abort_controller.raw_work.load(Ordering::Relaxed);
-      let status = sys::napi_cancel_async_work(env, raw_async_work);
-      // async work is already started, so we can't cancel it
-      if status != sys::Status::napi_ok {
-        abort_controller.status.store(0, Ordering::Relaxed);
-      } else {
-        // abort function must be called from JavaScript main thread, so Relaxed Ordering is ok.
-        abort_controller.status.store(2, Ordering::Relaxed);
+      // the `abort` event is only emitted once, the composed signals are not needed anymore
+      for source in abort_controller.sources.iter() {
+        for composed in source.take() {
+          if let Some(status) = composed.status.upgrade() {
+            cancel_async_work(
+              env,
+              &composed.raw_work,
+              &status,
+              &composed.aborted,
+              &composed.wakers,
+            );
+          }
+        }
       }
+      cancel_async_work(
+        env,
+        &abort_controller.raw_work,
+        &abort_controller.status,
+        &abort_controller.aborted,
+        &abort_controller.wakers,
+      );
     }
     let mut undefined = ptr::null_mut();
     check_status!(
@@ -182,16 +345,62 @@ fn on_abort_impl(
   }
 }
 
+fn cancel_async_work(
+  env: sys::napi_env,
+  raw_work: &AtomicPtr<sys::napi_async_work__>,
+  status: &AtomicU8,
+  aborted: &AtomicBool,
+  wakers: &Mutex<Vec<Waker>>,
+) {
+  // Task Completed
+  if status.load(Ordering::Relaxed) == 1 {
+    return;
+  }
+  {
+    let mut wakers = wakers.lock().unwrap_or_else(PoisonError::into_inner);
+    aborted.store(true, Ordering::Relaxed);
+    wakers.drain(..).for_each(Waker::wake);
+  }
+  let raw_async_work = raw_work.load(Ordering::Relaxed);
+  if raw_async_work.is_null() {
+    return;
+  }
+  let cancel_status = unsafe { sys::napi_cancel_async_work(env, raw_async_work) };
+  // async work is already started, so we can't cancel it
+  if cancel_status != sys::Status::napi_ok {
+    status.store(0, Ordering::Relaxed);
+  } else {
+    // abort function must be called from JavaScript main thread, so Relaxed Ordering is ok.
+    status.store(2, Ordering::Relaxed);
+  }
+}
+
 impl<T: Task> ToNapiValue for AsyncTask<T> {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
+    #[cfg(feature = "napi4")]
+    let progress = match val.on_progress {
+      Some(callback) => {
+        let env_ = Env::from_raw(env);
+        let callback = callback.borrow_back(&env_)?;
+        let aborted = val
+          .abort_signal
+          .as_ref()
+          .map(|abort_signal| abort_signal.aborted.clone());
+        Some(ProgressReporter::new(env, callback.value, aborted)?)
+      }
+      None => None,
+    };
+    #[cfg(not(feature = "napi4"))]
+    let progress: Option<ProgressReporter> = None;
     if let Some(abort_signal) = val.abort_signal {
-      let async_promise = async_work::run(env, val.inner, Some(abort_signal.status.clone()))?;
+      let async_promise =
+        async_work::run_with_progress(env, val.inner, Some(abort_signal.status.clone()), progress)?;
       abort_signal
         .raw_work
         .store(async_promise.napi_async_work, Ordering::Relaxed);
       Ok(async_promise.promise_object().inner)
     } else {
-      let async_promise = async_work::run(env, val.inner, None)?;
+      let async_promise = async_work::run_with_progress(env, val.inner, None, progress)?;
       Ok(async_promise.promise_object().inner)
     }
   }
@@ -1,6 +1,8 @@
-use std::cell::{Cell, LazyCell};
-use std::ffi::c_void;
-use std::hash::BuildHasherDefault;
+use std::any::TypeId;
+use std::cell::{Cell, LazyCell, RefCell};
+use std::collections::HashMap;
+use std::ffi::{c_void, CString};
+use std::hash::{BuildHasherDefault, Hash};
 use std::ops::{Deref, DerefMut};
 use std::ptr;
 use std::sync::{Arc, Weak};
@@ -8,14 +10,18 @@ use std::sync::{Arc, Weak};
 use nohash_hasher::NoHashHasher;
 
 use crate::{
-  bindgen_runtime::{FromNapiValue, PersistedPerInstanceHashMap, ToNapiValue},
-  check_status, Env, Error, Result, Status,
+  bindgen_runtime::{
+    ClassInstance, External, FromNapiValue, Function, JavaScriptClassExt, JsValuesTupleIntoVec,
+    PersistedPerInstanceHashMap, ToNapiValue, Unknown,
+  },
+  check_status, sys, Env, Error, Result, Status, ValueType,
 };
 
 type RefInformation = (
   /* wrapped_value */ *mut c_void,
   /* napi_ref */ crate::sys::napi_ref,
   /* finalize_callback */ *const Cell<*mut dyn FnOnce()>,
+  /* type_id */ TypeId,
 );
 
 thread_local! {
@@ -24,6 +30,30 @@ thread_local! {
   > = LazyCell::new(Default::default);
 }
 
+thread_local! {
+  /// The number of the `Reference::reference` calls which are not balanced by `Reference::unreference` yet,
+  /// keyed by the `napi_ref` of the class instance
+  static EXPLICIT_REFERENCES: RefCell<HashMap<crate::sys::napi_ref, u32>> = Default::default();
+}
+
+/// Number of wrapped class instances that are still alive on the current thread
+///
+/// Every `#[napi]` class instance is tracked until it's finalized by the GC,
+/// so this is useful for asserting that objects are actually freed in leak tests.
+/// Only available in debug builds.
+#[cfg(debug_assertions)]
+pub fn reference_count() -> usize {
+  REFERENCE_MAP.with(|cell| cell.borrow_mut(|map| map.len()))
+}
+
+/// Same as [`reference_count`], but only counts the instances of class `T`
+#[cfg(debug_assertions)]
+pub fn live_references_of<T: 'static>() -> usize {
+  let type_id = TypeId::of::<T>();
+  REFERENCE_MAP
+    .with(|cell| cell.borrow_mut(|map| map.values().filter(|(_, _, _, id)| *id == type_id).count()))
+}
+
 /// ### Experimental feature
 ///
 /// Create a `reference` from `Class` instance.
@@ -69,7 +99,7 @@ impl<T: 'static> Reference<T> {
   pub fn add_ref(env: crate::sys::napi_env, t: *mut c_void, value: RefInformation) {
     REFERENCE_MAP.with(|cell| {
       cell.borrow_mut(|map| {
-        if let Some((_, previous_ref, previous_rc)) = map.insert(t, value) {
+        if let Some((_, previous_ref, previous_rc, _)) = map.insert(t, value) {
           unsafe { Arc::from_raw(previous_rc) };
           unsafe { crate::sys::napi_delete_reference(env, previous_ref) };
         }
@@ -79,7 +109,7 @@ impl<T: 'static> Reference<T> {
 
   #[doc(hidden)]
   pub unsafe fn from_value_ptr(t: *mut c_void, env: crate::sys::napi_env) -> Result<Self> {
-    if let Some((wrapped_value, napi_ref, finalize_callbacks_ptr)) =
+    if let Some((wrapped_value, napi_ref, finalize_callbacks_ptr, _)) =
       REFERENCE_MAP.with(|cell| cell.borrow_mut(|map| map.get(&t).cloned()))
     {
       let mut ref_count = 0;
@@ -147,6 +177,59 @@ impl<T: 'static> Reference<T> {
     })
   }
 
+  /// Increase the ref count of the class instance and return the new count, like `napi_reference_ref`.
+  ///
+  /// The instance is kept alive until the `unreference` is called, even if all the `Reference`s of it are dropped.
+  /// So the instance can be shared among the owners which are not `Reference`s, like an `External` or a native resource.
+  /// Every `reference` must be balanced with an `unreference`, otherwise the instance is never finalized,
+  /// and the `ObjectFinalize::finalize` and the `SharedReference`s cleanup of it are never called.
+  pub fn reference(&self, env: Env) -> Result<u32> {
+    let mut ref_count = 0;
+    check_status!(
+      unsafe { crate::sys::napi_reference_ref(env.0, self.napi_ref, &mut ref_count) },
+      "Failed to ref napi reference"
+    )?;
+    EXPLICIT_REFERENCES.with(|references| {
+      *references.borrow_mut().entry(self.napi_ref).or_default() += 1;
+    });
+    Ok(ref_count)
+  }
+
+  /// Decrease the ref count increased by `reference` and return the new count, like `napi_reference_unref`.
+  ///
+  /// The instance is finalized by the GC after the count reaches `0`,
+  /// which means all the `Reference`s are dropped and all the `reference` calls are balanced.
+  /// It fails without changing the count if there is no `reference` call of the instance left to balance,
+  /// because the count of the living `Reference`s is released on drop.
+  pub fn unreference(&self, env: Env) -> Result<u32> {
+    let balanced = EXPLICIT_REFERENCES.with(|references| {
+      let mut references = references.borrow_mut();
+      match references.get_mut(&self.napi_ref) {
+        Some(count) if *count > 1 => {
+          *count -= 1;
+          true
+        }
+        Some(_) => {
+          references.remove(&self.napi_ref);
+          true
+        }
+        None => false,
+      }
+    });
+    if !balanced {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "The ref count is not increased by `Reference::reference`".to_owned(),
+      ));
+    }
+    let mut ref_count = 0;
+    check_status!(
+      unsafe { crate::sys::napi_reference_unref(env.0, self.napi_ref, &mut ref_count) },
+      "Failed to unref napi reference"
+    )?;
+    Ok(ref_count)
+  }
+
   pub fn downgrade(&self) -> WeakReference<T> {
     WeakReference {
       raw: self.raw,
@@ -155,6 +238,106 @@ impl<T: 'static> Reference<T> {
     }
   }
 
+  /// Get the method `name` of the class instance, bound to the instance like `instance[name].bind(instance)` in JavaScript.
+  ///
+  /// The returned function keeps the instance alive on its own,
+  /// so it's still callable after all the `Reference`s of the instance are dropped.
+  pub fn bind_method<'env, Args: JsValuesTupleIntoVec, Return>(
+    &self,
+    env: &'env Env,
+    name: &str,
+  ) -> Result<Function<'env, Args, Return>> {
+    let mut instance = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(env.0, self.napi_ref, &mut instance) },
+      "Failed to get reference value"
+    )?;
+    let method_name = CString::new(name)?;
+    let mut method = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_named_property(env.0, instance, method_name.as_ptr(), &mut method) },
+      "Failed to get method `{}` of {}",
+      name,
+      std::any::type_name::<T>(),
+    )?;
+    let mut value_type = 0;
+    check_status!(unsafe { sys::napi_typeof(env.0, method, &mut value_type) })?;
+    if ValueType::from(value_type) != ValueType::Function {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "`{}` is not a method of {}",
+          name,
+          std::any::type_name::<T>()
+        ),
+      ));
+    }
+    let mut bind = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_named_property(env.0, method, c"bind".as_ptr(), &mut bind) },
+      "Failed to get Function.prototype.bind"
+    )?;
+    let mut bound_method = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_call_function(env.0, method, bind, 1, &instance, &mut bound_method) },
+      "Failed to bind method `{}` of {}",
+      name,
+      std::any::type_name::<T>(),
+    )?;
+    unsafe { Function::from_napi_value(env.0, bound_method) }
+  }
+
+  /// Create a JavaScript `External` holding the `data`, which is owned by the class instance rather than the `External`.
+  ///
+  /// It's for the native ownership graph like a parent and its children, where the children point into the parent:
+  /// - The `External` holds a strong reference to the class instance,
+  ///   so the instance is not garbage collected while the `External` is still reachable from JavaScript.
+  /// - Dropping the `External` in JavaScript only releases its reference to the instance, the `data` is kept alive.
+  /// - The `data` is dropped when the class instance is finalized, before the instance itself and the data shared by the `share_with` earlier.
+  ///
+  /// So the `data` is valid as long as either the instance or the `External` is alive,
+  /// and it can be accessed as `&External<U>` or `&mut External<U>` like the other `External`s.
+  pub fn create_linked_external<'env, U: 'static>(
+    &self,
+    env: &'env Env,
+    data: U,
+  ) -> Result<Unknown<'env>> {
+    let mut instance = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(env.0, self.napi_ref, &mut instance) },
+      "Failed to get reference value"
+    )?;
+    let mut instance_ref = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_reference(env.0, instance, 1, &mut instance_ref) },
+      "Failed to create reference of {} for the linked External",
+      std::any::type_name::<T>(),
+    )?;
+    let external = Box::into_raw(Box::new(External::new(data)));
+    let mut value = ptr::null_mut();
+    let status = unsafe {
+      sys::napi_create_external(
+        env.0,
+        external.cast(),
+        Some(release_linked_instance),
+        instance_ref.cast(),
+        &mut value,
+      )
+    };
+    if status != sys::Status::napi_ok {
+      drop(unsafe { Box::from_raw(external) });
+      unsafe { sys::napi_delete_reference(env.0, instance_ref) };
+    }
+    check_status!(status, "Create linked external value failed")?;
+    let prev_drop_fn = unsafe { Box::from_raw(self.finalize_callbacks.get()) };
+    let drop_fn = Box::new(move || {
+      drop(unsafe { Box::from_raw(external) });
+      prev_drop_fn();
+    });
+    self.finalize_callbacks.set(Box::into_raw(drop_fn));
+    Ok(unsafe { Unknown::from_raw_unchecked(env.0, value) })
+  }
+
   /// Safety to share because caller can provide `Env`
   pub fn share_with<S: 'static, F: FnOnce(&'static mut T) -> Result<S>>(
     self,
@@ -176,6 +359,15 @@ impl<T: 'static> Reference<T> {
   }
 }
 
+/// The finalizer of the linked `External`, the data is owned by the class instance
+unsafe extern "C" fn release_linked_instance(
+  env: sys::napi_env,
+  _finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  unsafe { sys::napi_delete_reference(env, finalize_hint.cast()) };
+}
+
 impl<T: 'static> Deref for Reference<T> {
   type Target = T;
 
@@ -245,6 +437,25 @@ impl<T: 'static> WeakReference<T> {
     }
   }
 
+  /// Get the `ClassInstance` of the referenced object without keeping it alive.
+  ///
+  /// Returns `None` once the referenced object has been finalized.
+  pub fn upgrade_instance<'env>(&self, env: &'env Env) -> Result<Option<ClassInstance<'env, T>>> {
+    if Weak::strong_count(&self.finalize_callbacks) == 0 {
+      return Ok(None);
+    }
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe { crate::sys::napi_get_reference_value(env.0, self.napi_ref, &mut result) },
+      "Failed to get reference value"
+    )?;
+    // The object may have been collected while its finalizer is still pending
+    if result.is_null() {
+      return Ok(None);
+    }
+    Ok(Some(unsafe { ClassInstance::new(result, env.0, self.raw) }))
+  }
+
   pub fn get(&self) -> Option<&T> {
     if Weak::strong_count(&self.finalize_callbacks) == 0 {
       None
@@ -329,3 +540,97 @@ impl<T: 'static, S: 'static> DerefMut for SharedReference<T, S> {
     unsafe { Box::leak(Box::from_raw(self.raw)) }
   }
 }
+
+/// ### Experimental feature
+///
+/// Map the native keys to the class instances without keeping them alive,
+/// so converting the same logical entity again returns the same JavaScript object while it's alive.
+///
+/// It's useful for graph-like data, where `node.parent === sibling.parent` is expected in JavaScript.
+/// The entries whose instances were garbage collected are evicted during lookup.
+pub struct ReferenceCache<K, T: 'static> {
+  entries: HashMap<K, WeakReference<T>>,
+  // sweep all of the dead entries when `entries` grows to this size, amortized O(1) per lookup
+  sweep_threshold: usize,
+}
+
+impl<K: Eq + Hash, T: 'static> Default for ReferenceCache<K, T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<K: Eq + Hash, T: 'static> ReferenceCache<K, T> {
+  pub fn new() -> Self {
+    Self {
+      entries: HashMap::new(),
+      sweep_threshold: 16,
+    }
+  }
+
+  /// Get the alive instance of `key` in the cache
+  pub fn get<'env>(&mut self, env: &'env Env, key: &K) -> Result<Option<ClassInstance<'env, T>>> {
+    let Some(weak) = self.entries.get(key) else {
+      return Ok(None);
+    };
+    let instance = weak.upgrade_instance(env)?;
+    if instance.is_none() {
+      self.entries.remove(key);
+    }
+    Ok(instance)
+  }
+
+  /// Get the alive instance of `key`, or create it with the `factory` if it's not cached or it was garbage collected
+  pub fn get_or_insert_with<'env, F>(
+    &mut self,
+    env: &'env Env,
+    key: K,
+    factory: F,
+  ) -> Result<ClassInstance<'env, T>>
+  where
+    T: JavaScriptClassExt,
+    F: FnOnce() -> Result<T>,
+  {
+    if let Some(instance) = self.get(env, &key)? {
+      return Ok(instance);
+    }
+    let reference = factory()?.into_reference(*env)?;
+    let weak = reference.downgrade();
+    let instance = weak.upgrade_instance(env)?.ok_or_else(|| {
+      Error::new(
+        Status::GenericFailure,
+        format!(
+          "The created instance of {} is dropped",
+          std::any::type_name::<T>()
+        ),
+      )
+    })?;
+    self.entries.insert(key, weak);
+    if self.entries.len() >= self.sweep_threshold {
+      self.evict_dead();
+      self.sweep_threshold = (self.entries.len() * 2).max(16);
+    }
+    Ok(instance)
+  }
+
+  /// Remove the entry of `key`, the instance itself is not affected
+  pub fn remove(&mut self, key: &K) -> bool {
+    self.entries.remove(key).is_some()
+  }
+
+  /// Remove the entries whose instances were garbage collected
+  pub fn evict_dead(&mut self) {
+    self
+      .entries
+      .retain(|_, weak| Weak::strong_count(&weak.finalize_callbacks) > 0);
+  }
+
+  /// Number of the entries, including the dead ones which are not evicted yet
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+}
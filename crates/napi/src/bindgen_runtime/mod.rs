@@ -1,25 +1,60 @@
 use std::ffi::c_void;
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+use std::future::Future;
 use std::rc::Rc;
+use std::sync::{Arc, RwLock};
 
+#[cfg(feature = "web_stream")]
+pub use async_iterator::AsyncStream;
 pub use callback_info::*;
 pub use ctor::ctor;
 pub use env::*;
-pub use iterator::Generator;
+pub use iterator::{Generator, IteratorGenerator};
 pub use js_values::*;
 pub use module_register::*;
 
 use super::sys;
-use crate::{JsError, Result, Status};
+use crate::{Error, JsError, Result, Status};
 
 #[cfg(feature = "tokio_rt")]
 pub mod async_iterator;
 mod callback_info;
 mod env;
-mod error;
+pub(crate) mod error;
 pub mod iterator;
 mod js_values;
 mod module_register;
 
+type FinalizeErrorHandler = Arc<dyn Fn(&Error) + Send + Sync>;
+
+static FINALIZE_ERROR_HANDLER: RwLock<Option<FinalizeErrorHandler>> = RwLock::new(None);
+
+/// Install the `handler` called with the errors returned from `ObjectFinalize::finalize` of all classes.
+///
+/// It's called before the error is thrown into the `Env`, so the addon can report the failed cleanups to its own logger.
+/// The errors of `AsyncObjectFinalize::finalize_async` are passed to it too, from the tokio runtime threads.
+/// The previous handler is replaced, it can be replaced in the handler too.
+pub fn set_finalize_error_handler(handler: Box<dyn Fn(&Error) + Send + Sync>) {
+  *FINALIZE_ERROR_HANDLER
+    .write()
+    .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::from(handler));
+}
+
+/// Returns `false` if there is no handler installed
+fn call_finalize_error_handler(err: &Error) -> bool {
+  // the lock is released before calling the handler, so the handler can call `set_finalize_error_handler`
+  let handler = FINALIZE_ERROR_HANDLER
+    .read()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+    .clone();
+  if let Some(handler) = handler {
+    handler(err);
+    true
+  } else {
+    false
+  }
+}
+
 pub trait ObjectFinalize: Sized {
   #[allow(unused)]
   fn finalize(self, env: Env) -> Result<()> {
@@ -27,6 +62,45 @@ pub trait ObjectFinalize: Sized {
   }
 }
 
+/// Asynchronous version of `ObjectFinalize`, opt-in with `#[napi(async_finalize)]`
+///
+/// The `Future` returned from `finalize_async` is spawned onto the tokio runtime.
+/// The JavaScript object is already gone by the time the `Future` runs,
+/// so only the native resources may be touched in it.
+/// If the tokio runtime has been shut down, the `Future` is dropped without being polled.
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+pub trait AsyncObjectFinalize: Sized {
+  fn finalize_async(self, env: Env) -> impl Future<Output = Result<()>> + Send + 'static;
+}
+
+#[doc(hidden)]
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+pub fn spawn_finalize_async<T: AsyncObjectFinalize>(value: T, env: Env) -> Result<()> {
+  #[cfg(not(feature = "noop"))]
+  {
+    let fut = value.finalize_async(env);
+    crate::tokio_runtime::try_spawn(async move {
+      #[allow(unused_variables)]
+      if let Err(err) = fut.await {
+        if call_finalize_error_handler(&err) {
+          return;
+        }
+        #[cfg(debug_assertions)]
+        eprintln!(
+          "Async finalize of `{}` failed: {}",
+          std::any::type_name::<T>(),
+          err
+        );
+      }
+    });
+  }
+  #[cfg(feature = "noop")]
+  {
+    let _ = (value, env);
+  }
+  Ok(())
+}
+
 /// # Safety
 ///
 /// called when node wrapper objects destroyed
@@ -36,13 +110,10 @@ pub(crate) unsafe extern "C" fn raw_finalize_unchecked<T: ObjectFinalize>(
   finalize_data: *mut c_void,
   _finalize_hint: *mut c_void,
 ) {
-  let data: Box<T> = unsafe { Box::from_raw(finalize_data.cast()) };
-  if let Err(err) = data.finalize(Env::from_raw(env)) {
-    let e: JsError = err.into();
-    unsafe { e.throw_into(env) };
-    return;
-  }
-  if let Some((_, ref_val, finalize_callbacks_ptr)) =
+  let data: T = unsafe { crate::js_values::take_native_object(finalize_data.cast()) };
+  let finalize_result = data.finalize(Env::from_raw(env));
+  // the reference must be cleaned up even if `finalize` failed, or the `napi_ref` is leaked
+  if let Some((_, ref_val, finalize_callbacks_ptr, _)) =
     REFERENCE_MAP.with(|cell| cell.borrow_mut(|reference_map| reference_map.remove(&finalize_data)))
   {
     let finalize_callbacks_rc = unsafe { Rc::from_raw(finalize_callbacks_ptr) };
@@ -69,6 +140,11 @@ pub(crate) unsafe extern "C" fn raw_finalize_unchecked<T: ObjectFinalize>(
       Status::from(delete_reference_status)
     );
   }
+  if let Err(err) = finalize_result {
+    call_finalize_error_handler(&err);
+    let e: JsError = err.into();
+    unsafe { e.throw_into(env) };
+  }
 }
 
 /// # Safety
@@ -113,3 +189,25 @@ pub unsafe extern "C" fn drop_buffer_slice(
     drop(Vec::from_raw_parts(finalize_data, len, len));
   }
 }
+
+/// # Safety
+///
+/// called when the external buffer created from a `Vec<u8>` is ready for gc,
+/// the capacity of the `Vec` is passed as the hint itself, so there is nothing to allocate for it
+pub(crate) unsafe extern "C" fn drop_buffer_vec(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  let capacity = finalize_hint as usize;
+  #[cfg(all(debug_assertions, not(windows)))]
+  {
+    js_values::BUFFER_DATA.with(|buffer_data| {
+      let mut buffer = buffer_data.lock().expect("Unlock Buffer data failed");
+      buffer.remove(&(finalize_data as *mut u8));
+    });
+  }
+  unsafe {
+    drop(Vec::from_raw_parts(finalize_data as *mut u8, 0, capacity));
+  }
+}
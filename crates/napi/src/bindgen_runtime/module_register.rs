@@ -53,8 +53,15 @@ impl<K, V, S: Default> Default for PersistedPerInstanceHashMap<K, V, S> {
 }
 
 #[cfg(not(feature = "noop"))]
-type ModuleRegisterCallback =
-  RwLock<Vec<(Option<&'static str>, (&'static str, ExportRegisterCallback))>>;
+#[derive(Clone, Copy)]
+enum ModuleExport {
+  Eager(ExportRegisterCallback),
+  // #[napi(lazy)], passed to the getter as `data`, the callback is called on the first access
+  Lazy(&'static (&'static str, ExportRegisterCallback)),
+}
+
+#[cfg(not(feature = "noop"))]
+type ModuleRegisterCallback = RwLock<Vec<(Option<&'static str>, (&'static str, ModuleExport))>>;
 
 #[cfg(not(feature = "noop"))]
 type ClassPropertyRegistry = HashMap<
@@ -124,6 +131,25 @@ thread_local! {
   #[cfg(all(feature = "napi4", not(feature = "noop")))]
   // Store thread id of the thread that created the CustomGC ThreadsafeFunction.
   pub(crate) static THREADS_CAN_ACCESS_ENV: Cell<bool> = const { Cell::new(false) };
+  #[cfg(not(feature = "noop"))]
+  // The envs of the current thread which have registered the module and are not torn down yet.
+  static LIVE_ENVS: RefCell<HashSet<sys::napi_env>> = Default::default();
+}
+
+/// If the `env` is created on the current thread and is not torn down yet,
+/// calling N-API with the other envs is undefined behavior.
+pub(crate) fn is_env_alive(env: sys::napi_env) -> bool {
+  #[cfg(not(feature = "noop"))]
+  {
+    LIVE_ENVS
+      .try_with(|envs| envs.borrow().contains(&env))
+      .unwrap_or(false)
+  }
+  #[cfg(feature = "noop")]
+  {
+    let _ = env;
+    false
+  }
 }
 
 type RegisteredClasses = PersistedPerInstanceHashMap<
@@ -169,7 +195,33 @@ pub fn register_module_export(
   MODULE_REGISTER_CALLBACK
     .write()
     .expect("Register module export failed")
-    .push((js_mod, (name, cb)));
+    .push((js_mod, (name, ModuleExport::Eager(cb))));
+}
+
+#[cfg(not(feature = "noop"))]
+#[doc(hidden)]
+/// Register the export as a getter, the `cb` is called on the first access,
+/// and the getter is replaced by the value it returns.
+pub fn register_lazy_module_export(
+  js_mod: Option<&'static str>,
+  name: &'static str,
+  cb: ExportRegisterCallback,
+) {
+  // registered once per process in the `ctor`, it's shared by all the module instances
+  let lazy_export = Box::leak(Box::new((name, cb)));
+  MODULE_REGISTER_CALLBACK
+    .write()
+    .expect("Register lazy module export failed")
+    .push((js_mod, (name, ModuleExport::Lazy(lazy_export))));
+}
+
+#[cfg(feature = "noop")]
+#[doc(hidden)]
+pub fn register_lazy_module_export(
+  _js_mod: Option<&'static str>,
+  _name: &'static str,
+  _cb: ExportRegisterCallback,
+) {
 }
 
 #[cfg(feature = "noop")]
@@ -300,6 +352,8 @@ pub unsafe extern "C" fn napi_register_module_v1(
   unsafe {
     sys::setup();
   }
+  #[cfg(debug_assertions)]
+  super::error::record_panic_backtrace();
   #[cfg(feature = "node_version_detect")]
   {
     let mut node_version = MaybeUninit::uninit();
@@ -319,6 +373,7 @@ pub unsafe extern "C" fn napi_register_module_v1(
   if MODULE_COUNT.fetch_add(1, Ordering::SeqCst) != 0 {
     wait_first_thread_registered();
   }
+  LIVE_ENVS.with(|envs| envs.borrow_mut().insert(env));
 
   let mut exports_objects: HashSet<String> = HashSet::default();
 
@@ -329,7 +384,7 @@ pub unsafe extern "C" fn napi_register_module_v1(
     register_callback
       .iter_mut()
       .fold(
-        HashMap::<Option<&'static str>, Vec<(&'static str, ExportRegisterCallback)>>::new(),
+        HashMap::<Option<&'static str>, Vec<(&'static str, ModuleExport)>>::new(),
         |mut acc, (js_mod, item)| {
           if let Some(k) = acc.get_mut(js_mod) {
             k.push(*item);
@@ -377,21 +432,49 @@ pub unsafe extern "C" fn napi_register_module_v1(
             exports_objects.insert(js_mod_str.to_string());
           }
         }
-        for (name, callback) in items {
+        let exported_object = if exports_js_mod.is_null() {
+          exports
+        } else {
+          exports_js_mod
+        };
+        for (name, export) in items {
           unsafe {
             let js_name = CStr::from_bytes_with_nul_unchecked(name.as_bytes());
-            if let Err(e) = callback(env).and_then(|v| {
-              let exported_object = if exports_js_mod.is_null() {
-                exports
-              } else {
-                exports_js_mod
-              };
-              check_status!(
-                sys::napi_set_named_property(env, exported_object, js_name.as_ptr(), v),
-                "Failed to register export `{}`",
-                name,
-              )
-            }) {
+            let registered = match export {
+              ModuleExport::Eager(callback) => callback(env).and_then(|v| {
+                check_status!(
+                  sys::napi_set_named_property(env, exported_object, js_name.as_ptr(), v),
+                  "Failed to register export `{}`",
+                  name,
+                )
+              }),
+              ModuleExport::Lazy(lazy_export) => {
+                let properties = [sys::napi_property_descriptor {
+                  utf8name: js_name.as_ptr(),
+                  name: ptr::null_mut(),
+                  method: None,
+                  getter: Some(lazy_export_getter),
+                  setter: None,
+                  value: ptr::null_mut(),
+                  attributes: sys::PropertyAttributes::enumerable
+                    | sys::PropertyAttributes::configurable,
+                  data: (*lazy_export as *const (&'static str, ExportRegisterCallback))
+                    .cast_mut()
+                    .cast(),
+                }];
+                check_status!(
+                  sys::napi_define_properties(
+                    env,
+                    exported_object,
+                    properties.len(),
+                    properties.as_ptr()
+                  ),
+                  "Failed to register lazy export `{}`",
+                  name,
+                )
+              }
+            };
+            if let Err(e) = registered {
               JsError::from(e).throw_into(env)
             }
           }
@@ -442,7 +525,17 @@ pub unsafe extern "C" fn napi_register_module_v1(
             .first()
             .map(|c| c.raw().method.unwrap())
             .unwrap_or(noop);
-          let raw_props: Vec<_> = props.iter().map(|prop| prop.raw()).collect();
+          let raw_props: Vec<_> = props
+            .iter()
+            .filter_map(|prop| match prop.symbol_for_name {
+              Some(key) => resolve_symbol_for(env, key).map(|name| {
+                let mut raw = prop.raw();
+                raw.name = name;
+                raw
+              }),
+              None => Some(prop.raw()),
+            })
+            .collect();
 
           let js_class_name = CStr::from_bytes_with_nul_unchecked(js_name.as_bytes());
           let mut class_ptr = ptr::null_mut();
@@ -549,6 +642,58 @@ pub unsafe extern "C" fn napi_register_module_v1(
   exports
 }
 
+#[cfg(not(feature = "noop"))]
+/// Getter of the #[napi(lazy)] exports, create the value on the first access and replace the getter with it
+unsafe extern "C" fn lazy_export_getter(
+  env: sys::napi_env,
+  callback_info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let mut this = ptr::null_mut();
+  let mut data = ptr::null_mut();
+  let result = check_status!(
+    unsafe {
+      sys::napi_get_cb_info(
+        env,
+        callback_info,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        &mut this,
+        &mut data,
+      )
+    },
+    "Get callback info of lazy export failed"
+  )
+  .and_then(|_| {
+    let (name, callback) = unsafe { *data.cast::<(&'static str, ExportRegisterCallback)>() };
+    let value = unsafe { callback(env) }?;
+    let properties = [sys::napi_property_descriptor {
+      utf8name: name.as_ptr().cast(),
+      name: ptr::null_mut(),
+      method: None,
+      getter: None,
+      setter: None,
+      value,
+      attributes: sys::PropertyAttributes::writable
+        | sys::PropertyAttributes::enumerable
+        | sys::PropertyAttributes::configurable,
+      data: ptr::null_mut(),
+    }];
+    check_status!(
+      unsafe { sys::napi_define_properties(env, this, properties.len(), properties.as_ptr()) },
+      "Failed to define lazy export `{}`",
+      name,
+    )?;
+    Ok(value)
+  });
+  match result {
+    Ok(value) => value,
+    Err(e) => {
+      unsafe { JsError::from(e).throw_into(env) };
+      ptr::null_mut()
+    }
+  }
+}
+
 #[cfg(not(feature = "noop"))]
 pub(crate) unsafe extern "C" fn noop(
   env: sys::napi_env,
@@ -623,12 +768,59 @@ fn create_custom_gc(env: sys::napi_env) {
   THREADS_CAN_ACCESS_ENV.with(|cell| cell.set(true));
 }
 
+#[cfg(not(feature = "noop"))]
+/// `Symbol.for(key)`, return `None` if it can't be resolved
+unsafe fn resolve_symbol_for(env: sys::napi_env, key: &str) -> Option<sys::napi_value> {
+  let mut symbol = ptr::null_mut();
+  #[cfg(feature = "napi9")]
+  {
+    let status = unsafe {
+      sys::node_api_symbol_for(env, key.as_ptr().cast(), key.len() as isize, &mut symbol)
+    };
+    (status == sys::Status::napi_ok).then_some(symbol)
+  }
+  #[cfg(not(feature = "napi9"))]
+  {
+    let Ok(symbol_ctor) = crate::Env::from_raw(env)
+      .get_global_object("Symbol")
+      .map(|symbol| crate::JsValue::raw(&symbol))
+    else {
+      return None;
+    };
+    let mut symbol_for = ptr::null_mut();
+    let mut js_key = ptr::null_mut();
+    let resolved = unsafe {
+      sys::napi_get_named_property(env, symbol_ctor, c"for".as_ptr().cast(), &mut symbol_for)
+        == sys::Status::napi_ok
+        && sys::napi_create_string_utf8(env, key.as_ptr().cast(), key.len() as isize, &mut js_key)
+          == sys::Status::napi_ok
+        && sys::napi_call_function(env, symbol_ctor, symbol_for, 1, &js_key, &mut symbol)
+          == sys::Status::napi_ok
+    };
+    if !resolved {
+      let mut is_pending = false;
+      unsafe { sys::napi_is_exception_pending(env, &mut is_pending) };
+      if is_pending {
+        let mut exception = ptr::null_mut();
+        unsafe { sys::napi_get_and_clear_last_exception(env, &mut exception) };
+      }
+    }
+    resolved.then_some(symbol)
+  }
+}
+
 #[cfg(not(feature = "noop"))]
 unsafe extern "C" fn thread_cleanup(
-  _: sys::napi_env,
+  env: sys::napi_env,
   #[allow(unused_variables)] id: *mut std::ffi::c_void,
   _data: *mut std::ffi::c_void,
 ) {
+  LIVE_ENVS.with(|envs| envs.borrow_mut().remove(&env));
+  crate::__private::clear_cached_getter_keys(env);
+  crate::js_values::clear_loose_equals(env);
+  crate::bindgen_runtime::flush_buffer_pool();
+  #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+  crate::tokio_runtime::reset_env_scope(env);
   if MODULE_COUNT.fetch_sub(1, Ordering::Relaxed) == 1 {
     #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
     {
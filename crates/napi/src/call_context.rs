@@ -1,8 +1,10 @@
+use std::any::{type_name, TypeId};
 use std::ptr;
 
 use crate::bindgen_runtime::{FromNapiValue, TypeName};
 use crate::check_status;
-use crate::{sys, Either, Env, Error, NapiValue, Result, Status};
+use crate::env::FUNCTION_DATA;
+use crate::{sys, Either, Env, Error, NapiValue, Result, Status, TaggedObject};
 
 /// Function call context
 pub struct CallContext<'env> {
@@ -73,6 +75,80 @@ impl<'env> CallContext<'env> {
     }
   }
 
+  /// Get the argument at `index`, or the `default` if the argument is not passed or is `undefined`
+  pub fn arg_or<ArgType: FromNapiValue>(&self, index: usize, default: ArgType) -> Result<ArgType> {
+    if index >= self.length {
+      return Ok(default);
+    }
+    let raw_arg = if index < self.arg_len() {
+      self.args[index]
+    } else {
+      self.raw_args()?[index]
+    };
+    let mut value_type = 0;
+    check_status!(
+      unsafe { sys::napi_typeof(self.env.0, raw_arg, &mut value_type) },
+      "Get the type of argument with index `{}` failed",
+      index
+    )?;
+    if value_type == sys::ValueType::napi_undefined {
+      return Ok(default);
+    }
+    unsafe { ArgType::from_napi_value(self.env.0, raw_arg) }
+  }
+
+  /// Convert the arguments from `from` to the end, like the `...rest` parameter in JavaScript.
+  ///
+  /// The arguments are not truncated by the `arg_len` of `#[js_function(arg_len)]`.
+  pub fn rest_args<ArgType: FromNapiValue>(&self, from: usize) -> Result<Vec<ArgType>> {
+    if from >= self.length {
+      return Ok(Vec::new());
+    }
+    let all_args;
+    let raw_args = if self.length <= self.arg_len() {
+      &self.args[..self.length]
+    } else {
+      all_args = self.raw_args()?;
+      &all_args
+    };
+    raw_args[from..]
+      .iter()
+      .enumerate()
+      .map(|(i, &raw_arg)| {
+        unsafe { ArgType::from_napi_value(self.env.0, raw_arg) }.map_err(|err| {
+          Error::new(
+            err.status,
+            format!(
+              "Failed to convert argument with index `{}`: {}",
+              from + i,
+              err.reason
+            ),
+          )
+        })
+      })
+      .collect()
+  }
+
+  /// All of the passed arguments, including the ones beyond `arg_len`
+  fn raw_args(&self) -> Result<Vec<sys::napi_value>> {
+    let mut argc = self.length;
+    let mut args = vec![ptr::null_mut(); argc];
+    check_status!(
+      unsafe {
+        sys::napi_get_cb_info(
+          self.env.0,
+          self.callback_info,
+          &mut argc,
+          args.as_mut_ptr(),
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Get arguments of the function call failed"
+    )?;
+    Ok(args)
+  }
+
   pub fn get_all(&self) -> Vec<crate::Unknown> {
     /* (0 .. self.arg_len()).map(|i| self.get(i).unwrap()).collect() */
     self
@@ -91,6 +167,46 @@ impl<'env> CallContext<'env> {
     unsafe { V::from_raw(self.env.0, value) }
   }
 
+  /// Get the data attached to the function by `Env::create_function_with_data`
+  ///
+  /// Fails if the data of the function is not attached by `Env::create_function_with_data`, or it's not a `T`.
+  pub fn function_data<T: 'static>(&self) -> Result<&'env T> {
+    let mut data = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_cb_info(
+        self.env.0,
+        self.callback_info,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+        &mut data,
+      )
+    })?;
+    if data.is_null() || !FUNCTION_DATA.with(|function_data| function_data.borrow().contains(&data))
+    {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Invalid argument, nothing attach to the function".to_owned(),
+      ));
+    }
+    if unsafe { *(data as *const TypeId) } != TypeId::of::<T>() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Invalid argument, {} is not the type of the function data",
+          type_name::<T>()
+        ),
+      ));
+    }
+    let tagged_object = unsafe { &*(data as *const TaggedObject<T>) };
+    tagged_object.object.as_ref().ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        "Invalid argument, nothing attach to the function".to_owned(),
+      )
+    })
+  }
+
   pub fn this<T: NapiValue>(&self) -> Result<T> {
     unsafe { T::from_raw(self.env.0, self.raw_this) }
   }
@@ -1,3 +1,7 @@
+use std::ffi::c_void;
+
+use crate::{check_status, sys, Result};
+
 pub(crate) struct CleanupEnvHookData<T: 'static> {
   pub(crate) data: T,
   pub(crate) hook: Box<dyn FnOnce(T)>,
@@ -7,3 +11,29 @@ pub(crate) struct CleanupEnvHookData<T: 'static> {
 /// And used by `Env::remove_env_cleanup_hook`
 #[derive(Clone, Copy)]
 pub struct CleanupEnvHook<T: 'static>(pub(crate) *mut CleanupEnvHookData<T>);
+
+/// Created by `Env::add_cleanup_hook`
+///
+/// The hook is still called on the `Env` teardown if the handle is dropped,
+/// call `CleanupHookHandle::remove` to cancel it.
+pub struct CleanupHookHandle {
+  pub(crate) env: sys::napi_env,
+  pub(crate) hook: *mut Box<dyn FnOnce()>,
+}
+
+impl CleanupHookHandle {
+  /// Unregister the hook, the hook will not be called and its data is dropped
+  pub fn remove(self) -> Result<()> {
+    check_status!(
+      unsafe { sys::napi_remove_env_cleanup_hook(self.env, Some(cleanup_hook), self.hook.cast()) },
+      "Failed to remove env cleanup hook"
+    )?;
+    drop(unsafe { Box::from_raw(self.hook) });
+    Ok(())
+  }
+}
+
+pub(crate) unsafe extern "C" fn cleanup_hook(hook: *mut c_void) {
+  let hook = unsafe { Box::from_raw(hook.cast::<Box<dyn FnOnce()>>()) };
+  hook();
+}
@@ -2,6 +2,8 @@
 
 #[cfg(any(feature = "compat-mode", feature = "napi6"))]
 use std::any::{type_name, TypeId};
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::convert::TryInto;
 use std::ffi::CString;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
@@ -25,12 +27,14 @@ use crate::bindgen_runtime::FinalizeContext;
 #[cfg(feature = "napi5")]
 use crate::bindgen_runtime::FunctionCallContext;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+use crate::bindgen_runtime::Promise;
+#[cfg(feature = "napi4")]
 use crate::bindgen_runtime::PromiseRaw;
 use crate::bindgen_runtime::{
   FromNapiValue, Function, JsValuesTupleIntoVec, Object, ToNapiValue, Unknown,
 };
 #[cfg(feature = "napi3")]
-use crate::cleanup_env::{CleanupEnvHook, CleanupEnvHookData};
+use crate::cleanup_env::{cleanup_hook, CleanupEnvHook, CleanupEnvHookData, CleanupHookHandle};
 #[cfg(feature = "serde-json")]
 use crate::js_values::{De, Ser};
 #[cfg(all(feature = "napi4", feature = "compat-mode"))]
@@ -420,6 +424,9 @@ impl Env {
   ///
   /// Registering externally allocated memory will trigger global garbage collections more often than it would otherwise.
   ///
+  /// `size` is the change of the externally allocated memory in bytes, pass a negative value when the native memory is freed.
+  /// Return the total amount of the externally allocated memory registered after the change.
+  ///
   /// ***ATTENTION ⚠️***, do not use this with `create_buffer_with_data/create_arraybuffer_with_data`, since these two functions already called the `adjust_external_memory` internal.
   pub fn adjust_external_memory(&self, size: i64) -> Result<i64> {
     let mut changed = 0i64;
@@ -631,6 +638,48 @@ impl Env {
     unsafe { Function::<Args, Return>::from_napi_value(self.0, raw_result) }
   }
 
+  #[cfg(feature = "napi5")]
+  /// Create a JavaScript function like `create_function`, with the native `data` attached to it.
+  ///
+  /// Read the `data` in the `callback` with `CallContext::function_data`.
+  /// The `data` is dropped when the function is garbage collected.
+  pub fn create_function_with_data<Args: JsValuesTupleIntoVec, Return, T: 'static>(
+    &self,
+    name: &str,
+    callback: Callback,
+    data: T,
+  ) -> Result<Function<'_, Args, Return>> {
+    let data_ptr = TaggedObject::into_raw(data);
+    let mut raw_result = ptr::null_mut();
+    let len = name.len();
+    if let Err(err) = check_status!(unsafe {
+      sys::napi_create_function(
+        self.0,
+        name.as_ptr().cast(),
+        len as isize,
+        Some(callback),
+        data_ptr.cast(),
+        &mut raw_result,
+      )
+    }) {
+      unsafe { TaggedObject::drop_raw(data_ptr) };
+      return Err(err);
+    }
+    FUNCTION_DATA.with(|data| data.borrow_mut().insert(data_ptr.cast()));
+    check_status!(unsafe {
+      sys::napi_add_finalizer(
+        self.0,
+        raw_result,
+        data_ptr.cast(),
+        Some(raw_finalize_function_data::<T>),
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    })?;
+
+    unsafe { Function::<Args, Return>::from_napi_value(self.0, raw_result) }
+  }
+
   #[cfg(feature = "napi5")]
   pub fn create_function_from_closure<Args: JsValuesTupleIntoVec, Return, F>(
     &self,
@@ -752,6 +801,36 @@ impl Env {
     );
   }
 
+  /// Check if there is a pending JavaScript exception, for example after calling a JavaScript function that throws.
+  ///
+  /// This API can be called even if there is a pending JavaScript exception.
+  pub fn is_exception_pending(&self) -> Result<bool> {
+    let mut is_pending = false;
+    check_status!(
+      unsafe { sys::napi_is_exception_pending(self.0, &mut is_pending) },
+      "Check if exception is pending failed"
+    )?;
+    Ok(is_pending)
+  }
+
+  /// Take the pending JavaScript exception out, so the native code can recover from it and keep calling into JavaScript.
+  ///
+  /// It returns `None` if there is no pending exception.
+  /// This API can be called even if there is a pending JavaScript exception.
+  pub fn get_and_clear_last_exception(&self) -> Result<Option<Unknown<'_>>> {
+    if !self.is_exception_pending()? {
+      return Ok(None);
+    }
+    let mut exception = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_and_clear_last_exception(self.0, &mut exception) },
+      "Get and clear last exception failed"
+    )?;
+    Ok(Some(unsafe {
+      Unknown::from_raw_unchecked(self.0, exception)
+    }))
+  }
+
   #[allow(clippy::expect_fun_call)]
   /// In the event of an unrecoverable error in a native module
   ///
@@ -822,8 +901,8 @@ impl Env {
       sys::napi_wrap(
         self.0,
         js_object.0.value,
-        Box::into_raw(Box::new(TaggedObject::new(native_object))).cast(),
-        Some(raw_finalize::<TaggedObject<T>>),
+        TaggedObject::into_raw(native_object).cast(),
+        Some(raw_finalize_tagged_object::<T>),
         Box::into_raw(Box::new(size_hint.unwrap_or(0) as i64)).cast(),
         ptr::null_mut(),
       )
@@ -877,7 +956,7 @@ impl Env {
       ))?;
       let type_id = unknown_tagged_object as *const TypeId;
       if *type_id == TypeId::of::<T>() {
-        drop(Box::from_raw(unknown_tagged_object as *mut TaggedObject<T>));
+        TaggedObject::<T>::drop_raw(unknown_tagged_object.cast());
         Ok(())
       } else {
         Err(Error::new(
@@ -945,8 +1024,8 @@ impl Env {
     check_status!(unsafe {
       sys::napi_create_external(
         self.0,
-        Box::into_raw(Box::new(TaggedObject::new(native_object))).cast(),
-        Some(raw_finalize::<TaggedObject<T>>),
+        TaggedObject::into_raw(native_object).cast(),
+        Some(raw_finalize_tagged_object::<T>),
         Box::into_raw(Box::new(size_hint.unwrap_or(0))).cast(),
         &mut object_value,
       )
@@ -1011,6 +1090,24 @@ impl Env {
     Ok(Object::from_raw(self.0, result))
   }
 
+  /// Create a JavaScript `Error` with the `msg`, and the `code` property if it's provided
+  ///
+  /// Unlike `create_error`, the `code` isn't derived from the `Status`, so it can be any code like `ERR_INVALID_STATE`,
+  /// which is checked with `err.code` in JavaScript.
+  pub fn create_error_with_code(&self, code: Option<&str>, msg: &str) -> Result<Object<'_>> {
+    let code = match code {
+      Some(code) => self.create_string(code)?.0.value,
+      None => ptr::null_mut(),
+    };
+    let msg = self.create_string(msg)?;
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_error(self.0, code, msg.0.value, &mut result) },
+      "Failed to create error with code"
+    )?;
+    Ok(Object::from_raw(self.0, result))
+  }
+
   /// Run [Task](./trait.Task.html) in libuv thread pool, return [AsyncWorkPromise](./struct.AsyncWorkPromise.html)
   pub fn spawn<T: 'static + Task>(&self, task: T) -> Result<AsyncWorkPromise<T::JsValue>> {
     async_work::run(self.0, task, None)
@@ -1041,6 +1138,24 @@ impl Env {
     unsafe { V::from_napi_value(self.0, raw_value) }
   }
 
+  /// Same as `run_script`, but name the script as `filename`, so it shows in the stack traces instead of `<anonymous>`.
+  ///
+  /// Node-API can't set the origin of the script, so the name is attached via a `//# sourceURL=` comment.
+  /// The `filename` must not contain whitespace.
+  pub fn run_script_with_filename<S: AsRef<str>, V: FromNapiValue>(
+    &self,
+    script: S,
+    filename: &str,
+  ) -> Result<V> {
+    if filename.is_empty() || filename.contains(char::is_whitespace) {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Invalid script filename `{}`", filename),
+      ));
+    }
+    self.run_script(format!("{}\n//# sourceURL={}", script.as_ref(), filename))
+  }
+
   /// `process.versions.napi`
   pub fn get_napi_version(&self) -> Result<u32> {
     let global = self.get_global()?;
@@ -1108,6 +1223,30 @@ impl Env {
     })
   }
 
+  /// Register a hook to be called with the `data` when the `Env` is torn down,
+  /// for example to shut down a thread pool or to flush a cache owned by the addon.
+  ///
+  /// The returned `CleanupHookHandle` can be used to unregister the hook before that.
+  #[cfg(feature = "napi3")]
+  pub fn add_cleanup_hook<T, F>(&self, data: T, hook: F) -> Result<CleanupHookHandle>
+  where
+    T: 'static,
+    F: 'static + FnOnce(T),
+  {
+    let hook: Box<dyn FnOnce()> = Box::new(move || hook(data));
+    let hook = Box::into_raw(Box::new(hook));
+    #[cfg(not(target_family = "wasm"))]
+    let status = unsafe { sys::napi_add_env_cleanup_hook(self.0, Some(cleanup_hook), hook.cast()) };
+    #[cfg(target_family = "wasm")]
+    let status =
+      unsafe { crate::napi_add_env_cleanup_hook(self.0, Some(cleanup_hook), hook.cast()) };
+    if let Err(err) = check_status!(status, "Failed to add env cleanup hook") {
+      drop(unsafe { Box::from_raw(hook) });
+      return Err(err);
+    }
+    Ok(CleanupHookHandle { env: self.0, hook })
+  }
+
   #[cfg(all(feature = "napi4", feature = "compat-mode"))]
   #[deprecated(
     since = "2.17.0",
@@ -1166,6 +1305,42 @@ impl Env {
     Ok(PromiseRaw::new(self.0, promise))
   }
 
+  #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+  /// Await all the JavaScript `promises` concurrently with `Promise.all`
+  ///
+  /// The values are in the same order as the `promises`.
+  /// It's rejected with the reason of the first rejected promise, without waiting for the others.
+  ///
+  /// ```no_run
+  /// use napi::bindgen_prelude::*;
+  ///
+  /// #[napi]
+  /// pub fn sum_all<'env>(env: &'env Env, promises: Vec<PromiseRaw<u32>>) -> Result<PromiseRaw<'env, u32>> {
+  ///   let all = env.await_all(promises);
+  ///   env.spawn_future(async move { Ok(all.await?.into_iter().sum()) })
+  /// }
+  /// ```
+  pub fn await_all<T: 'static + FromNapiValue>(
+    &self,
+    promises: Vec<PromiseRaw<T>>,
+  ) -> impl Future<Output = Result<Vec<T>>> {
+    let all = self.promise_all(promises);
+    async move { all?.await }
+  }
+
+  #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+  fn promise_all<T: 'static + FromNapiValue>(
+    &self,
+    promises: Vec<PromiseRaw<T>>,
+  ) -> Result<Promise<Vec<T>>> {
+    let promise_class = self.get_global_object("Promise")?;
+    let all: Function<crate::bindgen_runtime::Array, Unknown> =
+      promise_class.get_named_property_unchecked("all")?;
+    let promises = self.create_array_from(promises)?;
+    let all_promise = all.apply(promise_class, promises)?;
+    unsafe { Promise::from_napi_value(self.0, all_promise.raw()) }
+  }
+
   #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
   /// Spawn a future with a callback
   /// So you can access the `Env` and resolved value after the future completed
@@ -1197,6 +1372,17 @@ impl Env {
     JsDeferred::new(self)
   }
 
+  /// Creates a promise and the `DeferredResolver` to settle it, which can be sent to any thread.
+  ///
+  /// Unlike `create_deferred`, the resolved value is created in Rust directly rather than by a callback.
+  #[cfg(feature = "napi4")]
+  pub fn create_promise_with_resolver<T: ToNapiValue + Send + 'static>(
+    &self,
+  ) -> Result<(PromiseRaw<'_, T>, DeferredResolver<T>)> {
+    let (resolver, promise) = DeferredResolver::new(self)?;
+    Ok((PromiseRaw::new(self.0, promise.0.value), resolver))
+  }
+
   /// This API does not observe leap seconds; they are ignored, as ECMAScript aligns with POSIX time specification.
   ///
   /// This API allocates a JavaScript Date object.
@@ -1276,6 +1462,25 @@ impl Env {
     }
   }
 
+  #[cfg(feature = "napi6")]
+  /// Associate the `data` with the currently running Agent, each env (like the worker threads) has its own data.
+  ///
+  /// The typed version of `set_instance_data` without the hint and the finalize callback, the `data` is dropped when the env is torn down.
+  /// Like `set_instance_data`, the data set by the previous call is overwritten without being dropped.
+  pub fn set_typed_instance_data<T: 'static>(&self, data: T) -> Result<()> {
+    self.set_instance_data(data, (), |ctx| drop(ctx.value))
+  }
+
+  #[cfg(feature = "napi6")]
+  /// Get the data set by `set_typed_instance_data`, returns `None` if no data is set.
+  ///
+  /// It returns an error if the data is not a `T`.
+  pub fn get_typed_instance_data<T: 'static>(&self) -> Result<Option<&T>> {
+    self
+      .get_instance_data::<T>()
+      .map(|data| data.map(|data| &*data))
+  }
+
   /// Registers hook, which is a function of type `FnOnce(Arg)`, as a function to be run with the `arg` parameter once the current Node.js environment exits.
   ///
   /// Unlike [`add_env_cleanup_hook`](https://docs.rs/napi/latest/napi/struct.Env.html#method.add_env_cleanup_hook), the hook is allowed to be asynchronous.
@@ -1393,7 +1598,24 @@ impl Env {
   where
     T: Serialize,
   {
-    let s = Ser(self);
+    let s = Ser::new(self);
+    node
+      .serialize(s)
+      .map(|v| Unknown(v, std::marker::PhantomData))
+  }
+
+  /// Same as `to_js_value`, but the entries of maps are set in the ascending order of their keys,
+  /// so the `HashMap`s are serialized into objects with the same key order on every call.
+  ///
+  /// The fields of structs are always in the declaration order.
+  /// The integer-like keys such as `"1"` are enumerated first in ascending numeric order by JavaScript anyway.
+  #[cfg(feature = "serde-json")]
+  #[allow(clippy::wrong_self_convention)]
+  pub fn to_js_value_with_sorted_keys<'env, T>(&self, node: &T) -> Result<Unknown<'env>>
+  where
+    T: Serialize,
+  {
+    let s = Ser::with_sorted_map_keys(self);
     node
       .serialize(s)
       .map(|v| Unknown(v, std::marker::PhantomData))
@@ -1441,13 +1663,33 @@ impl Env {
     Ok(result)
   }
 
-  pub fn get_node_version(&self) -> Result<NodeVersion> {
+  /// The version of the running Node.js, the same as `process.version`
+  pub fn node_version(&self) -> Result<NodeVersion> {
     let mut result = ptr::null();
-    check_status!(unsafe { sys::napi_get_node_version(self.0, &mut result) })?;
+    check_status!(
+      unsafe { sys::napi_get_node_version(self.0, &mut result) },
+      "Failed to get node version"
+    )?;
     let version = unsafe { *result };
     version.try_into()
   }
 
+  pub fn get_node_version(&self) -> Result<NodeVersion> {
+    self.node_version()
+  }
+
+  /// The highest N-API version supported by the runtime
+  ///
+  /// It could be used to detect the newer N-API functions before calling them.
+  pub fn napi_version(&self) -> Result<u32> {
+    let mut result = 0;
+    check_status!(
+      unsafe { sys::napi_get_version(self.0, &mut result) },
+      "Failed to get napi version"
+    )?;
+    Ok(result)
+  }
+
   /// get raw env ptr
   pub fn raw(&self) -> sys::napi_env {
     self.0
@@ -1468,14 +1710,37 @@ unsafe extern "C" fn drop_buffer(
   mem::drop(unsafe { Vec::from_raw_parts(finalize_data as *mut u8, length, cap) });
 }
 
-#[cfg_attr(target_family = "wasm", allow(unused_variables))]
-pub(crate) unsafe extern "C" fn raw_finalize<T>(
+thread_local! {
+  /// The `TaggedObject`s attached to the functions by `Env::create_function_with_data`,
+  /// `CallContext::function_data` only reads the data of the functions in it,
+  /// the data of the other functions, like the closures of `create_function_from_closure`, is not a `TaggedObject`.
+  pub(crate) static FUNCTION_DATA: RefCell<HashSet<*mut c_void>> = Default::default();
+}
+
+#[cfg(feature = "napi5")]
+unsafe extern "C" fn raw_finalize_function_data<T: 'static>(
+  env: sys::napi_env,
+  finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  // the thread local may be destroyed already if the env is torn down on the thread exit
+  let _ = FUNCTION_DATA.try_with(|data| data.borrow_mut().remove(&finalize_data));
+  unsafe { raw_finalize_tagged_object::<T>(env, finalize_data, finalize_hint) };
+}
+
+/// Finalize the `TaggedObject` allocated by `TaggedObject::into_raw`
+pub(crate) unsafe extern "C" fn raw_finalize_tagged_object<T: 'static>(
   env: sys::napi_env,
   finalize_data: *mut c_void,
   finalize_hint: *mut c_void,
 ) {
-  let tagged_object = finalize_data as *mut T;
-  drop(unsafe { Box::from_raw(tagged_object) });
+  unsafe { TaggedObject::<T>::drop_raw(finalize_data.cast()) };
+  unsafe { release_size_hint(env, finalize_hint) };
+}
+
+/// Give back the external memory of the `size_hint` passed to `napi_wrap`
+#[cfg_attr(target_family = "wasm", allow(unused_variables))]
+unsafe fn release_size_hint(env: sys::napi_env, finalize_hint: *mut c_void) {
   #[cfg(not(target_family = "wasm"))]
   if !finalize_hint.is_null() {
     let size_hint = unsafe { *Box::from_raw(finalize_hint as *mut i64) };
@@ -1643,11 +1908,13 @@ pub(crate) unsafe extern "C" fn trampoline_setter<
       "napi_get_cb_info failed"
     );
 
-    let closure_data_ptr = unsafe { *(data_ptr as *mut PropertyClosures) }.setter_closure;
+    let closure_data_ptr = unsafe { &*(data_ptr as *const PropertyClosuresData) }
+      .closures
+      .setter_closure;
     (raw_args, raw_this, closure_data_ptr)
   };
 
-  let closure: &F = Box::leak(unsafe { Box::from_raw(closure_data_ptr.cast()) });
+  let closure: &F = unsafe { &*closure_data_ptr.cast::<F>() };
   let env = Env::from_raw(raw_env);
   raw_args
     .first()
@@ -1694,11 +1961,13 @@ pub(crate) unsafe extern "C" fn trampoline_getter<
       "napi_get_cb_info failed"
     );
 
-    let closure_data_ptr = unsafe { *(data_ptr as *mut PropertyClosures) }.getter_closure;
+    let closure_data_ptr = unsafe { &*(data_ptr as *const PropertyClosuresData) }
+      .closures
+      .getter_closure;
     (raw_this, closure_data_ptr)
   };
 
-  let closure: &F = Box::leak(unsafe { Box::from_raw(closure_data_ptr.cast()) });
+  let closure: &F = unsafe { &*closure_data_ptr.cast::<F>() };
   let env = Env::from_raw(raw_env);
   unsafe { crate::bindgen_runtime::This::from_napi_value(raw_env, raw_this) }
     .and_then(|this| closure(env, this))
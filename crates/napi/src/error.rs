@@ -25,6 +25,8 @@ pub struct Error<S: AsRef<str> = Status> {
   // Convert raw `JsError` into Error
   pub(crate) maybe_raw: sys::napi_ref,
   pub(crate) maybe_env: sys::napi_env,
+  // Set to the `cause` property of the JavaScript `Error`
+  pub(crate) cause: Option<Box<Error>>,
 }
 
 impl<S: AsRef<str>> Drop for Error<S> {
@@ -91,7 +93,14 @@ impl<S: AsRef<str>> ToNapiValue for Error<S> {
 unsafe impl<S> Send for Error<S> where S: Send + AsRef<str> {}
 unsafe impl<S> Sync for Error<S> where S: Sync + AsRef<str> {}
 
-impl<S: AsRef<str> + std::fmt::Debug> error::Error for Error<S> {}
+impl<S: AsRef<str> + std::fmt::Debug> error::Error for Error<S> {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    self
+      .cause
+      .as_deref()
+      .map(|cause| cause as &(dyn error::Error + 'static))
+  }
+}
 
 impl<S: AsRef<str>> From<std::convert::Infallible> for Error<S> {
   fn from(_: std::convert::Infallible) -> Self {
@@ -140,6 +149,7 @@ impl From<Unknown<'_>> for Error {
         reason: error_message,
         maybe_raw: result,
         maybe_env,
+        cause: None,
       };
     }
 
@@ -148,6 +158,7 @@ impl From<Unknown<'_>> for Error {
       reason: "".to_string(),
       maybe_raw: result,
       maybe_env,
+      cause: None,
     }
   }
 }
@@ -155,7 +166,11 @@ impl From<Unknown<'_>> for Error {
 #[cfg(feature = "anyhow")]
 impl From<anyhow::Error> for Error {
   fn from(value: anyhow::Error) -> Self {
-    Error::new(Status::GenericFailure, format!("{:?}", value))
+    let mut err = Error::new(Status::GenericFailure, format!("{:?}", value));
+    err.cause = value
+      .source()
+      .map(|source| Box::new(Error::from_error(source)));
+    err
   }
 }
 
@@ -176,6 +191,7 @@ impl<S: AsRef<str>> Error<S> {
       reason: reason.to_string(),
       maybe_raw: ptr::null_mut(),
       maybe_env: ptr::null_mut(),
+      cause: None,
     }
   }
 
@@ -185,8 +201,17 @@ impl<S: AsRef<str>> Error<S> {
       reason: "".to_owned(),
       maybe_raw: ptr::null_mut(),
       maybe_env: ptr::null_mut(),
+      cause: None,
     }
   }
+
+  /// Set the `cause` of this Error, it becomes the `cause` property of the JavaScript `Error` when thrown.
+  ///
+  /// The `cause` could have its own `cause`, the whole chain is converted into JavaScript.
+  pub fn with_cause(mut self, cause: Error) -> Self {
+    self.cause = Some(Box::new(cause));
+    self
+  }
 }
 
 impl<S: AsRef<str> + Clone> Error<S> {
@@ -202,6 +227,11 @@ impl<S: AsRef<str> + Clone> Error<S> {
       reason: self.reason.to_string(),
       maybe_raw: self.maybe_raw,
       maybe_env: self.maybe_env,
+      cause: self
+        .cause
+        .as_ref()
+        .map(|cause| cause.try_clone().map(Box::new))
+        .transpose()?,
     })
   }
 }
@@ -213,8 +243,18 @@ impl Error {
       reason: reason.into(),
       maybe_raw: ptr::null_mut(),
       maybe_env: ptr::null_mut(),
+      cause: None,
     }
   }
+
+  /// Create an Error from any Rust error, the errors in its `source()` chain are converted into the `cause` chain.
+  pub fn from_error(error: &dyn error::Error) -> Self {
+    let mut err = Error::from_reason(error.to_string());
+    err.cause = error
+      .source()
+      .map(|source| Box::new(Error::from_error(source)));
+    err
+  }
 }
 
 impl From<std::ffi::NulError> for Error {
@@ -224,18 +264,14 @@ impl From<std::ffi::NulError> for Error {
       reason: format!("{}", error),
       maybe_raw: ptr::null_mut(),
       maybe_env: ptr::null_mut(),
+      cause: None,
     }
   }
 }
 
 impl From<std::io::Error> for Error {
   fn from(error: std::io::Error) -> Self {
-    Error {
-      status: Status::GenericFailure,
-      reason: format!("{}", error),
-      maybe_raw: ptr::null_mut(),
-      maybe_env: ptr::null_mut(),
-    }
+    Error::from_error(&error)
   }
 }
 
@@ -312,6 +348,34 @@ pub(crate) fn get_error_message_and_stack_trace(
   Ok(result)
 }
 
+/// Define the `cause` as a non-enumerable property, the same as `new Error(message, { cause })`
+unsafe fn set_error_cause(
+  env: sys::napi_env,
+  js_error: sys::napi_value,
+  cause: Option<Box<Error>>,
+) {
+  let Some(cause) = cause else {
+    return;
+  };
+  let cause = unsafe { JsError::from(*cause).into_value(env) };
+  let properties = [sys::napi_property_descriptor {
+    utf8name: c"cause".as_ptr(),
+    name: ptr::null_mut(),
+    method: None,
+    getter: None,
+    setter: None,
+    value: cause,
+    attributes: sys::PropertyAttributes::writable | sys::PropertyAttributes::configurable,
+    data: ptr::null_mut(),
+  }];
+  let define_status =
+    unsafe { sys::napi_define_properties(env, js_error, properties.len(), properties.as_ptr()) };
+  debug_assert!(
+    define_status == sys::Status::napi_ok,
+    "Set Error cause failed"
+  );
+}
+
 macro_rules! impl_object_methods {
   ($js_value:ident, $kind:expr) => {
     impl<S: AsRef<str>> $js_value<S> {
@@ -319,6 +383,7 @@ macro_rules! impl_object_methods {
       ///
       /// This function is safety if env is not null ptr.
       pub unsafe fn into_value(mut self, env: sys::napi_env) -> sys::napi_value {
+        let cause = self.0.cause.take();
         if !self.0.maybe_raw.is_null() {
           let mut err = ptr::null_mut();
           let get_err_status =
@@ -352,6 +417,7 @@ macro_rules! impl_object_methods {
           );
           // make sure ref_value is a valid error at first and avoid throw error failed.
           if is_error {
+            unsafe { set_error_cause(env, err, cause) };
             return err;
           }
         }
@@ -382,6 +448,7 @@ macro_rules! impl_object_methods {
         debug_assert!(create_reason_status == sys::Status::napi_ok);
         let create_error_status = unsafe { $kind(env, error_code, reason_string, &mut js_error) };
         debug_assert!(create_error_status == sys::Status::napi_ok);
+        unsafe { set_error_cause(env, js_error, cause) };
         js_error
       }
 
@@ -454,6 +521,76 @@ impl_object_methods!(JsRangeError, sys::napi_create_range_error);
 #[cfg(feature = "napi9")]
 impl_object_methods!(JsSyntaxError, sys::node_api_create_syntax_error);
 
+/// The JavaScript error thrown for the `Err` returned from a `#[napi]` function, see `AsJsError`
+pub enum JsErrorKind<S: AsRef<str> = String> {
+  Error(JsError<S>),
+  TypeError(JsTypeError<S>),
+  RangeError(JsRangeError<S>),
+  #[cfg(feature = "napi9")]
+  SyntaxError(JsSyntaxError<S>),
+}
+
+impl<S: AsRef<str>> JsErrorKind<S> {
+  /// # Safety
+  ///
+  /// This function is safety if env is not null ptr.
+  pub unsafe fn throw_into(self, env: sys::napi_env) {
+    match self {
+      Self::Error(err) => unsafe { err.throw_into(env) },
+      Self::TypeError(err) => unsafe { err.throw_into(env) },
+      Self::RangeError(err) => unsafe { err.throw_into(env) },
+      #[cfg(feature = "napi9")]
+      Self::SyntaxError(err) => unsafe { err.throw_into(env) },
+    }
+  }
+}
+
+/// Map the error type returned from the `#[napi]` functions into the thrown JavaScript error.
+///
+/// The `status` of the `Error` becomes the `code` property of the JavaScript error,
+/// so the domain error can be thrown as a `TypeError` or `RangeError` with its own `code`:
+///
+/// ```rust
+/// use napi::bindgen_prelude::*;
+///
+/// enum ConfigError {
+///   MissingKey(String),
+///   OutOfRange(u32),
+/// }
+///
+/// impl AsJsError for ConfigError {
+///   fn into_js_error(self) -> JsErrorKind {
+///     match self {
+///       ConfigError::MissingKey(key) => JsErrorKind::TypeError(
+///         Error::new("ERR_MISSING_KEY".to_owned(), format!("`{key}` is required")).into(),
+///       ),
+///       ConfigError::OutOfRange(value) => JsErrorKind::RangeError(
+///         Error::new("ERR_OUT_OF_RANGE".to_owned(), format!("{value} is out of range")).into(),
+///       ),
+///     }
+///   }
+/// }
+/// ```
+///
+/// Then the `#[napi]` functions can return `Result<T, ConfigError>` directly.
+/// The `async fn` still needs to return the `napi::Result<T>`.
+pub trait AsJsError<S: AsRef<str> = String> {
+  fn into_js_error(self) -> JsErrorKind<S>;
+}
+
+impl<S: AsRef<str>> AsJsError<S> for Error<S> {
+  fn into_js_error(self) -> JsErrorKind<S> {
+    JsErrorKind::Error(JsError::from(self))
+  }
+}
+
+#[cfg(feature = "anyhow")]
+impl AsJsError<Status> for anyhow::Error {
+  fn into_js_error(self) -> JsErrorKind<Status> {
+    JsErrorKind::Error(JsError::from(self))
+  }
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! error {
@@ -6,7 +6,7 @@ use std::ptr;
 use crate::{bindgen_runtime::JsObjectValue, JsValue};
 use crate::{
   bindgen_runtime::{Object, ToNapiValue},
-  check_status, sys, Env, Error, Result,
+  check_status, sys, Env, Error, Result, Status,
 };
 
 #[cfg(feature = "deferred_trace")]
@@ -183,6 +183,53 @@ impl<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>> JsDeferred<Data,
   }
 }
 
+type BoxedResolver<T> = Box<dyn FnOnce(Env) -> Result<T> + Send>;
+
+/// The resolving side of the promise created by `Env::create_promise_with_resolver`.
+///
+/// It's `Send`, so it can be moved into another thread or any async runtime to settle the promise there.
+/// The promise is rejected if the `DeferredResolver` is dropped without being resolved or rejected.
+pub struct DeferredResolver<T: ToNapiValue + Send + 'static> {
+  inner: Option<JsDeferred<T, BoxedResolver<T>>>,
+}
+
+impl<T: ToNapiValue + Send + 'static> DeferredResolver<T> {
+  pub(crate) fn new(env: &Env) -> Result<(Self, Object<'_>)> {
+    let (deferred, promise) = JsDeferred::new(env)?;
+    Ok((
+      Self {
+        inner: Some(deferred),
+      },
+      promise,
+    ))
+  }
+
+  /// Resolve the promise with the `value`, it's converted into the JavaScript value on the JavaScript thread.
+  pub fn resolve(mut self, value: T) {
+    if let Some(deferred) = self.inner.take() {
+      deferred.resolve(Box::new(move |_| Ok(value)));
+    }
+  }
+
+  /// Reject the promise with the `error`.
+  pub fn reject(mut self, error: Error) {
+    if let Some(deferred) = self.inner.take() {
+      deferred.reject(error);
+    }
+  }
+}
+
+impl<T: ToNapiValue + Send + 'static> Drop for DeferredResolver<T> {
+  fn drop(&mut self) {
+    if let Some(deferred) = self.inner.take() {
+      deferred.reject(Error::new(
+        Status::Cancelled,
+        "DeferredResolver was dropped without resolving the promise",
+      ));
+    }
+  }
+}
+
 fn js_deferred_new_raw(
   env: &Env,
   resolve_deferred: sys::napi_threadsafe_function_call_js,
@@ -0,0 +1,179 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::{
+  bindgen_runtime::{
+    FnArgs, FromNapiValue, Function, JsObjectValue, JsValuesTupleIntoVec, Object, ToNapiValue,
+    TypeName, Unknown, ValidateNapiValue,
+  },
+  check_status, sys, Env, Error, JsValue, Result, Status, Value, ValueType,
+};
+
+#[derive(Clone, Copy)]
+/// Represents a JavaScript Map
+///
+/// The keys and values are compared and held by JavaScript, so they can be any JavaScript values,
+/// unlike the `HashMap` which is converted from and into a plain object.
+pub struct JsMap<'env>(pub(crate) Value, pub(crate) PhantomData<&'env ()>);
+
+impl TypeName for JsMap<'_> {
+  fn type_name() -> &'static str {
+    "Map"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsMap<'_> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    if !unsafe { is_instance_of_global(env, napi_val, "Map")? } {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a Map value".to_owned(),
+      ));
+    }
+    Ok(ptr::null_mut())
+  }
+}
+
+impl FromNapiValue for JsMap<'_> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { Self::validate(env, napi_val)? };
+    Ok(Self(
+      Value {
+        env,
+        value: napi_val,
+        value_type: ValueType::Object,
+      },
+      PhantomData,
+    ))
+  }
+}
+
+impl<'env> JsValue<'env> for JsMap<'env> {
+  fn value(&self) -> Value {
+    self.0
+  }
+}
+
+impl<'env> JsObjectValue<'env> for JsMap<'env> {}
+
+impl<'env> JsMap<'env> {
+  /// Create an empty `Map`, like `new Map()` in JavaScript
+  pub fn new(env: &'env Env) -> Result<Self> {
+    let map_class = global_class(env.raw(), "Map")?;
+    let map = map_class.new_instance(())?;
+    Ok(Self(
+      Value {
+        env: env.raw(),
+        value: map.raw(),
+        value_type: ValueType::Object,
+      },
+      PhantomData,
+    ))
+  }
+
+  /// Same as `Map.prototype.get` in JavaScript, but returns `None` if there is no entry for the `key`
+  pub fn get<K: ToNapiValue, V: FromNapiValue>(&self, key: K) -> Result<Option<V>> {
+    // the key is converted only once for both `has` and `get`
+    let key = unsafe { K::to_napi_value(self.0.env, key)? };
+    if !self.has(key)? {
+      return Ok(None);
+    }
+    call_method(self, "get", key).map(Some)
+  }
+
+  /// Same as `Map.prototype.set` in JavaScript
+  pub fn set<K: ToNapiValue, V: ToNapiValue>(&mut self, key: K, value: V) -> Result<()> {
+    call_method::<_, Unknown>(self, "set", FnArgs::from((key, value)))?;
+    Ok(())
+  }
+
+  /// Same as `Map.prototype.has` in JavaScript
+  pub fn has<K: ToNapiValue>(&self, key: K) -> Result<bool> {
+    call_method(self, "has", key)
+  }
+
+  /// Same as `Map.prototype.delete` in JavaScript, returns `false` if there was no entry for the `key`
+  pub fn delete<K: ToNapiValue>(&mut self, key: K) -> Result<bool> {
+    call_method(self, "delete", key)
+  }
+
+  /// Same as `Map.prototype.clear` in JavaScript
+  pub fn clear(&mut self) -> Result<()> {
+    call_method::<_, Unknown>(self, "clear", ())?;
+    Ok(())
+  }
+
+  /// The number of entries in this `Map`
+  pub fn size(&self) -> Result<u32> {
+    self.get_named_property_unchecked("size")
+  }
+
+  /// Collect the keys in insertion order
+  pub fn keys<K: FromNapiValue>(&self) -> Result<Vec<K>> {
+    collect_iterator(self, "keys")
+  }
+
+  /// Collect the values in insertion order
+  pub fn values<V: FromNapiValue>(&self) -> Result<Vec<V>> {
+    collect_iterator(self, "values")
+  }
+
+  /// Collect the `[key, value]` entries in insertion order
+  pub fn entries<K: FromNapiValue, V: FromNapiValue>(&self) -> Result<Vec<(K, V)>> {
+    collect_iterator(self, "entries")
+  }
+}
+
+pub(super) fn global_class<'env>(
+  env: sys::napi_env,
+  name: &str,
+) -> Result<Function<'env, (), Unknown<'env>>> {
+  let class = Env::from(env).get_global_function(name)?.raw();
+  unsafe { Function::from_napi_value(env, class) }
+}
+
+pub(super) unsafe fn is_instance_of_global(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+  name: &str,
+) -> Result<bool> {
+  let class = global_class(env, name)?;
+  let mut is_instance = false;
+  check_status!(
+    unsafe { sys::napi_instanceof(env, napi_val, class.raw(), &mut is_instance) },
+    "Failed to check if value is {}",
+    name,
+  )?;
+  Ok(is_instance)
+}
+
+pub(super) fn call_method<'env, Args: JsValuesTupleIntoVec, R: FromNapiValue>(
+  this: &impl JsObjectValue<'env>,
+  name: &str,
+  args: Args,
+) -> Result<R> {
+  let method = this.get_named_property_unchecked::<Function<Args, R>>(name)?;
+  method.apply(this.raw(), args)
+}
+
+// drive the iterator returned from the `name` method to the end
+pub(super) fn collect_iterator<'env, T: FromNapiValue>(
+  this: &impl JsObjectValue<'env>,
+  name: &str,
+) -> Result<Vec<T>> {
+  let iterator: Object = call_method(this, name, ())?;
+  let next = iterator.get_named_property_unchecked::<Function<(), Object>>("next")?;
+  let mut items = Vec::new();
+  loop {
+    let result = next.apply(iterator, ())?;
+    if result.get_named_property_unchecked::<bool>("done")? {
+      break;
+    }
+    items.push(result.get_named_property_unchecked::<T>("value")?);
+  }
+  Ok(items)
+}
@@ -39,6 +39,7 @@ mod external;
 #[cfg(feature = "compat-mode")]
 mod function;
 mod global;
+mod map;
 #[cfg(feature = "compat-mode")]
 mod null;
 mod number;
@@ -47,6 +48,7 @@ mod object;
 mod object_property;
 #[cfg(feature = "serde-json")]
 mod ser;
+mod set;
 mod string;
 mod symbol;
 mod tagged_object;
@@ -78,6 +80,7 @@ pub use external::JsExternal;
 #[cfg(feature = "compat-mode")]
 pub use function::JsFunction;
 pub use global::*;
+pub use map::JsMap;
 #[cfg(feature = "compat-mode")]
 pub use null::*;
 pub use number::JsNumber;
@@ -86,11 +89,15 @@ pub use object::*;
 pub use object_property::*;
 #[cfg(feature = "serde-json")]
 pub use ser::Ser;
+pub use set::JsSet;
 pub use string::*;
 pub use symbol::*;
-pub(crate) use tagged_object::TaggedObject;
+pub(crate) use tagged_object::{alloc_native_object, take_native_object, TaggedObject};
+pub use tagged_object::{set_tagged_object_allocator, TaggedObjectAllocator};
 #[cfg(feature = "compat-mode")]
 pub use undefined::JsUndefined;
+#[cfg(not(feature = "noop"))]
+pub(crate) use unknown::clear_loose_equals;
 pub use unknown::Unknown;
 pub use value::JsValue;
 pub(crate) use value::Value;
@@ -610,35 +617,36 @@ macro_rules! impl_object_methods {
 
       /// This method allows the efficient definition of multiple properties on a given object.
       pub fn define_properties(&mut self, properties: &[Property]) -> Result<()> {
-        let properties_iter = properties.iter().map(|property| property.raw());
+        let raw_properties = properties
+          .iter()
+          .map(|property| property.raw())
+          .collect::<Vec<sys::napi_property_descriptor>>();
         #[cfg(feature = "napi5")]
         {
-          let mut closures = properties_iter
-            .clone()
+          let closures = raw_properties
+            .iter()
             .map(|p| p.data)
             .filter(|data| !data.is_null())
-            .collect::<Vec<*mut std::ffi::c_void>>();
+            .collect::<Vec<*mut std::ffi::c_void>>()
+            .into_boxed_slice();
           let len = Box::into_raw(Box::new(closures.len()));
           check_status!(unsafe {
             sys::napi_add_finalizer(
               self.0.env,
               self.0.value,
-              closures.as_mut_ptr().cast(),
+              Box::into_raw(closures).cast(),
               Some(finalize_closures),
               len.cast(),
               ptr::null_mut(),
             )
           })?;
-          std::mem::forget(closures);
         }
         check_status!(unsafe {
           sys::napi_define_properties(
             self.0.env,
             self.0.value,
-            properties.len(),
-            properties_iter
-              .collect::<Vec<sys::napi_property_descriptor>>()
-              .as_ptr(),
+            raw_properties.len(),
+            raw_properties.as_ptr(),
           )
         })
       }
@@ -1,8 +1,12 @@
+#[cfg(feature = "napi5")]
+use std::any::Any;
 use std::convert::From;
 #[cfg(feature = "napi5")]
 use std::ffi::c_void;
 use std::ffi::CString;
 use std::ptr;
+#[cfg(feature = "napi5")]
+use std::rc::Rc;
 
 use bitflags::bitflags;
 
@@ -27,9 +31,28 @@ impl Default for PropertyClosures {
   }
 }
 
+#[cfg(feature = "napi5")]
+#[derive(Clone, Default)]
+/// Keep the closures of `PropertyClosures` alive
+///
+/// The closures are shared by the cloned `Property`s and the objects they are defined on,
+/// and dropped when the last of them is gone.
+pub(crate) struct PropertyClosureOwners {
+  setter: Option<Rc<dyn Any>>,
+  getter: Option<Rc<dyn Any>>,
+}
+
+#[cfg(feature = "napi5")]
+/// The `data` of the `napi_property_descriptor` created by `Property::raw`
+pub(crate) struct PropertyClosuresData {
+  pub(crate) closures: PropertyClosures,
+  _owners: PropertyClosureOwners,
+}
+
 #[derive(Clone)]
 pub struct Property {
   utf8_name: Option<CString>,
+  pub(crate) symbol_for_name: Option<&'static str>,
   name: sys::napi_value,
   getter: sys::napi_callback,
   setter: sys::napi_callback,
@@ -39,12 +62,15 @@ pub struct Property {
   pub(crate) is_ctor: bool,
   #[cfg(feature = "napi5")]
   pub(crate) closures: PropertyClosures,
+  #[cfg(feature = "napi5")]
+  closure_owners: PropertyClosureOwners,
 }
 
 impl Default for Property {
   fn default() -> Self {
     Property {
       utf8_name: Default::default(),
+      symbol_for_name: None,
       name: ptr::null_mut(),
       getter: Default::default(),
       setter: Default::default(),
@@ -54,6 +80,8 @@ impl Default for Property {
       is_ctor: Default::default(),
       #[cfg(feature = "napi5")]
       closures: PropertyClosures::default(),
+      #[cfg(feature = "napi5")]
+      closure_owners: PropertyClosureOwners::default(),
     }
   }
 }
@@ -69,6 +97,19 @@ bitflags! {
   }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The own property descriptor read back with `JsObjectValue::get_property_descriptor`
+pub struct PropertyDescriptor {
+  pub enumerable: bool,
+  pub configurable: bool,
+  /// Always `false` for the accessor properties
+  pub writable: bool,
+  /// The property is defined with a getter or a setter, rather than a value
+  pub is_accessor: bool,
+  pub has_getter: bool,
+  pub has_setter: bool,
+}
+
 impl Default for PropertyAttributes {
   fn default() -> Self {
     PropertyAttributes::Configurable | PropertyAttributes::Enumerable | PropertyAttributes::Writable
@@ -91,6 +132,15 @@ impl Property {
     Ok(self)
   }
 
+  #[doc(hidden)]
+  /// Use `Symbol.for(key)` as the name, it's only resolved while registering the `#[napi]` class.
+  ///
+  /// The property is skipped if the `Symbol` can't be resolved.
+  pub fn with_symbol_for_name(mut self, key: &'static str) -> Self {
+    self.symbol_for_name = Some(key);
+    self
+  }
+
   pub fn with_name<T: ToNapiValue>(mut self, env: &Env, name: T) -> Result<Self> {
     self.name = unsafe { T::to_napi_value(env.0, name)? };
     Ok(self)
@@ -112,9 +162,9 @@ impl Property {
     F: 'static + Fn(Env, This) -> Result<R>,
     R: ToNapiValue,
   {
-    let boxed_callback = Box::new(callback);
-    let closure_data_ptr: *mut F = Box::into_raw(boxed_callback);
-    self.closures.getter_closure = closure_data_ptr.cast();
+    let callback = Rc::new(callback);
+    self.closures.getter_closure = Rc::as_ptr(&callback).cast_mut().cast();
+    self.closure_owners.getter = Some(callback);
 
     let fun = crate::trampoline_getter::<R, F>;
     self.getter = Some(fun);
@@ -132,9 +182,9 @@ impl Property {
     F: 'static + Fn(crate::Env, This, V) -> Result<()>,
     V: FromNapiValue,
   {
-    let boxed_callback = Box::new(callback);
-    let closure_data_ptr: *mut F = Box::into_raw(boxed_callback);
-    self.closures.setter_closure = closure_data_ptr.cast();
+    let callback = Rc::new(callback);
+    self.closures.setter_closure = Rc::as_ptr(&callback).cast_mut().cast();
+    self.closure_owners.setter = Some(callback);
 
     let fun = crate::trampoline_setter::<V, F>;
     self.setter = Some(fun);
@@ -158,7 +208,10 @@ impl Property {
 
   pub(crate) fn raw(&self) -> sys::napi_property_descriptor {
     #[cfg(feature = "napi5")]
-    let closures = Box::into_raw(Box::new(self.closures));
+    let closures = Box::into_raw(Box::new(PropertyClosuresData {
+      closures: self.closures,
+      _owners: self.closure_owners.clone(),
+    }));
     sys::napi_property_descriptor {
       utf8name: match self.utf8_name {
         Some(ref name) => name.as_ptr(),
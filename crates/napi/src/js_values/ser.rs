@@ -7,11 +7,23 @@ use crate::{
   Env, Error, JsString, JsValue, Result, Unknown, Value, ValueType,
 };
 
-pub struct Ser<'env>(pub(crate) &'env Env);
+/// Serialize the Rust values into JavaScript values.
+///
+/// The fields of structs are set in the declaration order, and the entries of maps are set in the iteration order of the map.
+/// JavaScript objects keep the insertion order of the string keys on all engines, except the integer-like keys such as `"1"`,
+/// which are always enumerated first in ascending numeric order.
+/// Use `Ser::with_sorted_map_keys` to sort the entries of maps by their keys, so the `HashMap`s are serialized in a stable order too.
+pub struct Ser<'env>(pub(crate) &'env Env, pub(crate) bool);
 
 impl<'env> Ser<'env> {
   pub fn new(env: &'env Env) -> Self {
-    Self(env)
+    Self(env, false)
+  }
+
+  /// Same as `Ser::new`, but the entries of maps are set in the ascending order of their keys, compared as strings,
+  /// rather than the iteration order of the maps.
+  pub fn with_sorted_map_keys(env: &'env Env) -> Self {
+    Self(env, true)
   }
 }
 
@@ -241,7 +253,12 @@ impl<'env> Serializer for Ser<'env> {
     let env = self.0;
     let key = env.create_string("")?;
     let obj = Object::new(env)?;
-    Ok(MapSerializer { key, obj })
+    Ok(MapSerializer {
+      key,
+      obj,
+      entries: Vec::new(),
+      sort_map_keys: self.1,
+    })
   }
 
   fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
@@ -249,6 +266,7 @@ impl<'env> Serializer for Ser<'env> {
     Ok(SeqSerializer {
       current_index: 0,
       array,
+      sort_map_keys: self.1,
     })
   }
 
@@ -276,6 +294,7 @@ impl<'env> Serializer for Ser<'env> {
     Ok(SeqSerializer {
       current_index: 0,
       array,
+      sort_map_keys: self.1,
     })
   }
 
@@ -325,6 +344,7 @@ impl<'env> Serializer for Ser<'env> {
     Ok(SeqSerializer {
       array: Array::new(self.0.raw(), len as u32)?,
       current_index: 0,
+      sort_map_keys: self.1,
     })
   }
 
@@ -336,12 +356,14 @@ impl<'env> Serializer for Ser<'env> {
     Ok(SeqSerializer {
       array: Array::new(self.0.raw(), len as u32)?,
       current_index: 0,
+      sort_map_keys: self.1,
     })
   }
 
   fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
     Ok(StructSerializer {
       obj: Object::new(self.0)?,
+      sort_map_keys: self.1,
     })
   }
 
@@ -367,6 +389,7 @@ impl<'env> Serializer for Ser<'env> {
     )?;
     Ok(StructSerializer {
       obj: Object::new(self.0)?,
+      sort_map_keys: self.1,
     })
   }
 }
@@ -374,6 +397,7 @@ impl<'env> Serializer for Ser<'env> {
 pub struct SeqSerializer<'env> {
   array: Array<'env>,
   current_index: usize,
+  sort_map_keys: bool,
 }
 
 impl ser::SerializeSeq for SeqSerializer<'_> {
@@ -387,7 +411,10 @@ impl ser::SerializeSeq for SeqSerializer<'_> {
     let env = Env::from_raw(self.array.env);
     self.array.set_element(
       self.current_index as _,
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
+      Unknown(
+        value.serialize(Ser(&env, self.sort_map_keys))?,
+        std::marker::PhantomData,
+      ),
     )?;
     self.current_index += 1;
     Ok(())
@@ -410,7 +437,10 @@ impl ser::SerializeTuple for SeqSerializer<'_> {
     let env = Env::from_raw(self.array.env);
     self.array.set_element(
       self.current_index as _,
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
+      Unknown(
+        value.serialize(Ser(&env, self.sort_map_keys))?,
+        std::marker::PhantomData,
+      ),
     )?;
     self.current_index += 1;
     Ok(())
@@ -433,7 +463,10 @@ impl ser::SerializeTupleStruct for SeqSerializer<'_> {
     let env = Env::from_raw(self.array.env);
     self.array.set_element(
       self.current_index as _,
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
+      Unknown(
+        value.serialize(Ser(&env, self.sort_map_keys))?,
+        std::marker::PhantomData,
+      ),
     )?;
     self.current_index += 1;
     Ok(())
@@ -456,7 +489,10 @@ impl ser::SerializeTupleVariant for SeqSerializer<'_> {
     let env = Env::from_raw(self.array.env);
     self.array.set_element(
       self.current_index as _,
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
+      Unknown(
+        value.serialize(Ser(&env, self.sort_map_keys))?,
+        std::marker::PhantomData,
+      ),
     )?;
     self.current_index += 1;
     Ok(())
@@ -470,6 +506,24 @@ impl ser::SerializeTupleVariant for SeqSerializer<'_> {
 pub struct MapSerializer<'env> {
   key: JsString<'env>,
   obj: Object<'env>,
+  // the entries are collected and set in `end` if the keys are sorted
+  entries: Vec<(String, Unknown<'env>)>,
+  sort_map_keys: bool,
+}
+
+impl<'env> MapSerializer<'env> {
+  fn set_entry(&mut self, key: JsString<'env>, value: Unknown<'env>) -> Result<()> {
+    if self.sort_map_keys {
+      let key = Unknown(key.0, PhantomData)
+        .coerce_to_string()?
+        .into_utf8()?
+        .into_owned()?;
+      self.entries.push((key, value));
+      Ok(())
+    } else {
+      self.obj.set_property(key, value)
+    }
+  }
 }
 
 #[doc(hidden)]
@@ -482,7 +536,10 @@ impl ser::SerializeMap for MapSerializer<'_> {
     T: ?Sized + Serialize,
   {
     let env = Env::from_raw(self.obj.0.env);
-    self.key = JsString(key.serialize(Ser::new(&env))?, std::marker::PhantomData);
+    self.key = JsString(
+      key.serialize(Ser(&env, self.sort_map_keys))?,
+      std::marker::PhantomData,
+    );
     Ok(())
   }
 
@@ -491,11 +548,11 @@ impl ser::SerializeMap for MapSerializer<'_> {
     T: ?Sized + Serialize,
   {
     let env = Env::from_raw(self.obj.0.env);
-    self.obj.set_property(
-      JsString::from_raw(self.key.0.env, self.key.0.value),
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
-    )?;
-    Ok(())
+    let value = Unknown(
+      value.serialize(Ser(&env, self.sort_map_keys))?,
+      std::marker::PhantomData,
+    );
+    self.set_entry(JsString::from_raw(self.key.0.env, self.key.0.value), value)
   }
 
   fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> StdResult<(), Self::Error>
@@ -504,20 +561,29 @@ impl ser::SerializeMap for MapSerializer<'_> {
     V: ?Sized + Serialize,
   {
     let env = Env::from_raw(self.obj.0.env);
-    self.obj.set_property(
-      JsString(key.serialize(Ser::new(&env))?, std::marker::PhantomData),
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
-    )?;
-    Ok(())
-  }
-
-  fn end(self) -> Result<Self::Ok> {
+    let key = JsString(
+      key.serialize(Ser(&env, self.sort_map_keys))?,
+      std::marker::PhantomData,
+    );
+    let value = Unknown(
+      value.serialize(Ser(&env, self.sort_map_keys))?,
+      std::marker::PhantomData,
+    );
+    self.set_entry(key, value)
+  }
+
+  fn end(mut self) -> Result<Self::Ok> {
+    self.entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (key, value) in self.entries {
+      self.obj.set_named_property(&key, value)?;
+    }
     Ok(self.obj.0)
   }
 }
 
 pub struct StructSerializer<'env> {
   obj: Object<'env>,
+  sort_map_keys: bool,
 }
 
 #[doc(hidden)]
@@ -532,7 +598,10 @@ impl ser::SerializeStruct for StructSerializer<'_> {
     let env = Env::from_raw(self.obj.0.env);
     self.obj.set_named_property(
       key,
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
+      Unknown(
+        value.serialize(Ser(&env, self.sort_map_keys))?,
+        std::marker::PhantomData,
+      ),
     )?;
     Ok(())
   }
@@ -554,7 +623,10 @@ impl ser::SerializeStructVariant for StructSerializer<'_> {
     let env = Env::from_raw(self.obj.0.env);
     self.obj.set_named_property(
       key,
-      Unknown(value.serialize(Ser::new(&env))?, std::marker::PhantomData),
+      Unknown(
+        value.serialize(Ser(&env, self.sort_map_keys))?,
+        std::marker::PhantomData,
+      ),
     )?;
     Ok(())
   }
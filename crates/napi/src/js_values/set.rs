@@ -0,0 +1,109 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+use super::map::{call_method, collect_iterator, global_class, is_instance_of_global};
+use crate::{
+  bindgen_runtime::{
+    FromNapiValue, JsObjectValue, ToNapiValue, TypeName, Unknown, ValidateNapiValue,
+  },
+  sys, Env, Error, JsValue, Result, Status, Value, ValueType,
+};
+
+#[derive(Clone, Copy)]
+/// Represents a JavaScript Set
+///
+/// The values are compared and held by JavaScript, so they can be any JavaScript values,
+/// unlike the `HashSet` which is copied from and into a `Set`.
+pub struct JsSet<'env>(pub(crate) Value, pub(crate) PhantomData<&'env ()>);
+
+impl TypeName for JsSet<'_> {
+  fn type_name() -> &'static str {
+    "Set"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsSet<'_> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    if !unsafe { is_instance_of_global(env, napi_val, "Set")? } {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a Set value".to_owned(),
+      ));
+    }
+    Ok(ptr::null_mut())
+  }
+}
+
+impl FromNapiValue for JsSet<'_> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { Self::validate(env, napi_val)? };
+    Ok(Self(
+      Value {
+        env,
+        value: napi_val,
+        value_type: ValueType::Object,
+      },
+      PhantomData,
+    ))
+  }
+}
+
+impl<'env> JsValue<'env> for JsSet<'env> {
+  fn value(&self) -> Value {
+    self.0
+  }
+}
+
+impl<'env> JsObjectValue<'env> for JsSet<'env> {}
+
+impl<'env> JsSet<'env> {
+  /// Create an empty `Set`, like `new Set()` in JavaScript
+  pub fn new(env: &'env Env) -> Result<Self> {
+    let set_class = global_class(env.raw(), "Set")?;
+    let set = set_class.new_instance(())?;
+    Ok(Self(
+      Value {
+        env: env.raw(),
+        value: set.raw(),
+        value_type: ValueType::Object,
+      },
+      PhantomData,
+    ))
+  }
+
+  /// Same as `Set.prototype.add` in JavaScript
+  pub fn add<V: ToNapiValue>(&mut self, value: V) -> Result<()> {
+    call_method::<_, Unknown>(self, "add", value)?;
+    Ok(())
+  }
+
+  /// Same as `Set.prototype.has` in JavaScript
+  pub fn has<V: ToNapiValue>(&self, value: V) -> Result<bool> {
+    call_method(self, "has", value)
+  }
+
+  /// Same as `Set.prototype.delete` in JavaScript, returns `false` if the `value` was not in this `Set`
+  pub fn delete<V: ToNapiValue>(&mut self, value: V) -> Result<bool> {
+    call_method(self, "delete", value)
+  }
+
+  /// Same as `Set.prototype.clear` in JavaScript
+  pub fn clear(&mut self) -> Result<()> {
+    call_method::<_, Unknown>(self, "clear", ())?;
+    Ok(())
+  }
+
+  /// The number of values in this `Set`
+  pub fn size(&self) -> Result<u32> {
+    self.get_named_property_unchecked("size")
+  }
+
+  /// Collect the values in insertion order
+  pub fn values<V: FromNapiValue>(&self) -> Result<Vec<V>> {
+    collect_iterator(self, "values")
+  }
+}
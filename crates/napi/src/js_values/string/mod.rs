@@ -140,6 +140,44 @@ impl<'env> JsString<'env> {
     })
   }
 
+  /// Copy the UTF-16 code units of this string into a new `Vec<u16>`, without the trailing `\0`
+  ///
+  /// The code units are returned as is, so the lone surrogates are kept rather than replaced or rejected.
+  pub fn as_utf16_vec(&self) -> Result<Vec<u16>> {
+    let mut buf = Vec::new();
+    self.read_utf16_into(&mut buf)?;
+    Ok(buf)
+  }
+
+  /// Read the UTF-16 code units of this string into `buf` and call `f` with them
+  ///
+  /// The `buf` is cleared before reading and only grows when the string doesn't fit in its capacity,
+  /// so reusing the same `buf` across a loop avoids allocating for every string.
+  /// Like `as_utf16_vec`, the lone surrogates are passed to `f` as is.
+  pub fn with_utf16<R>(&self, buf: &mut Vec<u16>, f: impl FnOnce(&[u16]) -> R) -> Result<R> {
+    self.read_utf16_into(buf)?;
+    Ok(f(buf.as_slice()))
+  }
+
+  fn read_utf16_into(&self, buf: &mut Vec<u16>) -> Result<()> {
+    // `napi_get_value_string_utf16` always writes a trailing '\0', which is not included in the `written_char_count`
+    let len = self.utf16_len()? + 1;
+    buf.clear();
+    buf.reserve(len);
+    let mut written_char_count = 0usize;
+    check_status!(unsafe {
+      sys::napi_get_value_string_utf16(
+        self.0.env,
+        self.0.value,
+        buf.as_mut_ptr(),
+        len,
+        &mut written_char_count,
+      )
+    })?;
+    unsafe { buf.set_len(written_char_count) };
+    Ok(())
+  }
+
   pub fn into_latin1(self) -> Result<JsStringLatin1<'env>> {
     let mut written_char_count = 0usize;
     let len = self.latin1_len()? + 1;
@@ -1,6 +1,10 @@
+use std::ptr;
+
+#[cfg(feature = "napi9")]
+use crate::Env;
 use crate::{
   bindgen_runtime::{FromNapiValue, TypeName, ValidateNapiValue},
-  sys, JsValue, Result, Value, ValueType,
+  check_status, sys, JsValue, Result, Value, ValueType,
 };
 
 #[derive(Clone, Copy)]
@@ -26,6 +30,33 @@ impl<'env> JsValue<'env> for JsSymbol<'env> {
   }
 }
 
+impl<'env> JsSymbol<'env> {
+  #[cfg(feature = "napi9")]
+  /// Get the `Symbol` of the `key` in the global symbol registry, same as `Symbol.for(key)` in JavaScript
+  ///
+  /// The symbols created with the same `key` are the same value, even across realms.
+  pub fn for_key(env: &'env Env, key: &str) -> Result<Self> {
+    env.symbol_for(key)
+  }
+
+  /// `Symbol.prototype.description`, return `None` if the `Symbol` was created without description
+  pub fn description(&self) -> Result<Option<String>> {
+    let mut description = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_named_property(
+          self.0.env,
+          self.0.value,
+          c"description".as_ptr().cast(),
+          &mut description,
+        )
+      },
+      "Get description of Symbol failed"
+    )?;
+    unsafe { Option::<String>::from_napi_value(self.0.env, description) }
+  }
+}
+
 impl FromNapiValue for JsSymbol<'_> {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
     Ok(JsSymbol(
@@ -1,5 +1,108 @@
+use std::alloc::{handle_alloc_error, Layout};
 use std::any::TypeId;
+use std::collections::HashMap;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex, RwLock};
 
+/// Allocate and free the `TaggedObject`s, which hold the native objects attached to the JavaScript values,
+/// like the objects of `JsObjectValue::wrap` and the data of `Env::create_function_with_data`,
+/// and the instances of the `#[napi]` classes created by the class constructors, factories and `new_instance`.
+///
+/// Install it with `set_tagged_object_allocator`, for example to count the native allocations of the addon.
+/// They are allocated with `Box` if no allocator is installed.
+///
+/// # Safety
+///
+/// Same as `std::alloc::GlobalAlloc`, `alloc` must return a block of memory fits the `layout` or null if it fails,
+/// and `dealloc` is called with the block returned from `alloc` and the same `layout`.
+pub unsafe trait TaggedObjectAllocator: Send + Sync {
+  fn alloc(&self, layout: Layout) -> *mut u8;
+
+  /// # Safety
+  ///
+  /// The `ptr` is allocated by `alloc` of this allocator with the same `layout`
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout);
+}
+
+static TAGGED_OBJECT_ALLOCATOR: RwLock<Option<&'static dyn TaggedObjectAllocator>> =
+  RwLock::new(None);
+
+/// Set once an allocator is installed, so the objects are freed with `Box` without looking up `ALLOCATED` before that
+static ALLOCATOR_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// The address of the objects allocated with an allocator, and the allocator of them.
+///
+/// The objects not in it are allocated with `Box`, so they don't need to carry their allocator.
+static ALLOCATED: LazyLock<Mutex<HashMap<usize, &'static dyn TaggedObjectAllocator>>> =
+  LazyLock::new(Default::default);
+
+/// Install the `allocator` for the `TaggedObject`s allocated afterwards, the previous one is replaced.
+///
+/// The `TaggedObject`s are freed with the allocator allocated them,
+/// so the objects allocated with the previous allocator or `Box` are still freed correctly.
+pub fn set_tagged_object_allocator(allocator: &'static dyn TaggedObjectAllocator) {
+  *TAGGED_OBJECT_ALLOCATOR
+    .write()
+    .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(allocator);
+  ALLOCATOR_INSTALLED.store(true, Ordering::Release);
+}
+
+/// Allocate the `value` with the installed `TaggedObjectAllocator`, or `Box` if there is none
+///
+/// The zero sized `T` is always allocated with `Box`, the allocator is not called with the empty `layout`.
+pub(crate) fn alloc_native_object<T>(value: T) -> *mut T {
+  let layout = Layout::new::<T>();
+  let allocator = if layout.size() == 0 {
+    None
+  } else {
+    *TAGGED_OBJECT_ALLOCATOR
+      .read()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  };
+  let Some(allocator) = allocator else {
+    return Box::into_raw(Box::new(value));
+  };
+  let raw = allocator.alloc(layout).cast::<T>();
+  if raw.is_null() {
+    handle_alloc_error(layout);
+  }
+  unsafe { ptr::write(raw, value) };
+  ALLOCATED
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+    .insert(raw as usize, allocator);
+  raw
+}
+
+/// Move the value out and free the memory allocated by `alloc_native_object`
+///
+/// # Safety
+///
+/// The `raw` must be returned from `alloc_native_object::<T>` and not be freed yet
+pub(crate) unsafe fn take_native_object<T>(raw: *mut T) -> T {
+  let allocator = if ALLOCATOR_INSTALLED.load(Ordering::Acquire) {
+    ALLOCATED
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+      .remove(&(raw as usize))
+  } else {
+    None
+  };
+  match allocator {
+    Some(allocator) => unsafe {
+      let value = ptr::read(raw);
+      allocator.dealloc(raw.cast(), Layout::new::<T>());
+      value
+    },
+    None => *unsafe { Box::from_raw(raw) },
+  }
+}
+
+/// The `type_id` is always at the start of the allocation, so it can be read before the type of `T` is known.
+///
+/// It's always allocated with `TaggedObject::into_raw` and freed with `TaggedObject::drop_raw`,
+/// so the `T` with `#[repr(align(N))]` is aligned and deallocated with the same layout and allocator.
 #[repr(C)]
 pub struct TaggedObject<T> {
   type_id: TypeId,
@@ -13,4 +116,18 @@ impl<T: 'static> TaggedObject<T> {
       object: Some(object),
     }
   }
+
+  /// Allocate the `TaggedObject` of `object` with the installed `TaggedObjectAllocator`, or `Box` if there is none
+  pub(crate) fn into_raw(object: T) -> *mut Self {
+    alloc_native_object(Self::new(object))
+  }
+
+  /// Drop the `object` and free the `TaggedObject` allocated by `into_raw`
+  ///
+  /// # Safety
+  ///
+  /// The `raw` must be returned from `into_raw` and not be freed yet
+  pub(crate) unsafe fn drop_raw(raw: *mut Self) {
+    drop(unsafe { take_native_object(raw) })
+  }
 }
@@ -1,10 +1,23 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ptr;
 
 use crate::{
   bindgen_runtime::{FromNapiValue, TypeName, ValidateNapiValue},
-  sys, type_of, JsValue, Result, Value, ValueType,
+  check_status, sys, type_of, Env, JsValue, Result, Value, ValueType,
 };
 
+thread_local! {
+  /// The `(a, b) => a == b` function of `Unknown::loose_equals`, it's evaluated once per env
+  static LOOSE_EQUALS: RefCell<HashMap<sys::napi_env, sys::napi_ref>> = Default::default();
+}
+
+/// Forget the `loose_equals` function of the `env`, which is torn down
+#[cfg(not(feature = "noop"))]
+pub(crate) fn clear_loose_equals(env: sys::napi_env) {
+  let _ = LOOSE_EQUALS.try_with(|functions| functions.borrow_mut().remove(&env));
+}
+
 #[derive(Clone, Copy)]
 /// Represents a raw JavaScript value
 pub struct Unknown<'env>(
@@ -67,6 +80,80 @@ impl Unknown<'_> {
     unsafe { V::from_napi_value(self.0.env, self.0.value) }
   }
 
+  /// Convert the value to `V`, or `None` if the value is not a `V`.
+  ///
+  /// Unlike `cast`, the value is validated before the conversion,
+  /// so it's safe to try the candidate types one by one:
+  ///
+  /// ```rust
+  /// use napi::{bindgen_prelude::*, Result};
+  ///
+  /// fn describe(value: Unknown) -> Result<String> {
+  ///   if let Some(n) = value.try_cast::<f64>()? {
+  ///     return Ok(format!("number {n}"));
+  ///   }
+  ///   if let Some(s) = value.try_cast::<String>()? {
+  ///     return Ok(format!("string {s}"));
+  ///   }
+  ///   Ok(format!("{}", value.get_type()?))
+  /// }
+  /// ```
+  pub fn try_cast<V>(&self) -> Result<Option<V>>
+  where
+    V: FromNapiValue + ValidateNapiValue,
+  {
+    if unsafe { V::validate(self.0.env, self.0.value) }.is_err() {
+      return Ok(None);
+    }
+    unsafe { V::from_napi_value(self.0.env, self.0.value) }.map(Some)
+  }
+
+  /// Same as `self === other` in JavaScript, so `NaN` is not equal to `NaN`, and `0` is equal to `-0`
+  pub fn strict_equals(&self, other: &Unknown) -> Result<bool> {
+    let mut result = false;
+    check_status!(
+      unsafe { sys::napi_strict_equals(self.0.env, self.0.value, other.0.value, &mut result) },
+      "Failed to compare the values with `===`"
+    )?;
+    Ok(result)
+  }
+
+  /// Same as `self == other` in JavaScript, for example `1 == '1'` and `null == undefined` are `true`.
+  ///
+  /// There is no loose equality in Node-API, so it's compared by a JavaScript function evaluated on the first call in the env.
+  /// The objects are converted with `ToPrimitive` when they are compared with the primitives,
+  /// the error thrown by `valueOf` or `Symbol.toPrimitive` is returned and kept pending in the env.
+  pub fn loose_equals(&self, other: &Unknown) -> Result<bool> {
+    let env = self.0.env;
+    let mut equals = ptr::null_mut();
+    if let Some(equals_ref) = LOOSE_EQUALS.with(|functions| functions.borrow().get(&env).copied()) {
+      check_status!(
+        unsafe { sys::napi_get_reference_value(env, equals_ref, &mut equals) },
+        "Failed to get the `==` function"
+      )?;
+    } else {
+      equals = Env::from_raw(env)
+        .run_script::<_, Unknown>("(a, b) => a == b")?
+        .0
+        .value;
+      let mut equals_ref = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_create_reference(env, equals, 1, &mut equals_ref) },
+        "Failed to create reference of the `==` function"
+      )?;
+      LOOSE_EQUALS.with(|functions| functions.borrow_mut().insert(env, equals_ref));
+    }
+    let mut recv = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_undefined(env, &mut recv) })?;
+    let args = [self.0.value, other.0.value];
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_call_function(env, recv, equals, args.len(), args.as_ptr(), &mut result) },
+      "Failed to compare the values with `==`"
+    )?;
+    unsafe { bool::from_napi_value(env, result) }
+  }
+
   /// # Safety
   ///
   /// JsUnknown doesn't have a type
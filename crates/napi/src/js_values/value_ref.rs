@@ -1,14 +1,26 @@
 use std::{marker::PhantomData, ptr};
 
 use crate::{
-  bindgen_runtime::{FromNapiMutRef, FromNapiValue, ToNapiValue},
+  bindgen_runtime::{is_env_alive, FromNapiMutRef, FromNapiValue, ToNapiValue},
   check_status, sys, Env, Result,
 };
 
 use super::JsValue;
 
+/// A reference to any JavaScript value, to persist it beyond the current native call.
+///
+/// The value is kept alive while the ref count is greater than `0`.
+/// The reference is deleted when the ref count reaches `0` by `unref`, or when the `Ref` is dropped,
+/// so a `Ref` can't be used after the `unref` which deleted it, and dropping it doesn't delete the reference again.
+///
+/// The reference can only be deleted on the JavaScript thread of its env, so dropping the `Ref`:
+///
+/// - on the other threads leaks the reference, `unref` it on the JavaScript thread before sending it away, or send it back to drop it.
+/// - after the env is torn down, for example it's held in a `static` or a `thread_local`, doesn't call N-API,
+///   the reference is released with the env.
 pub struct Ref<T> {
   pub(crate) raw_ref: sys::napi_ref,
+  pub(crate) env: sys::napi_env,
   pub(crate) _phantom: PhantomData<T>,
   pub(crate) taken: bool,
 }
@@ -19,31 +31,90 @@ unsafe impl<T> Sync for Ref<T> {}
 
 impl<'env, T: JsValue<'env>> Ref<T> {
   pub fn new(env: &Env, value: &T) -> Result<Ref<T>> {
+    Self::new_with_ref_count(env, value, 1)
+  }
+
+  /// Create a reference with the initial `ref_count`.
+  ///
+  /// The reference is weak if the `ref_count` is `0`, that is the value could be garbage collected,
+  /// and `get_value` returns an error after that.
+  pub fn new_with_ref_count(env: &Env, value: &T, ref_count: u32) -> Result<Ref<T>> {
     let mut raw_ref = ptr::null_mut();
     check_status!(
-      unsafe { sys::napi_create_reference(env.0, value.raw(), 1, &mut raw_ref) },
+      unsafe { sys::napi_create_reference(env.0, value.raw(), ref_count, &mut raw_ref) },
       "Create napi_ref from {} failed",
       std::any::type_name::<T>()
     )?;
-    Ok(Ref {
+    Ok(Ref::from_raw(env.0, raw_ref))
+  }
+}
+
+impl<T> Ref<T> {
+  pub(crate) fn from_raw(env: sys::napi_env, raw_ref: sys::napi_ref) -> Self {
+    Ref {
       raw_ref,
+      env,
       taken: false,
       _phantom: PhantomData,
-    })
+    }
   }
 
-  pub fn unref(&mut self, env: &Env) -> Result<()> {
+  /// Increase the ref count, returns the ref count after increased.
+  pub fn reference(&mut self, env: &Env) -> Result<u32> {
+    if self.taken {
+      return Err(crate::Error::new(
+        crate::Status::InvalidArg,
+        "Ref value has been deleted",
+      ));
+    }
+    let mut ref_count = 0;
     check_status!(
-      unsafe { sys::napi_reference_unref(env.0, self.raw_ref, &mut 0) },
-      "unref Ref failed"
+      unsafe { sys::napi_reference_ref(env.0, self.raw_ref, &mut ref_count) },
+      "ref Ref failed"
     )?;
+    Ok(ref_count)
+  }
 
+  /// Decrease the ref count.
+  ///
+  /// The reference is deleted once the ref count reaches `0`, and the `Ref` can't be used anymore.
+  pub fn unref(&mut self, env: &Env) -> Result<()> {
+    self.unreference(env).map(|_| ())
+  }
+
+  /// Same as `unref`, but returns the ref count after decreased.
+  pub fn unreference(&mut self, env: &Env) -> Result<u32> {
+    if self.taken {
+      return Err(crate::Error::new(
+        crate::Status::InvalidArg,
+        "Ref value has been deleted",
+      ));
+    }
+    let mut ref_count = 0;
     check_status!(
-      unsafe { sys::napi_delete_reference(env.0, self.raw_ref) },
-      "delete Ref failed"
+      unsafe { sys::napi_reference_unref(env.0, self.raw_ref, &mut ref_count) },
+      "unref Ref failed"
     )?;
-    self.taken = true;
-    Ok(())
+
+    if ref_count == 0 {
+      check_status!(
+        unsafe { sys::napi_delete_reference(env.0, self.raw_ref) },
+        "delete Ref failed"
+      )?;
+      self.taken = true;
+    }
+    Ok(ref_count)
+  }
+}
+
+impl<T> Drop for Ref<T> {
+  fn drop(&mut self) {
+    // `is_env_alive` is false on the other threads too
+    if self.taken || !is_env_alive(self.env) {
+      return;
+    }
+    let status = unsafe { sys::napi_delete_reference(self.env, self.raw_ref) };
+    debug_assert_eq!(status, sys::Status::napi_ok, "Drop Ref failed");
   }
 }
 
@@ -58,6 +129,13 @@ impl<T: FromNapiValue> Ref<T> {
     }
     let mut result = ptr::null_mut();
     check_status!(unsafe { sys::napi_get_reference_value(env.0, self.raw_ref, &mut result) })?;
+    // the value of a weak reference has been garbage collected
+    if result.is_null() {
+      return Err(crate::Error::new(
+        crate::Status::GenericFailure,
+        "Ref value has been garbage collected",
+      ));
+    }
     unsafe { T::from_napi_value(env.0, result) }
   }
 }
@@ -80,6 +158,12 @@ impl<'env, T: FromNapiValue + JsValue<'env>> FromNapiValue for Ref<T> {
 
 impl<T: 'static> ToNapiValue for Ref<T> {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    if val.taken {
+      return Err(crate::Error::new(
+        crate::Status::InvalidArg,
+        "Ref value has been deleted",
+      ));
+    }
     let mut result = ptr::null_mut();
     check_status!(
       unsafe { sys::napi_get_reference_value(env, val.raw_ref, &mut result) },
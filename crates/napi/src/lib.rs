@@ -91,10 +91,10 @@ mod js_values;
 mod status;
 mod task;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
-mod tokio_runtime;
+pub mod tokio_runtime;
 mod value_type;
 #[cfg(feature = "napi3")]
-pub use cleanup_env::CleanupEnvHook;
+pub use cleanup_env::{CleanupEnvHook, CleanupHookHandle};
 #[cfg(feature = "napi4")]
 pub mod threadsafe_function;
 
@@ -111,7 +111,7 @@ pub use env::*;
 pub use error::*;
 pub use js_values::*;
 pub use status::Status;
-pub use task::Task;
+pub use task::{ProgressReporter, Task};
 pub use value_type::*;
 pub use version::NodeVersion;
 #[cfg(feature = "serde-json")]
@@ -155,11 +155,18 @@ pub mod bindgen_prelude {
   #[cfg(all(feature = "compat-mode", not(feature = "noop")))]
   pub use crate::bindgen_runtime::register_module_exports;
   #[cfg(feature = "tokio_rt")]
-  pub use crate::tokio_runtime::*;
+  pub use crate::tokio_runtime::{
+    block_on, create_custom_tokio_runtime, execute_tokio_future, within_runtime_if_available,
+    AsyncBlock, AsyncBlockBuilder,
+  };
+  #[cfg(all(feature = "tokio_rt", not(feature = "noop")))]
+  pub use crate::tokio_runtime::{
+    env_scope, shutdown_async_runtime, spawn, spawn_blocking, start_async_runtime,
+  };
   pub use crate::{
     assert_type_of, bindgen_runtime::*, check_pending_exception, check_status,
     check_status_or_throw, error, error::*, sys, type_of, JsError, JsValue, Property,
-    PropertyAttributes, Result, Status, Task, ValueType,
+    PropertyAttributes, PropertyDescriptor, Result, Status, Task, ValueType,
   };
 
   // This function's signature must be kept in sync with the one in tokio_runtime.rs, otherwise napi
@@ -176,10 +183,166 @@ pub mod bindgen_prelude {
 #[doc(hidden)]
 pub mod __private {
   pub use crate::bindgen_runtime::{
-    get_class_constructor, iterator::create_iterator, register_class, ___CALL_FROM_FACTORY,
+    error::panic_to_error, get_class_constructor, iterator::create_iterator, register_class,
+    ___CALL_FROM_FACTORY,
   };
 
-  use crate::sys;
+  use crate::{check_status, sys};
+
+  /// Allocate the class instance with the installed `TaggedObjectAllocator`, or `Box` if there is none
+  pub fn alloc_class_instance<T>(value: T) -> *mut T {
+    crate::js_values::alloc_native_object(value)
+  }
+
+  /// Throw a `TypeError` and return `false` if the class constructor is called without `new`
+  pub unsafe fn check_new_target(
+    env: sys::napi_env,
+    cb: sys::napi_callback_info,
+    class_name: &str,
+  ) -> bool {
+    let mut new_target = std::ptr::null_mut();
+    let status = unsafe { sys::napi_get_new_target(env, cb, &mut new_target) };
+    if status == sys::Status::napi_ok && !new_target.is_null() {
+      return true;
+    }
+    let message = if status == sys::Status::napi_ok {
+      format!(
+        "Class constructor {} cannot be invoked without 'new'",
+        class_name
+      )
+    } else {
+      format!(
+        "Failed to get new.target of class constructor {}",
+        class_name
+      )
+    };
+    let message = std::ffi::CString::new(message).unwrap_or_default();
+    unsafe { sys::napi_throw_type_error(env, std::ptr::null(), message.as_ptr()) };
+    false
+  }
+
+  thread_local! {
+    /// The `Symbol` keys of the `#[napi(getter, cache)]` getters, each key is created once per env
+    static CACHED_GETTER_KEYS: std::cell::RefCell<
+      std::collections::HashMap<(sys::napi_env, &'static str), sys::napi_ref>,
+    > = Default::default();
+  }
+
+  /// Forget the `Symbol` keys of the cached getters created in the `env`, which is torn down
+  #[cfg(not(feature = "noop"))]
+  pub(crate) fn clear_cached_getter_keys(env: sys::napi_env) {
+    let _ = CACHED_GETTER_KEYS
+      .try_with(|keys| keys.borrow_mut().retain(|(key_env, _), _| *key_env != env));
+  }
+
+  /// The `Symbol` which the value of the cached getter is stored on the instance with, the `key` is the description of it
+  unsafe fn cached_getter_key(
+    env: sys::napi_env,
+    key: &'static str,
+  ) -> crate::Result<sys::napi_value> {
+    use std::ptr;
+
+    let mut symbol = ptr::null_mut();
+    if let Some(key_ref) = CACHED_GETTER_KEYS.with(|keys| keys.borrow().get(&(env, key)).copied()) {
+      check_status!(
+        unsafe { sys::napi_get_reference_value(env, key_ref, &mut symbol) },
+        "Failed to get the key of the cached getter"
+      )?;
+      return Ok(symbol);
+    }
+    let mut description = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_string_utf8(
+          env,
+          key.as_ptr().cast(),
+          key.len() as isize - 1,
+          &mut description,
+        )
+      },
+      "Failed to create the description of the cached getter key"
+    )?;
+    check_status!(
+      unsafe { sys::napi_create_symbol(env, description, &mut symbol) },
+      "Failed to create the key of the cached getter"
+    )?;
+    let mut key_ref = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_reference(env, symbol, 1, &mut key_ref) },
+      "Failed to create reference of the cached getter key"
+    )?;
+    CACHED_GETTER_KEYS.with(|keys| keys.borrow_mut().insert((env, key), key_ref));
+    Ok(symbol)
+  }
+
+  /// Returns the `this` of the `#[napi(getter, cache)]` getter, and the value cached on it if there is one
+  ///
+  /// The `key` must end with `\0`
+  pub unsafe fn get_cached_getter_value(
+    env: sys::napi_env,
+    cb: sys::napi_callback_info,
+    key: &'static str,
+  ) -> crate::Result<(sys::napi_value, Option<sys::napi_value>)> {
+    use std::ptr;
+
+    let mut this = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_cb_info(
+          env,
+          cb,
+          ptr::null_mut(),
+          ptr::null_mut(),
+          &mut this,
+          ptr::null_mut(),
+        )
+      },
+      "Failed to get this of the cached getter"
+    )?;
+    let key_value = unsafe { cached_getter_key(env, key) }?;
+    let mut has_cached = false;
+    check_status!(
+      unsafe { sys::napi_has_own_property(env, this, key_value, &mut has_cached) },
+      "Failed to check the cached value of the getter"
+    )?;
+    if !has_cached {
+      return Ok((this, None));
+    }
+    let mut cached = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_property(env, this, key_value, &mut cached) },
+      "Failed to get the cached value of the getter"
+    )?;
+    Ok((this, Some(cached)))
+  }
+
+  /// Cache the `value` returned by the `#[napi(getter, cache)]` getter on `this` as a non-enumerable and non-writable property,
+  /// keyed by a `Symbol` shared by the instances of the class, so it's hidden from the string keys.
+  ///
+  /// The property is configurable, the getter creates the value again after it's deleted.
+  /// The `key` must end with `\0`
+  pub unsafe fn set_cached_getter_value(
+    env: sys::napi_env,
+    this: sys::napi_value,
+    key: &'static str,
+    value: sys::napi_value,
+  ) -> crate::Result<sys::napi_value> {
+    let property = sys::napi_property_descriptor {
+      utf8name: std::ptr::null(),
+      name: unsafe { cached_getter_key(env, key) }?,
+      method: None,
+      getter: None,
+      setter: None,
+      value,
+      attributes: sys::PropertyAttributes::configurable,
+      data: std::ptr::null_mut(),
+    };
+    check_status!(
+      unsafe { sys::napi_define_properties(env, this, 1, &property) },
+      "Failed to cache the value of the getter"
+    )?;
+    Ok(value)
+  }
 
   pub unsafe fn log_js_value<V: AsRef<[sys::napi_value]>>(
     // `info`, `log`, `warning` or `error`
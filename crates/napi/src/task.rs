@@ -1,7 +1,18 @@
+#[cfg(feature = "napi4")]
+use std::ffi::c_void;
+#[cfg(feature = "napi4")]
+use std::ptr;
+use std::sync::{
+  atomic::{AtomicBool, Ordering},
+  Arc,
+};
+
 use crate::{
   bindgen_runtime::{ToNapiValue, TypeName},
   Env, Error, Result,
 };
+#[cfg(feature = "napi4")]
+use crate::{check_status, sys, JsError};
 
 pub trait Task: Send + Sized {
   type Output: Send + Sized + 'static;
@@ -10,6 +21,15 @@ pub trait Task: Send + Sized {
   /// Compute logic in libuv thread
   fn compute(&mut self) -> Result<Self::Output>;
 
+  #[allow(unused_variables)]
+  /// Compute logic in libuv thread, with a `ProgressReporter` to post the intermediate progress back to JavaScript
+  ///
+  /// The reporter is connected to the callback passed to `AsyncTask::on_progress`, and does nothing otherwise.
+  /// Call `compute` by default.
+  fn compute_with_progress(&mut self, progress: &ProgressReporter) -> Result<Self::Output> {
+    self.compute()
+  }
+
   /// Into this method if `compute` return `Ok`
   fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue>;
 
@@ -25,3 +45,178 @@ pub trait Task: Send + Sized {
     Ok(())
   }
 }
+
+/// Post the progress of a running `Task` back to JavaScript
+///
+/// Progress reported after the `AbortSignal` of the task is aborted is dropped,
+/// including the ones that are queued but not delivered yet.
+pub struct ProgressReporter {
+  #[cfg(feature = "napi4")]
+  tsfn: sys::napi_threadsafe_function,
+  aborted: Option<Arc<AtomicBool>>,
+}
+
+unsafe impl Send for ProgressReporter {}
+unsafe impl Sync for ProgressReporter {}
+
+impl Default for ProgressReporter {
+  fn default() -> Self {
+    Self {
+      #[cfg(feature = "napi4")]
+      tsfn: ptr::null_mut(),
+      aborted: None,
+    }
+  }
+}
+
+impl ProgressReporter {
+  #[cfg(feature = "napi4")]
+  pub(crate) fn new(
+    env: sys::napi_env,
+    callback: sys::napi_value,
+    aborted: Option<Arc<AtomicBool>>,
+  ) -> Result<Self> {
+    const RESOURCE_NAME: &str = "napi_rs_task_progress";
+    let mut async_resource_name = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_string_utf8(
+          env,
+          RESOURCE_NAME.as_ptr().cast(),
+          RESOURCE_NAME.len() as isize,
+          &mut async_resource_name,
+        )
+      },
+      "Create string utf8 in ProgressReporter::new failed"
+    )?;
+    let context = Box::into_raw(Box::new(aborted.clone()));
+    let mut tsfn = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_threadsafe_function(
+          env,
+          callback,
+          ptr::null_mut(),
+          async_resource_name,
+          0,
+          1,
+          ptr::null_mut(),
+          Some(progress_finalize),
+          context.cast(),
+          Some(call_progress_callback),
+          &mut tsfn,
+        )
+      },
+      "Create threadsafe function in ProgressReporter::new failed"
+    )?;
+    Ok(Self { tsfn, aborted })
+  }
+
+  /// Post the `progress` to the JavaScript callback, it's a no-op if there is no callback
+  pub fn report(&self, progress: f64) {
+    #[cfg(feature = "napi4")]
+    {
+      if self.tsfn.is_null() || self.is_aborted() {
+        return;
+      }
+      let data = Box::into_raw(Box::new(progress));
+      let status = unsafe {
+        sys::napi_call_threadsafe_function(
+          self.tsfn,
+          data.cast(),
+          sys::ThreadsafeFunctionCallMode::nonblocking,
+        )
+      };
+      if status != sys::Status::napi_ok {
+        drop(unsafe { Box::from_raw(data) });
+      }
+    }
+    #[cfg(not(feature = "napi4"))]
+    let _ = progress;
+  }
+
+  /// Whether the `AbortSignal` of the task has been aborted
+  ///
+  /// A started task can't be canceled, check it in `compute_with_progress` to stop the work early.
+  pub fn is_aborted(&self) -> bool {
+    self
+      .aborted
+      .as_ref()
+      .map(|aborted| aborted.load(Ordering::Relaxed))
+      .unwrap_or(false)
+  }
+}
+
+impl Drop for ProgressReporter {
+  fn drop(&mut self) {
+    #[cfg(feature = "napi4")]
+    if !self.tsfn.is_null() {
+      unsafe {
+        sys::napi_release_threadsafe_function(
+          self.tsfn,
+          sys::ThreadsafeFunctionReleaseMode::release,
+        )
+      };
+    }
+  }
+}
+
+#[cfg(feature = "napi4")]
+unsafe extern "C" fn progress_finalize(
+  _env: sys::napi_env,
+  _finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  drop(unsafe { Box::from_raw(finalize_hint.cast::<Option<Arc<AtomicBool>>>()) });
+}
+
+#[cfg(feature = "napi4")]
+unsafe extern "C" fn call_progress_callback(
+  env: sys::napi_env,
+  js_callback: sys::napi_value,
+  context: *mut c_void,
+  data: *mut c_void,
+) {
+  let progress = *unsafe { Box::from_raw(data.cast::<f64>()) };
+  // env and/or callback can be null when shutting down
+  if env.is_null() || js_callback.is_null() {
+    return;
+  }
+  let aborted = unsafe { &*context.cast::<Option<Arc<AtomicBool>>>() };
+  if let Some(aborted) = aborted {
+    if aborted.load(Ordering::Relaxed) {
+      return;
+    }
+  }
+  if let Err(err) = call_progress_callback_impl(env, js_callback, progress) {
+    let mut is_pending = false;
+    unsafe { sys::napi_is_exception_pending(env, &mut is_pending) };
+    let exception = if is_pending {
+      // the JavaScript callback throws
+      let mut exception = ptr::null_mut();
+      unsafe { sys::napi_get_and_clear_last_exception(env, &mut exception) };
+      exception
+    } else {
+      unsafe { JsError::from(err).into_value(env) }
+    };
+    unsafe { sys::napi_fatal_exception(env, exception) };
+  }
+}
+
+#[cfg(feature = "napi4")]
+fn call_progress_callback_impl(
+  env: sys::napi_env,
+  js_callback: sys::napi_value,
+  progress: f64,
+) -> Result<()> {
+  let mut recv = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_undefined(env, &mut recv) },
+    "Get undefined failed in Task progress callback"
+  )?;
+  let progress = unsafe { ToNapiValue::to_napi_value(env, progress)? };
+  check_status!(
+    unsafe { sys::napi_call_function(env, recv, js_callback, 1, &progress, ptr::null_mut()) },
+    "Call Task progress callback failed"
+  )
+}
@@ -7,9 +7,11 @@ use std::os::raw::c_void;
 use std::ptr::{self, null_mut};
 use std::sync::{
   self,
-  atomic::{AtomicBool, AtomicPtr, Ordering},
-  Arc, RwLock, RwLockWriteGuard,
+  atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering},
+  Arc, Mutex, RwLock, RwLockWriteGuard,
 };
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::bindgen_runtime::{
   FromNapiValue, JsValuesTupleIntoVec, TypeName, Unknown, ValidateNapiValue,
@@ -48,6 +50,55 @@ struct ThreadsafeFunctionHandle {
   raw: AtomicPtr<sys::napi_threadsafe_function__>,
   aborted: RwLock<bool>,
   referred: AtomicBool,
+  /// Number of calls that have been queued but not yet dispatched to the JavaScript thread
+  queue_size: Arc<AtomicUsize>,
+  dispatch: Arc<Mutex<DispatchState>>,
+}
+
+/// Tracks the `call_js_cb` running on the JavaScript thread.
+///
+/// The release of a ThreadsafeFunction dropped inside its own callback is deferred until the callback returns.
+#[derive(Default)]
+struct DispatchState {
+  depth: usize,
+  deferred_release: Option<sys::napi_threadsafe_function>,
+}
+
+// The raw ThreadsafeFunction can be released from any thread
+unsafe impl Send for DispatchState {}
+
+impl DispatchState {
+  fn enter(state: &Mutex<Self>) {
+    state
+      .lock()
+      .expect("Threadsafe Function dispatch lock failed")
+      .depth += 1;
+  }
+
+  /// Returns the ThreadsafeFunction to release if it was dropped in the callback
+  fn exit(state: &Mutex<Self>) -> Option<sys::napi_threadsafe_function> {
+    let mut state = state
+      .lock()
+      .expect("Threadsafe Function dispatch lock failed");
+    state.depth -= 1;
+    if state.depth == 0 {
+      state.deferred_release.take()
+    } else {
+      None
+    }
+  }
+
+  /// Returns `false` if the release is deferred
+  fn try_release(state: &Mutex<Self>, raw: sys::napi_threadsafe_function) -> bool {
+    let mut state = state
+      .lock()
+      .expect("Threadsafe Function dispatch lock failed");
+    if state.depth == 0 {
+      return true;
+    }
+    state.deferred_release = Some(raw);
+    false
+  }
 }
 
 impl ThreadsafeFunctionHandle {
@@ -57,6 +108,8 @@ impl ThreadsafeFunctionHandle {
       raw: AtomicPtr::new(raw),
       aborted: RwLock::new(false),
       referred: AtomicBool::new(true),
+      queue_size: Arc::new(AtomicUsize::new(0)),
+      dispatch: Default::default(),
     })
   }
 
@@ -96,34 +149,89 @@ impl ThreadsafeFunctionHandle {
   fn set_raw(&self, raw: sys::napi_threadsafe_function) {
     self.raw.store(raw, Ordering::SeqCst)
   }
+
+  /// Call `napi_call_threadsafe_function` and keep track of the queue size
+  ///
+  /// # Safety
+  ///
+  /// The `data` must be the type that the `call_js_cb` of this ThreadsafeFunction expects
+  unsafe fn call(&self, data: *mut c_void, mode: ThreadsafeFunctionCallMode) -> sys::napi_status {
+    // increase before calling, the `call_js_cb` may be dispatched before `napi_call_threadsafe_function` returns
+    self.queue_size.fetch_add(1, Ordering::SeqCst);
+    let status = unsafe { sys::napi_call_threadsafe_function(self.get_raw(), data, mode.into()) };
+    if status != sys::Status::napi_ok {
+      self.queue_size.fetch_sub(1, Ordering::SeqCst);
+    }
+    status
+  }
+
+  /// Retry the `NonBlocking` call until the queue accepts the `data` or the `timeout` elapsed.
+  ///
+  /// The `data` is dropped if it isn't queued.
+  ///
+  /// # Safety
+  ///
+  /// `D` must be the type that the `call_js_cb` of this ThreadsafeFunction expects
+  unsafe fn call_with_timeout<D>(&self, data: Box<D>, timeout: Duration) -> Status {
+    let data = Box::into_raw(data);
+    let deadline = Instant::now() + timeout;
+    let mut backoff = Duration::from_micros(10);
+    let status = loop {
+      let status = self.with_read_aborted(|aborted| {
+        if aborted {
+          sys::Status::napi_closing
+        } else {
+          unsafe { self.call(data.cast(), ThreadsafeFunctionCallMode::NonBlocking) }
+        }
+      });
+      let now = Instant::now();
+      if status != sys::Status::napi_queue_full || now >= deadline {
+        break status;
+      }
+      thread::sleep(backoff.min(deadline - now));
+      backoff = (backoff * 2).min(Duration::from_millis(1));
+    };
+    if status != sys::Status::napi_ok {
+      drop(unsafe { Box::from_raw(data) });
+    }
+    status.into()
+  }
 }
 
 impl Drop for ThreadsafeFunctionHandle {
   fn drop(&mut self) {
     self.with_read_aborted(|aborted| {
-      if !aborted {
-        let release_status = unsafe {
-          sys::napi_release_threadsafe_function(
-            self.get_raw(),
-            sys::ThreadsafeFunctionReleaseMode::release,
-          )
-        };
-        assert!(
-          release_status == sys::Status::napi_ok,
-          "Threadsafe Function release failed {}",
-          Status::from(release_status)
-        );
+      if !aborted && DispatchState::try_release(&self.dispatch, self.get_raw()) {
+        release_raw_threadsafe_function(self.get_raw());
       }
     })
   }
 }
 
+fn release_raw_threadsafe_function(raw: sys::napi_threadsafe_function) {
+  let release_status = unsafe {
+    sys::napi_release_threadsafe_function(raw, sys::ThreadsafeFunctionReleaseMode::release)
+  };
+  assert!(
+    release_status == sys::Status::napi_ok,
+    "Threadsafe Function release failed {}",
+    Status::from(release_status)
+  );
+}
+
 #[repr(u8)]
 enum ThreadsafeFunctionCallVariant {
   Direct,
   WithCallback,
 }
 
+/// The `context` of the underlying `napi_threadsafe_function`
+struct ThreadsafeFunctionContext<R> {
+  callback: R,
+  queue_size: Arc<AtomicUsize>,
+  dispatch: Arc<Mutex<DispatchState>>,
+}
+
 struct ThreadsafeFunctionCallJsBackData<T, Return = Unknown<'static>> {
   data: T,
   call_variant: ThreadsafeFunctionCallVariant,
@@ -162,6 +270,10 @@ struct ThreadsafeFunctionCallJsBackData<T, Return = Unknown<'static>> {
 ///   });
 /// }
 /// ```
+///
+/// It's fine to drop the last `ThreadsafeFunction` inside its own callback,
+/// for example in the callback of `call_with_return_value`,
+/// the underlying `napi_threadsafe_function` is released after the callback returns.
 pub struct ThreadsafeFunction<
   T: 'static,
   Return: 'static + FromNapiValue = Unknown<'static>,
@@ -342,8 +454,12 @@ impl<
     }
 
     let mut raw_tsfn = ptr::null_mut();
-    let callback_ptr = Box::into_raw(Box::new(callback));
     let handle = ThreadsafeFunctionHandle::null();
+    let context_ptr = Box::into_raw(Box::new(ThreadsafeFunctionContext {
+      callback,
+      queue_size: handle.queue_size.clone(),
+      dispatch: handle.dispatch.clone(),
+    }));
     check_status!(
       unsafe {
         sys::napi_create_threadsafe_function(
@@ -355,7 +471,7 @@ impl<
           1,
           Arc::downgrade(&handle).into_raw().cast_mut().cast(), // pass handler to thread_finalize_cb
           Some(thread_finalize_cb::<T, NewArgs, R>),
-          callback_ptr.cast(),
+          context_ptr.cast(),
           Some(call_js_cb::<T, Return, NewArgs, ErrorStatus, R, CalleeHandled>),
           &mut raw_tsfn,
         )
@@ -437,6 +553,14 @@ impl<
     })
   }
 
+  /// Number of calls that are queued but haven't been dispatched to the JavaScript thread yet.
+  ///
+  /// Node-API doesn't expose the queue of `napi_threadsafe_function`, so this is counted by the calls made through this `ThreadsafeFunction` and its clones.
+  /// Producers can use it to slow down before the queue reaches the `MaxQueueSize`.
+  pub fn queue_size(&self) -> usize {
+    self.handle.queue_size.load(Ordering::SeqCst)
+  }
+
   /// Get the raw `ThreadSafeFunction` pointer
   pub fn raw(&self) -> sys::napi_threadsafe_function {
     self.handle.get_raw()
@@ -461,8 +585,7 @@ impl<
       }
 
       unsafe {
-        sys::napi_call_threadsafe_function(
-          self.handle.get_raw(),
+        self.handle.call(
           Box::into_raw(Box::new(value.map(|data| {
             ThreadsafeFunctionCallJsBackData {
               data,
@@ -471,13 +594,26 @@ impl<
             }
           })))
           .cast(),
-          mode.into(),
+          mode,
         )
       }
       .into()
     })
   }
 
+  /// Call the ThreadsafeFunction in `NonBlocking` mode, retrying while the queue is full.
+  ///
+  /// Returns `Status::QueueFull` if the queue can't accept the value within the `timeout`, the value is dropped in that case.
+  /// Don't call it on the JavaScript thread, the queue can't be drained while it's waiting.
+  pub fn call_with_timeout(&self, value: Result<T, ErrorStatus>, timeout: Duration) -> Status {
+    let data = Box::new(value.map(|data| ThreadsafeFunctionCallJsBackData {
+      data,
+      call_variant: ThreadsafeFunctionCallVariant::Direct,
+      callback: Box::new(|_d: Result<Return>, _| Ok(())),
+    }));
+    unsafe { self.handle.call_with_timeout(data, timeout) }
+  }
+
   /// Call the ThreadsafeFunction, and handle the return value with a callback
   pub fn call_with_return_value<F: 'static + FnOnce(Result<Return>, Env) -> Result<()>>(
     &self,
@@ -491,8 +627,7 @@ impl<
       }
 
       unsafe {
-        sys::napi_call_threadsafe_function(
-          self.handle.get_raw(),
+        self.handle.call(
           Box::into_raw(Box::new(value.map(|data| {
             ThreadsafeFunctionCallJsBackData {
               data,
@@ -501,7 +636,7 @@ impl<
             }
           })))
           .cast(),
-          mode.into(),
+          mode,
         )
       }
       .into()
@@ -520,8 +655,7 @@ impl<
 
       check_status!(
         unsafe {
-          sys::napi_call_threadsafe_function(
-            self.handle.get_raw(),
+          self.handle.call(
             Box::into_raw(Box::new(value.map(|data| {
               ThreadsafeFunctionCallJsBackData {
                 data,
@@ -536,7 +670,7 @@ impl<
               }
             })))
             .cast(),
-            ThreadsafeFunctionCallMode::NonBlocking.into(),
+            ThreadsafeFunctionCallMode::NonBlocking,
           )
         },
         "Threadsafe function call_async failed"
@@ -572,21 +706,33 @@ impl<
       }
 
       unsafe {
-        sys::napi_call_threadsafe_function(
-          self.handle.get_raw(),
+        self.handle.call(
           Box::into_raw(Box::new(ThreadsafeFunctionCallJsBackData {
             data: value,
             call_variant: ThreadsafeFunctionCallVariant::Direct,
             callback: Box::new(|_d: Result<Return>, _: Env| Ok(())),
           }))
           .cast(),
-          mode.into(),
+          mode,
         )
       }
       .into()
     })
   }
 
+  /// Call the ThreadsafeFunction in `NonBlocking` mode, retrying while the queue is full.
+  ///
+  /// Returns `Status::QueueFull` if the queue can't accept the value within the `timeout`, the value is dropped in that case.
+  /// Don't call it on the JavaScript thread, the queue can't be drained while it's waiting.
+  pub fn call_with_timeout(&self, value: T, timeout: Duration) -> Status {
+    let data = Box::new(ThreadsafeFunctionCallJsBackData {
+      data: value,
+      call_variant: ThreadsafeFunctionCallVariant::Direct,
+      callback: Box::new(|_d: Result<Return>, _: Env| Ok(())),
+    });
+    unsafe { self.handle.call_with_timeout(data, timeout) }
+  }
+
   /// Call the ThreadsafeFunction, and handle the return value with a callback
   pub fn call_with_return_value<F: 'static + FnOnce(Result<Return>, Env) -> Result<()>>(
     &self,
@@ -600,15 +746,14 @@ impl<
       }
 
       unsafe {
-        sys::napi_call_threadsafe_function(
-          self.handle.get_raw(),
+        self.handle.call(
           Box::into_raw(Box::new(ThreadsafeFunctionCallJsBackData {
             data: value,
             call_variant: ThreadsafeFunctionCallVariant::WithCallback,
             callback: Box::new(cb),
           }))
           .cast(),
-          mode.into(),
+          mode,
         )
       }
       .into()
@@ -617,8 +762,11 @@ impl<
 
   #[cfg(feature = "tokio_rt")]
   /// Call the ThreadsafeFunction, and handle the return value with in `async` way
+  ///
+  /// If the JavaScript callback throws, the error is returned as `Err` rather than thrown into the event loop.
+  /// If the JavaScript callback returns a `Promise`, use `Promise<R>` as the `Return` type and await it as well.
   pub async fn call_async(&self, value: T) -> Result<Return> {
-    let (sender, receiver) = tokio::sync::oneshot::channel::<Return>();
+    let (sender, receiver) = tokio::sync::oneshot::channel::<Result<Return>>();
 
     self.handle.with_read_aborted(|aborted| {
       if aborted {
@@ -626,30 +774,143 @@ impl<
       }
 
       check_status!(unsafe {
-        sys::napi_call_threadsafe_function(
-          self.handle.get_raw(),
+        self.handle.call(
           Box::into_raw(Box::new(ThreadsafeFunctionCallJsBackData {
             data: value,
             call_variant: ThreadsafeFunctionCallVariant::WithCallback,
             callback: Box::new(move |d, _| {
-              d.and_then(|d| {
-                sender
-                  .send(d)
-                  // The only reason for send to return Err is if the receiver isn't listening
-                  // Not hiding the error would result in a napi_fatal_error call, it's safe to ignore it instead.
-                  .or(Ok(()))
-              })
+              sender
+                .send(d)
+                // The only reason for send to return Err is if the receiver isn't listening
+                // Not hiding the error would result in a napi_fatal_error call, it's safe to ignore it instead.
+                .or(Ok(()))
             }),
           }))
           .cast(),
-          ThreadsafeFunctionCallMode::NonBlocking.into(),
+          ThreadsafeFunctionCallMode::NonBlocking,
         )
       })
     })?;
 
     receiver
       .await
-      .map_err(|err| crate::Error::new(Status::GenericFailure, format!("{}", err)))
+      .map_err(|_| {
+        crate::Error::new(
+          Status::GenericFailure,
+          "Receive value from threadsafe function sender failed",
+        )
+      })
+      .and_then(identity)
+  }
+}
+
+/// A ThreadsafeFunction that keeps only the latest value while the call is waiting to be dispatched
+///
+/// It's for the high frequency events like progress or mouse move, where only the latest value matters.
+/// `call_latest` replaces the value of the pending call instead of queueing another call if the JavaScript thread hasn't picked it up yet,
+/// so there is at most one call of this function in the queue no matter how fast the values are produced.
+/// The JavaScript callback is always called with the most recent value, the replaced values are dropped without being sent.
+///
+/// Like the `ThreadsafeFunction` with `CalleeHandled = false`, the callback is called with the value as arguments directly.
+///
+/// ```rust
+/// use std::thread;
+///
+/// use napi::{bindgen_prelude::*, threadsafe_function::CoalescingThreadsafeFunction};
+/// use napi_derive::napi;
+///
+/// #[napi]
+/// pub fn watch_progress(on_progress: CoalescingThreadsafeFunction<u32>) {
+///   thread::spawn(move || {
+///     for progress in 0..=100 {
+///       on_progress.call_latest(progress);
+///     }
+///   });
+/// }
+/// ```
+pub struct CoalescingThreadsafeFunction<
+  T: 'static + JsValuesTupleIntoVec,
+  Return: 'static + FromNapiValue = Unknown<'static>,
+> {
+  inner: ThreadsafeFunction<(), Return, T, Status, false>,
+  /// The value of the pending call, a call is queued only when it changes from `None` to `Some`
+  latest: Arc<Mutex<Option<T>>>,
+}
+
+impl<T: 'static + JsValuesTupleIntoVec, Return: 'static + FromNapiValue> FromNapiValue
+  for CoalescingThreadsafeFunction<T, Return>
+{
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let latest = Arc::new(Mutex::new(None));
+    let inner = ThreadsafeFunction::<(), Return, T, Status, false>::create(env, napi_val, {
+      let latest = latest.clone();
+      move |_ctx| {
+        latest
+          .lock()
+          .expect("Coalescing Threadsafe Function lock failed")
+          .take()
+          .ok_or_else(|| {
+            Error::new(
+              Status::GenericFailure,
+              "No value for the Coalescing Threadsafe Function call".to_owned(),
+            )
+          })
+      }
+    })?;
+    Ok(Self { inner, latest })
+  }
+}
+
+impl<T: 'static + JsValuesTupleIntoVec, Return: 'static + FromNapiValue> TypeName
+  for CoalescingThreadsafeFunction<T, Return>
+{
+  fn type_name() -> &'static str {
+    "CoalescingThreadsafeFunction"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Function
+  }
+}
+
+impl<T: 'static + JsValuesTupleIntoVec, Return: 'static + FromNapiValue> ValidateNapiValue
+  for CoalescingThreadsafeFunction<T, Return>
+{
+}
+
+impl<T: 'static + JsValuesTupleIntoVec, Return: 'static + FromNapiValue>
+  CoalescingThreadsafeFunction<T, Return>
+{
+  /// Call the JavaScript function with the `value` in `NonBlocking` mode,
+  /// or replace the value of the pending call if the previous one hasn't been dispatched yet.
+  ///
+  /// Returns `Status::Closing` if the ThreadsafeFunction has been released, the `value` is dropped in that case.
+  pub fn call_latest(&self, value: T) -> Status {
+    if self.inner.aborted() {
+      return Status::Closing;
+    }
+    // The lock is held while queueing, so the JavaScript thread can't take the value between the check and the call
+    let mut latest = self
+      .latest
+      .lock()
+      .expect("Coalescing Threadsafe Function lock failed");
+    if latest.replace(value).is_some() {
+      return Status::Ok;
+    }
+    let status = self.inner.call((), ThreadsafeFunctionCallMode::NonBlocking);
+    if status != Status::Ok {
+      latest.take();
+    }
+    status
+  }
+
+  pub fn aborted(&self) -> bool {
+    self.inner.aborted()
+  }
+
+  /// Get the raw `ThreadSafeFunction` pointer
+  pub fn raw(&self) -> sys::napi_threadsafe_function {
+    self.inner.raw()
   }
 }
 
@@ -672,7 +933,7 @@ unsafe extern "C" fn thread_finalize_cb<T: 'static, V: 'static + JsValuesTupleIn
   }
 
   // cleanup
-  drop(unsafe { Box::<R>::from_raw(finalize_hint.cast()) });
+  drop(unsafe { Box::<ThreadsafeFunctionContext<R>>::from_raw(finalize_hint.cast()) });
 }
 
 unsafe extern "C" fn call_js_cb<
@@ -690,12 +951,17 @@ unsafe extern "C" fn call_js_cb<
 ) where
   R: 'static + FnMut(ThreadsafeCallContext<T>) -> Result<V>,
 {
+  let context: &mut ThreadsafeFunctionContext<R> =
+    unsafe { Box::leak(Box::from_raw(context.cast())) };
+  context.queue_size.fetch_sub(1, Ordering::SeqCst);
+
   // env and/or callback can be null when shutting down
   if raw_env.is_null() || js_callback.is_null() {
     return;
   }
 
-  let callback: &mut R = unsafe { Box::leak(Box::from_raw(context.cast())) };
+  DispatchState::enter(&context.dispatch);
+  let callback = &mut context.callback;
   let val = unsafe {
     if CalleeHandled {
       *Box::<Result<ThreadsafeFunctionCallJsBackData<T, Return>, ErrorStatus>>::from_raw(
@@ -755,6 +1021,7 @@ unsafe extern "C" fn call_js_cb<
               maybe_env: raw_env,
               status: Status::from(raw_status),
               reason,
+              cause: None,
             })
           })
         } else {
@@ -780,7 +1047,11 @@ unsafe extern "C" fn call_js_cb<
       )
     },
   };
-  handle_call_js_cb_status(status, raw_env)
+  handle_call_js_cb_status(status, raw_env);
+  // the ThreadsafeFunction was dropped in the callback
+  if let Some(raw) = DispatchState::exit(&context.dispatch) {
+    release_raw_threadsafe_function(raw);
+  }
 }
 
 fn handle_call_js_cb_status(status: sys::napi_status, raw_env: sys::napi_env) {
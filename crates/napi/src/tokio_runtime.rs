@@ -6,7 +6,7 @@ use tokio::runtime::Runtime;
 
 use crate::{bindgen_runtime::ToNapiValue, sys, Env, Error, Result};
 #[cfg(not(feature = "noop"))]
-use crate::{JsDeferred, Unknown};
+use crate::{check_status, JsDeferred, JsValue, Unknown};
 
 #[cfg(not(feature = "noop"))]
 fn create_runtime() -> Runtime {
@@ -102,6 +102,19 @@ where
     .expect("Access tokio runtime failed in spawn")
 }
 
+#[cfg(not(feature = "noop"))]
+/// Spawns a future onto the Tokio runtime if the runtime is still alive.
+///
+/// Returns `None` and drops the future if the runtime has been shut down.
+pub(crate) fn try_spawn<F>(fut: F) -> Option<tokio::task::JoinHandle<F::Output>>
+where
+  F: 'static + Send + Future<Output = ()>,
+{
+  RT.read()
+    .ok()
+    .and_then(|rt| rt.as_ref().map(|rt| rt.spawn(fut)))
+}
+
 #[cfg(not(feature = "noop"))]
 /// Runs a future to completion
 /// This is blocking, meaning that it pauses other execution until the future is complete,
@@ -134,6 +147,63 @@ where
     .expect("Access tokio runtime failed in spawn_blocking")
 }
 
+#[cfg(not(feature = "noop"))]
+/// Get the `Handle` of the Tokio runtime used by the NAPI-RS, the runtime will be created if it's not initialized yet.
+///
+/// Spawn the tasks of the addon onto this runtime instead of creating another one,
+/// so they are stopped together with the async functions when the Node env exits.
+///
+/// It panics if the runtime has been shut down by `shutdown_async_runtime`.
+pub fn handle() -> tokio::runtime::Handle {
+  RT.read()
+    .ok()
+    .and_then(|rt| rt.as_ref().map(|rt| rt.handle().clone()))
+    .expect("Access tokio runtime failed in handle")
+}
+
+#[cfg(not(feature = "noop"))]
+/// Start the async runtime and keep it running until the returned guard is dropped.
+///
+/// It's useful in the Rust tests which drive the async code of an addon without a Node env,
+/// the runtime is shut down when the test is done.
+///
+/// ```
+/// let rt = napi::tokio_runtime::block_on_runtime();
+/// assert_eq!(rt.block_on(async { 1 + 1 }), 2);
+/// ```
+pub fn block_on_runtime() -> AsyncRuntimeGuard {
+  start_async_runtime();
+  AsyncRuntimeGuard {
+    _not_send: PhantomData,
+  }
+}
+
+#[cfg(not(feature = "noop"))]
+/// Created by `block_on_runtime`, shut down the async runtime when dropped.
+pub struct AsyncRuntimeGuard {
+  _not_send: PhantomData<*mut ()>,
+}
+
+#[cfg(not(feature = "noop"))]
+impl AsyncRuntimeGuard {
+  /// Same as `block_on`
+  pub fn block_on<F: Future>(&self, fut: F) -> F::Output {
+    block_on(fut)
+  }
+
+  /// Same as `handle`
+  pub fn handle(&self) -> tokio::runtime::Handle {
+    handle()
+  }
+}
+
+#[cfg(not(feature = "noop"))]
+impl Drop for AsyncRuntimeGuard {
+  fn drop(&mut self) {
+    shutdown_async_runtime();
+  }
+}
+
 #[cfg(not(feature = "noop"))]
 // This function's signature must be kept in sync with the one in lib.rs, otherwise napi
 // will fail to compile with the `tokio_rt` feature.
@@ -228,6 +298,9 @@ pub fn execute_tokio_future<
   let deferred_for_panic = deferred.clone();
   let sendable_resolver = SendableResolver::new(resolver);
 
+  let scope = EnvScope::get_or_create(&env)?;
+  let fut = ENV_SCOPE.scope(scope, fut);
+
   let inner = async move {
     match fut.await {
       Ok(v) => deferred.resolve(move |env| {
@@ -274,6 +347,176 @@ pub fn execute_tokio_future<
   Ok(promise.0.value)
 }
 
+#[cfg(not(feature = "noop"))]
+tokio::task_local! {
+  static ENV_SCOPE: EnvScope;
+}
+
+#[cfg(not(feature = "noop"))]
+thread_local! {
+  /// The `EnvScope` of each `Env` on the current thread
+  static ENV_SCOPES: std::cell::RefCell<std::collections::HashMap<sys::napi_env, EnvScope>> =
+    Default::default();
+}
+
+#[cfg(not(feature = "noop"))]
+#[derive(Clone, Copy)]
+/// The threadsafe function used by `env_scope` to run the closures on the JavaScript thread
+///
+/// It's created once for each `Env`, and it's unref'd, so it doesn't keep the event loop alive.
+/// The pending `JsDeferred` of the async function keeps the event loop alive instead.
+struct EnvScope(sys::napi_threadsafe_function);
+
+#[cfg(not(feature = "noop"))]
+unsafe impl Send for EnvScope {}
+#[cfg(not(feature = "noop"))]
+unsafe impl Sync for EnvScope {}
+
+#[cfg(not(feature = "noop"))]
+type EnvScopeCallback = Box<dyn FnOnce(Env) + Send>;
+
+#[cfg(not(feature = "noop"))]
+impl EnvScope {
+  fn get_or_create(env: &Env) -> Result<Self> {
+    if let Some(scope) = ENV_SCOPES.with(|scopes| scopes.borrow().get(&env.0).copied()) {
+      return Ok(scope);
+    }
+    let mut async_resource_name = std::ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_string_utf8(
+          env.0,
+          c"napi_rs_env_scope".as_ptr().cast(),
+          crate::bindgen_prelude::NAPI_AUTO_LENGTH,
+          &mut async_resource_name,
+        )
+      },
+      "Create async resource name in env_scope failed"
+    )?;
+    let mut tsfn = std::ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_threadsafe_function(
+          env.0,
+          std::ptr::null_mut(),
+          std::ptr::null_mut(),
+          async_resource_name,
+          0,
+          1,
+          std::ptr::null_mut(),
+          None,
+          std::ptr::null_mut(),
+          Some(call_env_scope_callback),
+          &mut tsfn,
+        )
+      },
+      "Create threadsafe function in env_scope failed"
+    )?;
+    check_status!(
+      unsafe { sys::napi_unref_threadsafe_function(env.0, tsfn) },
+      "Unref threadsafe function in env_scope failed"
+    )?;
+    let scope = EnvScope(tsfn);
+    ENV_SCOPES.with(|scopes| scopes.borrow_mut().insert(env.0, scope));
+    Ok(scope)
+  }
+}
+
+#[cfg(not(feature = "noop"))]
+/// Forget the `EnvScope` of the `env`, the threadsafe function is released by Node.js along with the `Env`
+pub(crate) fn reset_env_scope(env: sys::napi_env) {
+  let _ = ENV_SCOPES.try_with(|scopes| scopes.borrow_mut().remove(&env));
+}
+
+#[cfg(not(feature = "noop"))]
+unsafe extern "C" fn call_env_scope_callback(
+  env: sys::napi_env,
+  _js_callback: sys::napi_value,
+  _context: *mut std::ffi::c_void,
+  data: *mut std::ffi::c_void,
+) {
+  let callback = unsafe { Box::from_raw(data.cast::<EnvScopeCallback>()) };
+  // env can be null when shutting down, drop the callback so the `env_scope` future returns an error
+  if env.is_null() {
+    return;
+  }
+  callback(Env::from_raw(env));
+}
+
+#[cfg(not(feature = "noop"))]
+/// Clear the exception left pending by the `env_scope` closure, and use its message as the reason of the returned error
+///
+/// The JavaScript exception can't be sent to the future, only its message is kept.
+fn take_pending_exception(env: &Env, err: Error) -> Error {
+  let reason = env.get_and_clear_last_exception().and_then(|exception| {
+    exception
+      .map(|exception| exception.coerce_to_string()?.into_utf8()?.into_owned())
+      .transpose()
+  });
+  match reason {
+    Ok(Some(reason)) => Error::new(err.status, reason),
+    _ => err,
+  }
+}
+
+#[cfg(not(feature = "noop"))]
+/// Run the closure on the JavaScript thread with the `Env`, and get the result back in the async function.
+///
+/// It's only available in the futures of the `async fn` exported by `#[napi]` and `AsyncBlockBuilder`,
+/// not in the tasks spawned by them.
+///
+/// The closure is called on the JavaScript main thread, it must not block.
+/// The result is sent back to the future, so it can't hold the JavaScript values, create a `Reference` if you need to keep them.
+/// If the closure returns an error while a JavaScript exception is pending, the exception is cleared and its message is used as the reason of the error.
+///
+/// ```no_run
+/// use napi::bindgen_prelude::*;
+///
+/// #[napi_derive::napi]
+/// async fn read_global_name() -> Result<String> {
+///   tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+///   env_scope(|env| env.get_global()?.get_named_property::<String>("name")).await
+/// }
+/// ```
+pub async fn env_scope<F, R>(f: F) -> Result<R>
+where
+  F: 'static + Send + FnOnce(Env) -> Result<R>,
+  R: 'static + Send,
+{
+  let scope = ENV_SCOPE.try_with(|scope| *scope).map_err(|_| {
+    Error::new(
+      crate::Status::GenericFailure,
+      "`env_scope` must be called in the future of the async function",
+    )
+  })?;
+  let (sender, receiver) = tokio::sync::oneshot::channel();
+  let callback: EnvScopeCallback = Box::new(move |env| {
+    let result = f(env).map_err(|err| take_pending_exception(&env, err));
+    let _ = sender.send(result);
+  });
+  let data = Box::into_raw(Box::new(callback));
+  let status = unsafe {
+    sys::napi_call_threadsafe_function(
+      scope.0,
+      data.cast(),
+      sys::ThreadsafeFunctionCallMode::nonblocking,
+    )
+  };
+  if status != sys::Status::napi_ok {
+    drop(unsafe { Box::from_raw(data) });
+    return Err(Error::new(
+      crate::Status::from(status),
+      "Failed to schedule the closure onto the JavaScript thread",
+    ));
+  }
+  receiver.await.map_err(|_| {
+    Error::new(
+      crate::Status::Closing,
+      "The JavaScript thread is closed before running the closure",
+    )
+  })?
+}
+
 pub struct AsyncBlockBuilder<
   V: Send + 'static,
   F: Future<Output = Result<V>> + Send + 'static,
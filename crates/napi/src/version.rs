@@ -2,10 +2,12 @@ use crate::{sys, Error, Status};
 use std::ffi::CStr;
 
 #[derive(Debug, Clone, Copy)]
+/// Version of the running Node.js, returned from `Env::node_version`
 pub struct NodeVersion {
   pub major: u32,
   pub minor: u32,
   pub patch: u32,
+  /// `process.release.name`, for example `node`
   pub release: &'static str,
 }
 
@@ -29,3 +29,14 @@ test('should be able to re-create wrapped native value', (t) => {
   testClass.renewWrapped()
   t.is(testClass.addNativeCount(0), 42)
 })
+
+test('should wrap over-aligned native value', (t) => {
+  const objects = Array.from({ length: 16 }, (_, i) => {
+    const object = {}
+    bindings.wrapSimdLanes(object, i)
+    return object
+  })
+  objects.forEach((object, i) => {
+    t.is(bindings.sumSimdLanes(object), i * 8 + 28)
+  })
+})
@@ -0,0 +1,4 @@
+const bindings = require('../index.node')
+
+bindings.addCleanupHookWithData('Cleanup hook with data executed', false)
+bindings.addCleanupHookWithData('Removed cleanup hook executed', true)
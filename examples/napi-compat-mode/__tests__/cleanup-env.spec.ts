@@ -1,3 +1,6 @@
+import { execSync } from 'child_process'
+import { join } from 'path'
+
 import test from 'ava'
 
 const bindings = require('../index.node')
@@ -15,3 +18,10 @@ test('should be able to remove cleanup hook', (t) => {
     bindings.removeCleanupHook(ret)
   })
 })
+
+test('should call the cleanup hook with data unless it was removed', (t) => {
+  const output = execSync(
+    `node ${join(__dirname, 'cleanup-env-sub-process.js')}`,
+  ).toString()
+  t.is(output.trim(), 'Cleanup hook with data executed')
+})
@@ -12,3 +12,19 @@ test('dynamic argument length should work', (t) => {
   t.is(bindings.dynamicArgumentLength(1), 101)
   t.is(bindings.dynamicArgumentLength(), 42)
 })
+
+test('argument with default value', (t) => {
+  t.is(bindings.addWithDefault(), 11)
+  t.is(bindings.addWithDefault(5), 15)
+  t.is(bindings.addWithDefault(undefined, 2), 3)
+  t.is(bindings.addWithDefault(3, 4), 7)
+})
+
+test('rest arguments', (t) => {
+  t.deepEqual(bindings.multiplyRestArguments(2), [])
+  t.deepEqual(bindings.multiplyRestArguments(2, 1, 2, 3, 4), [2, 4, 6, 8])
+  t.throws(() => bindings.multiplyRestArguments(2, 1, 'x'), {
+    message:
+      'Failed to convert argument with index `2`: Failed to convert napi value String into rust type `u32`',
+  })
+})
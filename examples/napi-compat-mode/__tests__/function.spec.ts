@@ -43,6 +43,24 @@ test('should be able to create function from closure', (t) => {
   }
 })
 
+test('should be able to create function with data', (t) => {
+  const counter = bindings.testCreateFunctionWithData(10)
+  t.is(counter.name, 'counter')
+  t.is(counter(1), 11)
+  t.is(counter(5), 16)
+  const anotherCounter = bindings.testCreateFunctionWithData(0)
+  t.is(anotherCounter(2), 2)
+  t.is(counter(1), 17)
+})
+
+test('should not read the data not attached by create function with data', (t) => {
+  const counter = bindings.testCreateFunctionWithForeignData()
+  t.throws(() => counter(1), {
+    code: 'InvalidArg',
+    message: 'Invalid argument, nothing attach to the function',
+  })
+})
+
 test('should be able to create nest function from closure', (t) => {
   let callbackExecuted = false
 
@@ -7,3 +7,17 @@ test('should get napi version', (t) => {
   t.true(typeof napiVersion === 'number')
   t.is(`${napiVersion}`, process.versions.napi!)
 })
+
+test('should get napi version from runtime', (t) => {
+  t.is(`${bindings.getRuntimeNapiVersion()}`, process.versions.napi!)
+})
+
+test('should get node version', (t) => {
+  const [major, minor, patch] = process.versions.node.split('.').map(Number)
+  t.deepEqual(bindings.getNodeVersion(), {
+    major,
+    minor,
+    patch,
+    release: process.release.name,
+  })
+})
@@ -1,3 +1,6 @@
+import { join } from 'node:path'
+import { Worker } from 'node:worker_threads'
+
 import test from 'ava'
 
 import { napiVersion } from '../napi-version'
@@ -22,3 +25,28 @@ test('should throw if get instance data type mismatched', (t) => {
     t.is(bindings.getWrongTypeInstanceData, undefined)
   }
 })
+
+test('instance data is isolated between worker threads', async (t) => {
+  if (napiVersion < 6 || process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  bindings.setInstanceCount(1)
+  const worker = new Worker(
+    `
+    const { parentPort, workerData } = require('node:worker_threads')
+    const bindings = require(workerData)
+    const before = bindings.getInstanceCount()
+    bindings.setInstanceCount(2)
+    parentPort.postMessage({ before, after: bindings.getInstanceCount() })
+    `,
+    { eval: true, workerData: join(__dirname, '..', '..', 'index.node') },
+  )
+  const data = await new Promise((resolve, reject) => {
+    worker.once('message', resolve)
+    worker.once('error', reject)
+  })
+  t.deepEqual(data, { before: undefined, after: 2 })
+  t.is(bindings.getInstanceCount(), 1)
+  await worker.terminate()
+})
@@ -2,13 +2,17 @@ use std::convert::TryInto;
 
 use napi::{
   bindgen_prelude::{Function, Unknown},
-  CallContext, JsNumber, JsObject, Property, Result,
+  CallContext, Error, JsNumber, JsObject, Property, Result, Status,
 };
 
 struct NativeClass {
   value: i32,
 }
 
+/// Same alignment as an AVX register
+#[repr(align(32))]
+struct SimdLanes([f32; 8]);
+
 #[js_function(1)]
 fn create_test_class(ctx: CallContext) -> Result<Function<Unknown, Unknown>> {
   let add_count_method = Property::new()
@@ -64,7 +68,30 @@ fn renew_wrapped(ctx: CallContext) -> Result<()> {
   Ok(())
 }
 
+#[js_function(2)]
+fn wrap_simd_lanes(ctx: CallContext) -> Result<()> {
+  let mut object = ctx.get::<JsObject>(0)?;
+  let start: f64 = ctx.get::<JsNumber>(1)?.try_into()?;
+  let lanes = SimdLanes(std::array::from_fn(|i| start as f32 + i as f32));
+  ctx.env.wrap(&mut object, lanes, None)
+}
+
+#[js_function(1)]
+fn sum_simd_lanes(ctx: CallContext) -> Result<JsNumber> {
+  let object = ctx.get::<JsObject>(0)?;
+  let lanes: &mut SimdLanes = ctx.env.unwrap(&object)?;
+  if (lanes as *const SimdLanes as usize) % std::mem::align_of::<SimdLanes>() != 0 {
+    return Err(Error::new(
+      Status::GenericFailure,
+      "Wrapped SimdLanes is not aligned".to_owned(),
+    ));
+  }
+  ctx.env.create_double(lanes.0.iter().sum::<f32>() as f64)
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("createTestClass", create_test_class)?;
+  exports.create_named_method("wrapSimdLanes", wrap_simd_lanes)?;
+  exports.create_named_method("sumSimdLanes", sum_simd_lanes)?;
   Ok(())
 }
@@ -17,8 +17,22 @@ fn remove_cleanup_hook(ctx: CallContext) -> Result<()> {
   Ok(())
 }
 
+#[js_function(2)]
+fn add_cleanup_hook_with_data(ctx: CallContext) -> Result<()> {
+  let message = ctx.get::<String>(0)?;
+  let remove = ctx.get::<bool>(1)?;
+  let hook = ctx.env.add_cleanup_hook(message, |message| {
+    println!("{message}");
+  })?;
+  if remove {
+    hook.remove()?;
+  }
+  Ok(())
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("addCleanupHook", add_cleanup_hook)?;
   exports.create_named_method("removeCleanupHook", remove_cleanup_hook)?;
+  exports.create_named_method("addCleanupHookWithData", add_cleanup_hook_with_data)?;
   Ok(())
 }
@@ -28,8 +28,29 @@ pub fn dynamic_argument_length(ctx: CallContext) -> Result<JsNumber> {
   }
 }
 
+#[js_function(2)]
+pub fn add_with_default(ctx: CallContext) -> Result<JsNumber> {
+  let a = ctx.arg_or::<u32>(0, 1)?;
+  let b = ctx.arg_or::<u32>(1, 10)?;
+  ctx.env.create_uint32(a + b)
+}
+
+#[js_function(1)]
+pub fn multiply_rest_arguments(ctx: CallContext) -> Result<Vec<u32>> {
+  let multiplier = ctx.get::<u32>(0)?;
+  Ok(
+    ctx
+      .rest_args::<u32>(1)?
+      .into_iter()
+      .map(|n| n * multiplier)
+      .collect(),
+  )
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("eitherNumberString", either_number_string)?;
   exports.create_named_method("dynamicArgumentLength", dynamic_argument_length)?;
+  exports.create_named_method("addWithDefault", add_with_default)?;
+  exports.create_named_method("multiplyRestArguments", multiply_rest_arguments)?;
   Ok(())
 }
@@ -1,6 +1,9 @@
+use std::cell::Cell;
+use std::ptr;
+
 use napi::{
   bindgen_prelude::{FnArgs, Function, Null},
-  CallContext, JsError, JsObject, JsString, JsValue, Result, Unknown,
+  check_status, sys, CallContext, JsError, JsObject, JsString, JsValue, Result, Unknown,
 };
 
 #[js_function(1)]
@@ -82,6 +85,41 @@ pub fn test_nest_create_function_from_closure(ctx: CallContext) -> Result<Functi
     })
 }
 
+#[js_function(1)]
+fn counter(ctx: CallContext) -> Result<u32> {
+  let step = ctx.get::<u32>(0)?;
+  let count = ctx.function_data::<Cell<u32>>()?;
+  count.set(count.get() + step);
+  Ok(count.get())
+}
+
+#[js_function(1)]
+pub fn test_create_function_with_data(ctx: CallContext) -> Result<Function<u32, u32>> {
+  let initial = ctx.get::<u32>(0)?;
+  ctx
+    .env
+    .create_function_with_data("counter", counter, Cell::new(initial))
+}
+
+static FOREIGN_DATA: u8 = 0;
+
+#[js_function]
+pub fn test_create_function_with_foreign_data(ctx: CallContext) -> Result<Unknown> {
+  // the data of the function is not created by `create_function_with_data`
+  let mut function = ptr::null_mut();
+  check_status!(unsafe {
+    sys::napi_create_function(
+      ctx.env.raw(),
+      c"counter".as_ptr(),
+      7,
+      Some(counter),
+      ptr::from_ref(&FOREIGN_DATA).cast_mut().cast(),
+      &mut function,
+    )
+  })?;
+  Ok(unsafe { Unknown::from_raw_unchecked(ctx.env.raw(), function) })
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testCallFunction", call_function)?;
   exports.create_named_method(
@@ -98,5 +136,10 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
     "testNestCreateFunctionFromClosure",
     test_nest_create_function_from_closure,
   )?;
+  exports.create_named_method("testCreateFunctionWithData", test_create_function_with_data)?;
+  exports.create_named_method(
+    "testCreateFunctionWithForeignData",
+    test_create_function_with_foreign_data,
+  )?;
   Ok(())
 }
@@ -40,11 +40,13 @@ mod serde;
 mod string;
 mod symbol;
 
-use napi_version::get_napi_version;
+use napi_version::{get_napi_version, get_node_version, get_runtime_napi_version};
 
 #[module_exports]
 fn init(mut exports: JsObject, env: Env) -> Result<()> {
   exports.create_named_method("getNapiVersion", get_napi_version)?;
+  exports.create_named_method("getRuntimeNapiVersion", get_runtime_napi_version)?;
+  exports.create_named_method("getNodeVersion", get_node_version)?;
   array::register_js(&mut exports)?;
   error::register_js(&mut exports)?;
   string::register_js(&mut exports)?;
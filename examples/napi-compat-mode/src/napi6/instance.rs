@@ -10,6 +10,21 @@ pub fn set_instance_data(env: Env) -> ContextlessResult<()> {
   Ok(Some(()))
 }
 
+#[js_function(1)]
+pub fn set_instance_count(ctx: CallContext) -> Result<()> {
+  let count = ctx.get::<i64>(0)?;
+  ctx.env.set_typed_instance_data(NativeObject { count })
+}
+
+#[contextless_function]
+pub fn get_instance_count(env: Env) -> ContextlessResult<i64> {
+  Ok(
+    env
+      .get_typed_instance_data::<NativeObject>()?
+      .map(|obj| obj.count),
+  )
+}
+
 #[contextless_function]
 pub fn get_instance_data(env: Env) -> ContextlessResult<i64> {
   if let Some(obj) = env.get_instance_data::<NativeObject>()? {
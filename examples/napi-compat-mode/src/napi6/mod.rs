@@ -33,6 +33,8 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testGetBigintWords", test_get_bigint_words)?;
 
   exports.create_named_method("setInstanceData", set_instance_data)?;
+  exports.create_named_method("setInstanceCount", set_instance_count)?;
+  exports.create_named_method("getInstanceCount", get_instance_count)?;
   exports.create_named_method("getInstanceData", get_instance_data)?;
   exports.create_named_method("getWrongTypeInstanceData", get_wrong_type_instance_data)?;
   Ok(())
@@ -1,6 +1,22 @@
-use napi::{CallContext, JsNumber, Result};
+use napi::{CallContext, JsNumber, JsObject, Result};
 
 #[js_function]
 pub fn get_napi_version(ctx: CallContext) -> Result<JsNumber> {
   ctx.env.create_uint32(ctx.env.get_napi_version()?)
 }
+
+#[js_function]
+pub fn get_runtime_napi_version(ctx: CallContext) -> Result<JsNumber> {
+  ctx.env.create_uint32(ctx.env.napi_version()?)
+}
+
+#[js_function]
+pub fn get_node_version(ctx: CallContext) -> Result<JsObject> {
+  let version = ctx.env.node_version()?;
+  let mut obj = ctx.env.create_object()?;
+  obj.set_named_property("major", version.major)?;
+  obj.set_named_property("minor", version.minor)?;
+  obj.set_named_property("patch", version.patch)?;
+  obj.set_named_property("release", version.release)?;
+  Ok(obj)
+}
@@ -0,0 +1,45 @@
+const {
+  FailedFinalize,
+  collectFinalizeErrors,
+  collectNextFinalizeError,
+  takeFinalizeErrors,
+  liveFailedFinalizeCount,
+} = require('../index.cjs')
+
+// the errors are still thrown into the env after the handler is called
+process.on('uncaughtException', () => {})
+
+collectFinalizeErrors()
+
+for (let i = 0; i < 3; i++) {
+  new FailedFinalize(`resource ${i}`)
+}
+
+;(async () => {
+  for (let i = 0; i < 10; i++) {
+    global.gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  const errors = takeFinalizeErrors().sort()
+  // the references are removed even if the finalize failed
+  const liveCount = liveFailedFinalizeCount()
+
+  collectNextFinalizeError()
+  for (let i = 3; i < 6; i++) {
+    new FailedFinalize(`resource ${i}`)
+  }
+  for (let i = 0; i < 10; i++) {
+    global.gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  // the addon may print to stdout as well, mark the line of the result
+  console.info(
+    'FINALIZE_ERROR_RESULT',
+    JSON.stringify({
+      errors,
+      liveCount,
+      // the handler replaced itself after the first error
+      nextErrorsCount: takeFinalizeErrors().length,
+    }),
+  )
+})()
@@ -1,6 +1,9 @@
+import { setFlagsFromString } from 'node:v8'
+import { runInNewContext } from 'node:vm'
+
 import test from 'ava'
 
-import { Fib, Fib2, Fib3, shutdownRuntime } from '../index.cjs'
+import { Fib, Fib2, Fib3, parseNumbers, shutdownRuntime } from '../index.cjs'
 
 test.after(() => {
   shutdownRuntime()
@@ -59,3 +62,56 @@ for (const [index, factory] of [
     })
   })
 }
+
+test('should be able to iterate a Rust iterator', (t) => {
+  const iterator = parseNumbers('1, 2,3')
+  t.deepEqual(iterator.next(), {
+    done: false,
+    value: 1,
+  })
+  t.deepEqual([...iterator], [2, 3])
+  t.deepEqual(iterator.next(), {
+    done: true,
+  })
+})
+
+test('should throw if next is detached from the Rust iterator', async (t) => {
+  let iterator: ReturnType<typeof parseNumbers> | null = parseNumbers('1,2,3')
+  const next = iterator.next
+  t.deepEqual(next.call(iterator), {
+    done: false,
+    value: 1,
+  })
+  t.throws(() => next.call(parseNumbers('4')), {
+    instanceOf: TypeError,
+    message:
+      'The iterator method is called on an object which is not the iterator',
+  })
+  if (process.env.WASI_TEST) {
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  iterator = null
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  // the state is freed along with the iterator
+  t.throws(() => next(), { instanceOf: TypeError })
+})
+
+test('should throw the error from a Rust iterator', (t) => {
+  const iterator = parseNumbers('1,x,3')
+  t.deepEqual(iterator.next(), {
+    done: false,
+    value: 1,
+  })
+  t.throws(() => iterator.next(), {
+    code: 'InvalidArg',
+    message: 'Failed to parse `x`: invalid digit found in string',
+  })
+  t.deepEqual(iterator.next(), {
+    done: true,
+  })
+})
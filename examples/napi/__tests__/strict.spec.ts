@@ -52,6 +52,12 @@ test('should validate arraybuffer', (t) => {
     message: 'Expected a TypedArray value',
   })
 
+  // @ts-expect-error
+  t.throws(() => validateTypedArray(new Float32Array([1, 2, 3])), {
+    code: 'InvalidArg',
+    message: 'Expected Uint8Array, got Float32Array',
+  })
+
   t.is(validateTypedArraySlice(new Uint8Array([1, 2, 3])), 3)
 
   // @ts-expect-error
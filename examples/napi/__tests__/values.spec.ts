@@ -5,17 +5,32 @@ import { fileURLToPath } from 'node:url'
 import { createReadStream } from 'node:fs'
 import { readFile as nodeReadFile } from 'node:fs/promises'
 import { Readable } from 'node:stream'
+import { inspect } from 'node:util'
+import {
+  deserialize as v8Deserialize,
+  serialize as v8Serialize,
+  setFlagsFromString,
+} from 'node:v8'
+import { runInNewContext } from 'node:vm'
+import { Worker } from 'node:worker_threads'
 
 import { Subject, take } from 'rxjs'
 import Sinon, { spy } from 'sinon'
 
 import {
   DEFAULT_COST,
+  LAZY_COST,
+  lazyAdd,
   add,
   fibonacci,
   call0,
   call1,
   call2,
+  callWithSpreadArgs,
+  sumRestArgs,
+  joinRestArgs,
+  applyWithSpreadArgs,
+  callWithFastPaths,
   apply0,
   apply1,
   callFunction,
@@ -27,13 +42,24 @@ import {
   concatLatin1,
   concatStr,
   concatUtf16,
+  utf16CodeUnits,
+  countLoneSurrogates,
+  decodeLatin1,
+  encodeLatin1,
+  returnLatin1Bytes,
+  returnUtf8Bytes,
   roundtripStr,
   getNums,
   getWords,
   sumNums,
+  sumArrayToVec,
+  createArrayOfSquares,
+  squareEvenNumbers,
   getTuple,
+  roundTripTuple,
   getMapping,
   sumMapping,
+  sumObjectValues,
   getBtreeMapping,
   sumBtreeMapping,
   getIndexMapping,
@@ -42,6 +68,16 @@ import {
   passSetToJs,
   passSetToRust,
   btreeSetToJs,
+  strictEqualsValues,
+  looseEqualsValues,
+  dedupStrictEqual,
+  countWords,
+  sumMapValues,
+  takeMapValue,
+  describeMapEntries,
+  createUniqueSet,
+  toggleSetValue,
+  getSetValues,
   btreeSetToRust,
   getCwd,
   Animal,
@@ -53,30 +89,57 @@ import {
   GetterSetterWithClosures,
   enumToI32,
   listObjKeys,
+  listObjPropertyNames,
+  typeTagObject,
+  isTypeTaggedObject,
+  createFrozenConfig,
+  createSealedConfig,
+  mergeObjects,
   createObj,
   mapOption,
   readFile,
   throwError,
   jsErrorCallback,
   customStatusCode,
+  parseWithAnyhow,
   panic,
+  panicWithFormattedMessage,
+  throwErrorWithCause,
+  createErrorWithCode,
+  parseConfig,
   readPackageJson,
   PackageJsonReader,
   getPackageJsonName,
   getBuffer,
   getEmptyBuffer,
+  getPooledBuffers,
+  getZeroCopyBuffer,
+  getBufferFromPool,
+  getBufferPoolCount,
+  getBufferSliceWithSpareCapacity,
   getEmptyTypedArray,
   asyncBufferToArray,
   readFileAsync,
   eitherStringOrNumber,
   returnEither,
   either3,
+  describeUnknown,
+  either3Variant,
   either4,
   eitherPromiseInEitherA,
   eitherF64OrU32,
   withoutAbortController,
   withAbortController,
+  countUntilAborted,
+  getAbortableSteps,
+  hashBuffer,
+  countWithProgress,
+  countWithAnySignal,
   asyncMultiTwo,
+  spawnOnRuntimeHandle,
+  sumAllPromises,
+  stringifyInEnvScope,
+  throwInEnvScope,
   bigintAdd,
   createBigInt,
   createBigIntI64,
@@ -87,8 +150,11 @@ import {
   threadsafeFunctionBuildThrowErrorWithStatus,
   threadsafeFunctionClosureCapture,
   tsfnCallWithCallback,
+  releaseTsfnInCallback,
   tsfnAsyncCall,
+  tsfnCallWithTimeout,
   tsfnThrowFromJs,
+  tsfnAsyncCallFatalMode,
   asyncPlus100,
   getGlobal,
   getUndefined,
@@ -96,18 +162,30 @@ import {
   setSymbolInObj,
   createSymbol,
   createSymbolFor,
+  createSymbolForKey,
+  getSymbolDescription,
   threadsafeFunctionFatalMode,
   createExternal,
   getExternal,
   mutateExternal,
   createExternalString,
+  takeExternalString,
   xxh2,
   xxh3,
   xxh64Alias,
   tsRename,
+  overrideUnknownReturnType,
   acceptArraybuffer,
   acceptSlice,
   u8ArrayToArray,
+  createSharedArrayBuffer,
+  detachArraybuffer,
+  sumArraybufferAfter,
+  fillArraybufferAfter,
+  sumSharedArrayBuffer,
+  parsePacketHeader,
+  createPacketHeader,
+  getDataviewByteOffset,
   i8ArrayToArray,
   u16ArrayToArray,
   i16ArrayToArray,
@@ -115,6 +193,8 @@ import {
   i32ArrayToArray,
   u64ArrayToArray,
   i64ArrayToArray,
+  bigint64ArrayRoundTrip,
+  biguint64ArrayRoundTrip,
   f32ArrayToArray,
   f64ArrayToArray,
   acceptUint8ClampedSlice,
@@ -122,6 +202,7 @@ import {
   convertU32Array,
   createExternalTypedArray,
   mutateTypedArray,
+  mutateFloat64Array,
   receiveAllOptionalObject,
   objectGetNamedPropertyShouldPerformTypecheck,
   fnReceivedAliased,
@@ -140,8 +221,18 @@ import {
   testSerdeRoundtrip,
   testSerdeBigNumberPrecision,
   testSerdeBufferBytes,
+  serializeLabeledObject,
+  serializeLabeledObjectWithSortedKeys,
   getBigintJsonValue,
   createObjWithProperty,
+  getPropertyDescriptor,
+  objectHasOwnProperty,
+  objectHasNamedProperty,
+  installCountingAllocator,
+  getNativeAllocations,
+  wrapNativeLabel,
+  getNativeLabel,
+  dropNativeLabel,
   receiveObjectOnlyFromJs,
   dateToNumber,
   chronoUtcDateToMillis,
@@ -150,11 +241,29 @@ import {
   chronoDateFixtureReturn1,
   chronoDateFixtureReturn2,
   derefUint8Array,
+  matrix4Trace,
+  rgbaToHex,
+  uint8ArraySubarray,
+  fillFloat32ArrayRange,
+  subarrayOfRustData,
   chronoDateAdd1Minute,
+  chronoUtcDateRoundTrip,
+  chronoUtcDateWithSubMillis,
   bufferPassThrough,
   arrayBufferPassThrough,
   JsRepo,
+  upgradeRepoDir,
+  upgradeRepo,
+  Graph,
+  PersistentValue,
+  LeakProbe,
+  liveClassInstanceCount,
+  liveLeakProbeCount,
+  dropRefAfterUnref,
   JsRemote,
+  bindRepoDir,
+  getBranchRef,
+  getDroppedBranches,
   CssStyleSheet,
   CatchOnConstructor,
   CatchOnConstructor2,
@@ -167,22 +276,45 @@ import {
   overrideIndividualArgOnFunction,
   overrideIndividualArgOnFunctionWithCbArg,
   createObjectWithClassField,
+  downcastBirdName,
+  compareAnimals,
+  compareAnimalHandles,
+  classInstanceEnvName,
+  getAnimalNameLengthFromForeign,
+  createObjectWithGetterClosure,
+  getDroppedPropertyClosures,
   receiveObjectWithClassField,
   AnotherClassForEither,
   receiveDifferentClass,
   getNumArr,
   getNestedNumArr,
   CustomFinalize,
+  InspectablePoint,
+  AsyncFinalize,
   plusOne,
+  assignAnimalToSymbol,
   Width,
   captureErrorInCallback,
   bigintFromI128,
+  bigintI128RoundTrip,
+  bigintU128RoundTrip,
   bigintFromI64,
   acceptThreadsafeFunction,
   acceptThreadsafeFunctionFatal,
   acceptThreadsafeFunctionTupleArgs,
+  coalesceProgress,
   promiseInEither,
   runScript,
+  runScriptWithFilename,
+  structuredCloneValue,
+  toTransferableValue,
+  transferValueThroughThread,
+  jsonStringifyValue,
+  getGlobalObject,
+  getProcessEnvVar,
+  jsonParseValue,
+  onObjectCollected,
+  adjustExternalMemory,
   tsfnReturnPromise,
   tsfnReturnPromiseTimeout,
   returnFromSharedCrate,
@@ -191,7 +323,10 @@ import {
   throwAsyncError,
   getModuleFileName,
   throwSyntaxError,
+  throwTypeError,
+  throwRangeError,
   type AliasedStruct,
+  type ExternalObject,
   returnObjectOnlyToJs,
   buildThreadsafeFunctionFromFunction,
   createOptionalExternal,
@@ -199,11 +334,23 @@ import {
   mutateOptionalExternal,
   panicInAsync,
   CustomStruct,
+  validateUsername,
+  callAndRecoverExceptions,
   ClassWithLifetime,
+  ImmutableBytes,
+  VisitCounter,
+  resetVisitCounter,
+  findVisitCounter,
+  Chart,
+  SharedCounter,
+  tryFindVisitCounter,
+  getDroppedVisitCounters,
   uInit8ArrayFromString,
   callThenOnPromise,
   callCatchOnPromise,
   callFinallyOnPromise,
+  resolveFromThread,
+  dropResolverFromThread,
   StructuredKind,
   validateStructuredEnum,
   createArraybuffer,
@@ -214,6 +361,7 @@ import {
   withinAsyncRuntimeIfAvailable,
   errorMessageContainsNullByte,
   returnCString,
+  returnStrContainsNullByte,
   receiveBufferSliceWithLifetime,
   generateFunctionAndCallIt,
   getMyVec,
@@ -227,6 +375,8 @@ import {
   acceptStream,
   createReadableStream,
   createReadableStreamFromClass,
+  countUpStream,
+  PullCounter,
   spawnThreadInThread,
   esmResolve,
   mergeTupleArray,
@@ -238,6 +388,8 @@ import {
   callAsyncWithUnknownReturnValue,
   shorterScope,
   shorterEscapableScope,
+  sumInEscapableScope,
+  escapeTwice,
   tsfnThrowFromJsCallbackContainsTsfn,
 } from '../index.cjs'
 // import other stuff in `#[napi(module_exports)]`
@@ -257,6 +409,19 @@ test('export const', (t) => {
   t.is(DEFAULT_COST, 12)
 })
 
+test('lazy export', (t) => {
+  t.is(LAZY_COST, 24)
+  t.is(lazyAdd(1, 2), 3)
+  // the getter is replaced with the value after the first access
+  for (const name of ['LAZY_COST', 'lazyAdd']) {
+    const descriptor = Object.getOwnPropertyDescriptor(nativeAddon, name)!
+    t.is(descriptor.get, undefined)
+    t.true(descriptor.writable)
+    t.true(descriptor.enumerable)
+  }
+  t.is((nativeAddon as any).lazyAdd, lazyAdd)
+})
+
 test('number', (t) => {
   t.is(add(1, 2), 3)
   t.is(fibonacci(5), 5)
@@ -283,15 +448,75 @@ test('string', (t) => {
     roundtripStr('what up?!\u0000after the NULL'),
     'what up?!\u0000after the NULL',
   )
+  t.is(returnStrContainsNullByte(), 'Hello\u0000from &str')
   t.is(returnCString(), 'Hello from C string!')
 })
 
+test('convert the bytes into JavaScript string with the chosen encoding', (t) => {
+  const bytes = Buffer.from(Array.from({ length: 256 }, (_, i) => i))
+  const decoded = decodeLatin1(bytes)
+  t.is(decoded.length, 256)
+  t.is(decoded, bytes.toString('latin1'))
+  t.is(decoded.charCodeAt(0xff), 0xff)
+  t.deepEqual(encodeLatin1(decoded), bytes)
+  t.is(returnLatin1Bytes(), 'Café © µ ÿ')
+  t.is(returnUtf8Bytes(false), 'Rust 🦀 é')
+  t.is(returnUtf8Bytes(true), 'Rust 🦀 \uFFFD')
+})
+
+test('read string as utf16 code units', (t) => {
+  const family = '👨‍👩‍👧'
+  const combining = 'e\u0301 n\u0303'
+  for (const s of [family, combining, `${family} ${combining} 🦀`, '']) {
+    t.deepEqual(
+      utf16CodeUnits(s),
+      Array.from({ length: s.length }, (_, i) => s.charCodeAt(i)),
+    )
+  }
+  t.deepEqual(utf16CodeUnits('a\uD800b\uDFFF'), [0x61, 0xd800, 0x62, 0xdfff])
+  t.is(countLoneSurrogates([family, combining, '', '\uD83D']), 1)
+  t.is(countLoneSurrogates(['x\uDC00y\uD83D', '🦀\uD83D', '\uDE00🦀']), 4)
+})
+
 test('array', (t) => {
   t.deepEqual(getNums(), [1, 1, 2, 3, 5, 8])
   t.deepEqual(getWords(), ['foo', 'bar'])
   t.deepEqual(getTuple([1, 'test', 2]), 3)
+  t.deepEqual(roundTripTuple([1.5, 'test', true]), [true, 'test', 1.5])
+  t.throws(() => roundTripTuple([1.5, 'test'] as any), {
+    code: 'InvalidArg',
+    message: 'Expected an Array with length 3 for the tuple, got 2',
+  })
+  t.throws(() => roundTripTuple([1.5, 'test', true, 'extra'] as any), {
+    code: 'InvalidArg',
+    message: 'Expected an Array with length 3 for the tuple, got 4',
+  })
 
   t.is(sumNums([1, 2, 3, 4, 5]), 15)
+  t.deepEqual(createArrayOfSquares(5), [0, 1, 4, 9, 16])
+  t.deepEqual(createArrayOfSquares(0), [])
+  t.is(sumArrayToVec([1, 2, 3, 4, 5]), 15)
+  t.is(sumArrayToVec([]), 0)
+  t.throws(() => sumArrayToVec([1, 2, 'three' as any]), {
+    code: 'NumberExpected',
+    message: /^Failed to convert element with index `2`/,
+  })
+  // eslint-disable-next-line no-sparse-arrays
+  t.throws(() => sumArrayToVec([1, , 3]), {
+    code: 'InvalidArg',
+    message: 'Found a hole at index `1` of the Array',
+  })
+  t.deepEqual(squareEvenNumbers([1, 2, 3, 4, 6]), [4, 16, 36])
+  t.deepEqual(squareEvenNumbers([1, 3]), [])
+  t.throws(() => squareEvenNumbers([2, 'four' as any]), {
+    code: 'NumberExpected',
+    message: /^Failed to map element with index `1`/,
+  })
+  t.throws(() => squareEvenNumbers([2, 3, 70000]), {
+    code: 'InvalidArg',
+    message:
+      'Failed to map element with index `2`: The square of 70000 overflows u32',
+  })
   t.deepEqual(getNumArr(), [1, 2])
   t.deepEqual(getNestedNumArr(), [[[1]], [[1]]])
 })
@@ -300,6 +525,11 @@ test('map', (t) => {
   t.deepEqual(getMapping(), { a: 101, b: 102, '\0c': 103 })
   t.deepEqual(getMappingWithHasher(), { a: 101, b: 102 })
   t.is(sumMapping({ a: 101, b: 102, '\0c': 103 }), 306)
+  t.is(sumObjectValues({ a: 101, b: 102, c: undefined }), 203)
+  t.throws(() => sumObjectValues({ a: 101, b: 'not a number' }), {
+    code: 'NumberExpected',
+    message: /^Failed to convert value of key `b`/,
+  })
   t.deepEqual(getBtreeMapping(), { a: 101, b: 102, '\0c': 103 })
   t.is(sumBtreeMapping({ a: 101, b: 102, '\0c': 103 }), 306)
   t.deepEqual(getIndexMapping(), { a: 101, b: 102, '\0c': 103 })
@@ -322,6 +552,87 @@ test('set', (t) => {
   t.deepEqual(Array.from(btreeSetToJs()).sort(), ['a', 'b', 'c'])
 })
 
+test('JsMap', (t) => {
+  const counts = countWords(['a', 'b', 'a', 'c', 'a'])
+  t.true(counts instanceof Map)
+  t.deepEqual(Array.from(counts), [
+    ['a', 3],
+    ['b', 1],
+    ['c', 1],
+  ])
+  t.is(sumMapValues(counts), 5)
+  t.deepEqual(describeMapEntries(counts), ['a=3', 'b=1', 'c=1'])
+
+  const key = {}
+  const map = new Map<unknown, unknown>([
+    [key, 'object key'],
+    [1, 'number key'],
+  ])
+  t.is(takeMapValue(map, key), 'object key')
+  t.false(map.has(key))
+  t.is(takeMapValue(map, key), null)
+  // keys are compared with SameValueZero like in JavaScript
+  t.is(takeMapValue(map, '1'), null)
+  t.is(map.size, 1)
+
+  t.throws(() => sumMapValues({} as Map<any, any>), {
+    message: 'Expected a Map value',
+  })
+})
+
+test('JsSet', (t) => {
+  const set = createUniqueSet([3, 1, 3, 2])
+  t.true(set instanceof Set)
+  t.deepEqual(getSetValues(set), [3, 1, 2])
+  t.false(toggleSetValue(set, 1))
+  t.true(toggleSetValue(set, 1))
+  t.deepEqual(getSetValues(set), [3, 2, 1])
+
+  const value = {}
+  const objects = new Set([value])
+  t.false(toggleSetValue(objects, value))
+  t.true(toggleSetValue(objects, {}))
+  t.is(objects.size, 1)
+
+  t.throws(() => getSetValues([1, 2] as unknown as Set<any>), {
+    message: 'Expected a Set value',
+  })
+})
+
+test('strict and loose equality of unknown values', (t) => {
+  t.false(strictEqualsValues(NaN, NaN))
+  t.false(looseEqualsValues(NaN, NaN))
+  t.true(strictEqualsValues(0, -0))
+  t.true(looseEqualsValues(0, -0))
+  t.false(strictEqualsValues('1', 1))
+  t.true(looseEqualsValues('1', 1))
+  t.false(strictEqualsValues(null, undefined))
+  t.true(looseEqualsValues(null, undefined))
+  const value = {}
+  t.true(strictEqualsValues(value, value))
+  t.false(looseEqualsValues({}, {}))
+  t.deepEqual(dedupStrictEqual([1, '1', value, value, NaN, NaN, 0, -0]), [
+    1,
+    '1',
+    value,
+    NaN,
+    NaN,
+    0,
+  ])
+  t.throws(
+    () =>
+      looseEqualsValues(
+        {
+          valueOf() {
+            throw new Error('valueOf failed')
+          },
+        },
+        1,
+      ),
+    { message: 'valueOf failed' },
+  )
+})
+
 test('enum', (t) => {
   t.deepEqual([Kind.Dog, Kind.Cat, Kind.Duck], [0, 1, 2])
   t.is(enumToI32(CustomNumEnum.Eight), 8)
@@ -353,6 +664,34 @@ test('structured enum', (t) => {
   t.throws(() => validateStructuredEnum({ type2: 'Greeting' } as any))
 })
 
+test('function call with spread args', (t) => {
+  const sum = (...args: number[]) => args.reduce((acc, cur) => acc + cur, 0)
+  t.is(callWithSpreadArgs(sum, []), 0)
+  t.is(callWithSpreadArgs(sum, [42]), 42)
+  t.is(
+    callWithSpreadArgs(sum, Array.from({ length: 100 }, (_, i) => i)),
+    4950,
+  )
+  t.is(
+    applyWithSpreadArgs(
+      function (this: { prefix: string }, ...args: string[]) {
+        return this.prefix + args.join(',')
+      },
+      { prefix: '>' },
+      ['a', 'b', 'c'],
+    ),
+    '>a,b,c',
+  )
+  t.deepEqual(callWithFastPaths((...args) => args.length), [0, 1, 2])
+  t.throws(
+    () =>
+      callWithSpreadArgs(() => {
+        throw new Error('spread')
+      }, [1]),
+    { message: 'spread' },
+  )
+})
+
 test('function call', async (t) => {
   t.is(
     call0((...args) => {
@@ -410,6 +749,38 @@ test('function call', async (t) => {
   )
 })
 
+test('rest arguments of the native function', (t) => {
+  const values = Array.from({ length: 300 }, (_, i) => i)
+  t.is(sumRestArgs(), 0)
+  t.is(sumRestArgs(1, 2), 3)
+  t.is(sumRestArgs(...values), 44850)
+  t.is(joinRestArgs('-'), '')
+  t.is(joinRestArgs('-', 'a', 'b'), 'a-b')
+  t.is(joinRestArgs(',', ...values.map(String)), values.join(','))
+  t.throws(() => sumRestArgs(1, 'x' as any), {
+    message: 'Failed to convert napi value String into rust type `u32`',
+  })
+})
+
+test('class constructor called without new', (t) => {
+  t.throws(() => (Animal as any)(Kind.Dog, '旺财'), {
+    instanceOf: TypeError,
+    message: "Class constructor Animal cannot be invoked without 'new'",
+  })
+  // the default constructor of `#[napi(constructor)]` struct
+  t.throws(() => (Dog as any)('Doge'), {
+    instanceOf: TypeError,
+    message: "Class constructor Dog cannot be invoked without 'new'",
+  })
+  // the JavaScript name of the class is used
+  t.throws(() => (Assets as any)(), {
+    instanceOf: TypeError,
+    message: "Class constructor Assets cannot be invoked without 'new'",
+  })
+  t.is(new Dog('Doge').name, 'Doge')
+  t.is(NinjaTurtle.newRaph().name, 'Raphael')
+})
+
 test('class', (t) => {
   const dog = new Animal(Kind.Dog, '旺财')
 
@@ -441,6 +812,11 @@ test('class', (t) => {
   const turtle = NinjaTurtle.newRaph()
   t.is(turtle.returnThis(), turtle)
   t.is(NinjaTurtle.isInstanceOf(turtle), true)
+  t.is(NinjaTurtle.getNameOf(turtle), 'Raphael')
+  t.throws(() => NinjaTurtle.getNameOf(new Assets()), {
+    code: 'InvalidArg',
+    message: /Value is not instanceof class/,
+  })
   // Inject this to function
   const width = new Width(1)
   t.is(plusOne.call(width), 2)
@@ -453,9 +829,24 @@ test('class', (t) => {
     new CatchOnConstructor()
   })
 
+  const animalKey = Symbol('animal')
+  const animalHolder: { [animalKey]?: Animal } = {}
+  assignAnimalToSymbol.call(animalHolder, animalKey, 'Tom')
+  const tom = animalHolder[animalKey]
+  t.is(tom?.name, 'Tom')
+  assignAnimalToSymbol.call(animalHolder, animalKey, 'Garfield')
+  t.is(animalHolder[animalKey]?.name, 'Garfield')
+  t.not(animalHolder[animalKey], tom)
+  t.deepEqual(Object.keys(animalHolder), [])
+
   const classWithLifetime = new ClassWithLifetime()
   t.deepEqual(classWithLifetime.getName(), 'alie')
   t.deepEqual(Object.keys(classWithLifetime), ['inner'])
+  t.is(classWithLifetime.getNamedAnimalName('inner'), 'alie')
+  t.throws(() => classWithLifetime.getNamedAnimalName('missing'), {
+    code: 'InvalidArg',
+    message: 'Property `missing` on this is undefined',
+  })
 
   if (!process.env.TEST_ZIG_CROSS) {
     t.throws(
@@ -466,7 +857,10 @@ test('class', (t) => {
         process.env.WASI_TEST
           ? undefined
           : {
-              message: 'CatchOnConstructor2 panic',
+              // the Rust backtrace is appended in debug builds with `RUST_BACKTRACE`
+              message: process.env.RUST_BACKTRACE
+                ? /^CatchOnConstructor2 panic/
+                : 'CatchOnConstructor2 panic',
             })(),
     )
   }
@@ -533,16 +927,409 @@ test('class in object field', (t) => {
   t.is(receiveObjectWithClassField(obj), obj.bird)
 })
 
+test('downcast class instance', (t) => {
+  t.is(downcastBirdName(new Bird('Carolyn')), 'Carolyn')
+  t.throws(() => downcastBirdName(new Animal(Kind.Dog, '旺财')), {
+    code: 'InvalidArg',
+    message: /is not the type of wrapped object/,
+  })
+})
+
+test('compare class instances', (t) => {
+  const dog = new Animal(Kind.Dog, '旺财')
+  t.deepEqual(compareAnimals(dog, dog), [true, true])
+  t.deepEqual(compareAnimals(dog, new Animal(Kind.Dog, '旺财')), [
+    false,
+    false,
+  ])
+  t.deepEqual(compareAnimalHandles({ first: dog, second: dog }), [
+    false,
+    true,
+    true,
+  ])
+})
+
+test('get the env of class instance', (t) => {
+  t.is(classInstanceEnvName(new Animal(Kind.Dog, '旺财')), '旺财')
+})
+
+test('pass the wrapped pointer of class instance to foreign code', (t) => {
+  t.is(getAnimalNameLengthFromForeign(new Animal(Kind.Dog, 'Rex')), 3)
+  t.is(getAnimalNameLengthFromForeign(new Animal(Kind.Cat, '旺财')), 6)
+})
+
 test('custom finalize class', (t) => {
   t.notThrows(() => new CustomFinalize(200, 200))
 })
 
+test('custom inspect class', (t) => {
+  const point = new InspectablePoint(1, 2)
+  t.is(inspect(point), 'InspectablePoint(1, 2)')
+  t.is(inspect({ point }), '{ point: InspectablePoint(1, 2) }')
+  t.false(
+    Object.prototype.propertyIsEnumerable.call(
+      InspectablePoint.prototype,
+      inspect.custom,
+    ),
+  )
+})
+
+test('async finalize class', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const finalizedCount = AsyncFinalize.finalizedCount()
+  ;(() => {
+    for (let i = 0; i < 10; i++) {
+      new AsyncFinalize(1024)
+    }
+  })()
+  for (
+    let i = 0;
+    i < 20 && AsyncFinalize.finalizedCount() < finalizedCount + 10;
+    i++
+  ) {
+    gc()
+    await new Promise((resolve) => setTimeout(resolve, 20))
+  }
+  t.is(AsyncFinalize.finalizedCount(), finalizedCount + 10)
+})
+
+test('zero_copy getter returning &[u8] should expose the instance data without copying', async (t) => {
+  let bytes: ImmutableBytes | null = new ImmutableBytes(1000, 'bytes')
+  const data = bytes.data
+  t.true(Buffer.isBuffer(data))
+  t.is(data.length, 1000)
+  t.is(data[999], 999 % 256)
+  t.is(bytes.label, 'bytes')
+  t.is(new ImmutableBytes(0, 'empty').data.length, 0)
+  // `&[u8]` without `#[napi(zero_copy)]` is copied
+  const copied = bytes.copiedData
+  t.deepEqual(copied, data)
+  copied[0] = 100
+  t.is(data[0], 0)
+  if (process.env.WASI_TEST) {
+    return
+  }
+  const checksum = bytes.checksum()
+  data[0] = 200
+  t.is(bytes.checksum(), checksum + 200)
+  t.is(bytes.data[0], 200)
+  // the `Buffer` keeps the instance alive
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  bytes = null
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(data[0], 200)
+  t.is(data[999], 999 % 256)
+})
+
+test('replace the native value behind a ClassInstance', (t) => {
+  const counter = new VisitCounter('home')
+  counter.visit()
+  counter.visit()
+  const dropped = getDroppedVisitCounters()
+  t.is(resetVisitCounter(counter, 'reset'), 2)
+  // the old value is dropped, the JavaScript object is kept and sees the new value
+  t.is(getDroppedVisitCounters(), dropped + 1)
+  t.true(counter instanceof VisitCounter)
+  t.is(counter.label, 'reset')
+  t.is(counter.visits, 0)
+  t.is(counter.visit(), 1)
+})
+
+test('return optional class instance', (t) => {
+  for (const find of [findVisitCounter, tryFindVisitCounter]) {
+    const counter = find('home')
+    t.true(counter instanceof VisitCounter)
+    t.is(counter?.label, 'home')
+    t.is(find(''), null)
+  }
+})
+
+test('share native state between instances', (t) => {
+  const parent = new SharedCounter()
+  const child = parent.share()
+  const other = new SharedCounter()
+  t.true(child instanceof SharedCounter)
+  t.is(parent.increment(), 1)
+  t.is(child.increment(), 2)
+  t.is(parent.count, 2)
+  t.is(child.count, 2)
+  t.is(other.count, 0)
+  t.true(parent.sharesWith(child))
+  t.false(parent.sharesWith(other))
+  t.is(parent.shareCount, 2)
+  t.is(other.shareCount, 1)
+  // the conflicting borrows are errors rather than panics
+  t.throws(() => parent.incrementAfter(() => child.increment()), {
+    message: 'The shared state is already borrowed',
+  })
+  t.throws(() => parent.incrementAfter(() => child.count), {
+    message: 'The shared state is already mutably borrowed',
+  })
+  t.is(child.count, 2)
+  t.is(
+    parent.incrementAfter(() => {}),
+    3,
+  )
+  t.is(child.count, 3)
+})
+
+test('cached getter returns the same object', (t) => {
+  const chart = new Chart('sales')
+  const options = chart.options
+  t.is(chart.options, options)
+  t.is(chart.optionsCreated, 1)
+  t.deepEqual(options, { title: 'sales', width: 800, height: 600 })
+  // the cache is a hidden property keyed by a Symbol
+  t.deepEqual(Object.keys(chart), [])
+  t.deepEqual(Object.getOwnPropertyNames(chart), [])
+  t.is(JSON.stringify(chart), '{}')
+  const [cacheKey] = Object.getOwnPropertySymbols(chart)
+  t.is(cacheKey.description, 'Chart.options')
+  // the cache can't be overwritten from JavaScript
+  t.throws(
+    () => {
+      ;(chart as any)[cacheKey] = {}
+    },
+    { instanceOf: TypeError },
+  )
+  t.is(chart.options, options)
+  const other = new Chart('sales')
+  t.not(other.options, options)
+  t.deepEqual(Object.getOwnPropertySymbols(other), [cacheKey])
+  // deleting the cache creates the value again
+  t.true(delete (chart as any)[cacheKey])
+  const recreated = chart.options
+  t.not(recreated, options)
+  t.is(chart.options, recreated)
+  t.is(chart.optionsCreated, 2)
+})
+
+test('property getter closure should be dropped with the object', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const obj = createObjectWithGetterClosure(42)
+  const droppedCount = getDroppedPropertyClosures()
+  ;(() => {
+    for (let i = 0; i < 10; i++) {
+      createObjectWithGetterClosure(i)
+    }
+  })()
+  for (
+    let i = 0;
+    i < 20 && getDroppedPropertyClosures() < droppedCount + 10;
+    i++
+  ) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(getDroppedPropertyClosures(), droppedCount + 10)
+  t.is(obj.value, 42)
+})
+
 test('should be able to create object reference and shared reference', (t) => {
   const repo = new JsRepo('.')
   t.is(repo.remote().name(), 'origin')
   t.is(new JsRemote(repo).name(), 'origin')
 })
 
+test('weak reference should not keep class instance alive', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  let repo: JsRepo | null = new JsRepo('.')
+  repo.storeWeak()
+  t.is(upgradeRepoDir(), '.')
+  repo = null
+  for (let i = 0; i < 10 && upgradeRepoDir() !== null; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(upgradeRepoDir(), null)
+})
+
+// serial because the weak reference of `storeWeak` is shared with other tests
+test.serial('reference count of class instance', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  let repo: JsRepo | null = new JsRepo('/tmp/retained')
+  repo.storeWeak()
+  // the `Reference` argument holds one count during the call
+  t.is(repo.retain(), 2)
+  t.is(repo.release(), 1)
+  t.throws(() => repo!.release(), {
+    code: 'InvalidArg',
+    message: 'The ref count is not increased by `Reference::reference`',
+  })
+  repo.retain()
+  repo = null
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(upgradeRepoDir(), '/tmp/retained')
+  upgradeRepo()!.release()
+  for (let i = 0; i < 10 && upgradeRepoDir() !== null; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(upgradeRepoDir(), null)
+})
+
+test('bound method should keep class instance alive', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const dir = bindRepoDir('/tmp/repo')
+  t.is(dir(), '/tmp/repo')
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(dir(), '/tmp/repo')
+  t.is(dir.call(new JsRepo('.')), '/tmp/repo')
+})
+
+test('linked external should keep class instance alive', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const dropped = getDroppedBranches()
+  let repo: JsRepo | null = new JsRepo('/tmp/repo')
+  let main: ExternalObject<'Branch'> | null = repo.branch('main')
+  let dev: ExternalObject<'Branch'> | null = repo.branch('dev')
+  repo = null
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(getBranchRef(main), '/tmp/repo:main')
+  main = null
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  // the branches are owned by the repository, which is kept alive by `dev`
+  t.is(getDroppedBranches(), dropped)
+  t.is(getBranchRef(dev), '/tmp/repo:dev')
+  dev = null
+  for (let i = 0; i < 10 && getDroppedBranches() !== dropped + 2; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(getDroppedBranches(), dropped + 2)
+})
+
+test('reference cache should return the same instance while it is alive', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const graph = new Graph()
+  const root = graph.node(1)
+  t.is(graph.node(1), root)
+  t.is(graph.node(2).id, 2)
+  for (let i = 0; i < 100; i++) {
+    graph.node(100 + i)
+  }
+  for (let i = 0; i < 10 && graph.cachedNodes > 1; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(graph.cachedNodes, 1)
+  t.is(graph.node(1), root)
+})
+
+test('Ref keeps any JavaScript value with the ref count', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const callback = () => 42
+  const strong = new PersistentValue(callback, 1)
+  const weak = new PersistentValue({ collected: true }, 0)
+  t.is(strong.get(), callback)
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is((strong.get() as () => number)(), 42)
+  t.throws(() => weak.get(), {
+    code: 'GenericFailure',
+    message: 'Ref value has been garbage collected',
+  })
+  t.is(strong.reference(), 2)
+  t.is(strong.unref(), 1)
+  t.is(strong.unref(), 0)
+  t.throws(() => strong.get(), {
+    code: 'InvalidArg',
+    message: 'Ref value has been deleted',
+  })
+  t.throws(() => strong.unref(), { code: 'InvalidArg' })
+})
+
+test('count the alive class instances', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const total = liveClassInstanceCount()
+  let probes: LeakProbe[] | null = [new LeakProbe(), new LeakProbe()]
+  t.is(liveLeakProbeCount(), 2)
+  t.is(liveClassInstanceCount(), total + 2)
+  const repo = new JsRepo('.')
+  // only the instances of `LeakProbe` are counted
+  t.is(liveLeakProbeCount(), 2)
+  t.is(liveClassInstanceCount(), total + 3)
+  t.is(probes.length, 2)
+  probes = null
+  for (let i = 0; i < 10 && liveLeakProbeCount() !== 0; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.is(liveLeakProbeCount(), 0)
+  t.truthy(repo)
+})
+
+test('Ref is deleted only once if it is dropped after unref', (t) => {
+  // deleted by `unref`
+  t.is(dropRefAfterUnref({}, 1), 0)
+  // deleted on drop
+  t.is(dropRefAfterUnref({}, 2), 1)
+  t.is(dropRefAfterUnref(() => {}, 3), 2)
+})
+
 test('should be able to into_reference', (t) => {
   const rules = ['body: { color: red }', 'div: { color: blue }']
   const sheet = new CssStyleSheet('test.css', rules)
@@ -615,8 +1402,89 @@ test('promise', async (t) => {
   t.true(spy.calledOnce)
 })
 
+Napi4Test('resolve promise from thread', async (t) => {
+  t.is(await resolveFromThread(42), 42)
+  await t.throwsAsync(() => resolveFromThread(), {
+    code: 'InvalidArg',
+    message: 'No value to resolve',
+  })
+  await t.throwsAsync(() => dropResolverFromThread(), {
+    code: 'Cancelled',
+  })
+})
+
+test('object type tag', (t) => {
+  const obj = {}
+  t.false(isTypeTaggedObject(obj))
+  typeTagObject(obj)
+  t.true(isTypeTaggedObject(obj))
+  t.false(isTypeTaggedObject({}))
+  t.throws(() => typeTagObject(obj), {
+    code: 'InvalidArg',
+  })
+})
+
+test('freeze and seal object', (t) => {
+  const config: any = createFrozenConfig()
+  t.true(Object.isFrozen(config))
+  // strict mode
+  t.throws(
+    () => {
+      config.retries = 10
+    },
+    { instanceOf: TypeError },
+  )
+  // sloppy mode
+  runInNewContext('config.retries = 10; config.extra = true', { config })
+  t.deepEqual(config, { name: 'napi-rs', retries: 3 })
+
+  const sealed: any = createSealedConfig()
+  t.true(Object.isSealed(sealed))
+  sealed.retries = 10
+  t.is(sealed.retries, 10)
+  t.throws(
+    () => {
+      sealed.extra = true
+    },
+    { instanceOf: TypeError },
+  )
+  t.deepEqual(Object.keys(sealed), ['retries'])
+})
+
+test('object assign', (t) => {
+  const symbol = Symbol('key')
+  let getterCalled = 0
+  const source = {
+    b: 3,
+    [symbol]: 'symbol',
+    get c() {
+      getterCalled++
+      return 4
+    },
+  }
+  Object.defineProperty(source, 'hidden', { value: 5, enumerable: false })
+  const merged = mergeObjects({ a: 1, b: 2 }, source) as any
+  t.deepEqual(merged, { a: 1, b: 3, c: 4, [symbol]: 'symbol' })
+  t.is(getterCalled, 1)
+  t.deepEqual(Object.getOwnPropertyDescriptor(merged, 'c'), {
+    value: 4,
+    writable: true,
+    enumerable: true,
+    configurable: true,
+  })
+  t.false('hidden' in merged)
+})
+
 test('object', (t) => {
   t.deepEqual(listObjKeys({ name: 'John Doe', age: 20 }), ['name', 'age'])
+  const proto = { inherited: 1 }
+  const obj = Object.create(proto)
+  obj.own = 2
+  obj[0] = 3
+  obj[Symbol('symbol')] = 4
+  Object.defineProperty(obj, 'hidden', { value: 5, enumerable: false })
+  t.deepEqual(listObjPropertyNames(obj, false), ['0', 'own'])
+  t.deepEqual(listObjPropertyNames(obj, true), ['0', 'own', 'inherited'])
   t.deepEqual(createObj(), { test: 1 })
   t.throws(
     () =>
@@ -690,6 +1558,92 @@ test('create object from Property', (t) => {
   t.is(obj.getter, 42)
 })
 
+test('read back property descriptor', (t) => {
+  const obj = createObjWithProperty()
+  t.deepEqual(getPropertyDescriptor(obj, 'value'), {
+    enumerable: true,
+    configurable: true,
+    writable: true,
+    isAccessor: false,
+    hasGetter: false,
+    hasSetter: false,
+  })
+  t.deepEqual(getPropertyDescriptor(obj, 'getter'), {
+    enumerable: true,
+    configurable: true,
+    writable: false,
+    isAccessor: true,
+    hasGetter: true,
+    hasSetter: false,
+  })
+  t.is(getPropertyDescriptor(obj, 'missing'), null)
+  // the properties from the prototype chain are not own properties
+  t.is(getPropertyDescriptor(obj, 'toString'), null)
+  const defined = Object.defineProperty({}, 'setter', { set() {} })
+  t.deepEqual(getPropertyDescriptor(defined, 'setter'), {
+    enumerable: false,
+    configurable: false,
+    writable: false,
+    isAccessor: true,
+    hasGetter: false,
+    hasSetter: true,
+  })
+})
+
+test('own property is distinct from the inherited one', (t) => {
+  const proto = { inherited: 1 }
+  const obj = Object.create(proto)
+  obj.own = 2
+  t.true(objectHasOwnProperty(obj, 'own'))
+  t.false(objectHasOwnProperty(obj, 'inherited'))
+  t.true(objectHasNamedProperty(obj, 'inherited'))
+  t.false(objectHasOwnProperty(obj, 'missing'))
+  t.false(objectHasNamedProperty(obj, 'missing'))
+  // the own property is found even if its value is `undefined`
+  obj.empty = undefined
+  t.true(objectHasOwnProperty(obj, 'empty'))
+})
+
+// serial because the allocator counts the native objects of all tests
+test.serial('native objects with custom allocator', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const boxed = {}
+  wrapNativeLabel(boxed, 'boxed')
+  installCountingAllocator()
+  const [allocated, freed] = getNativeAllocations()
+  const obj = {}
+  wrapNativeLabel(obj, 'label')
+  t.is(getNativeLabel(obj), 'label')
+  t.deepEqual(getNativeAllocations(), [allocated + 1, freed])
+  dropNativeLabel(obj)
+  // the object allocated before the allocator is installed is freed by `Box`
+  dropNativeLabel(boxed)
+  t.deepEqual(getNativeAllocations(), [allocated + 1, freed + 1])
+  let collected: object | null = {}
+  wrapNativeLabel(collected, 'collected')
+  collected = null
+  for (let i = 0; i < 10 && getNativeAllocations()[1] !== freed + 2; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.deepEqual(getNativeAllocations(), [allocated + 2, freed + 2])
+  // the instances of the classes are allocated with the allocator too
+  let animal: Animal | null = new Animal(Kind.Dog, 'allocated')
+  t.is(animal.name, 'allocated')
+  t.deepEqual(getNativeAllocations(), [allocated + 3, freed + 2])
+  animal = null
+  for (let i = 0; i < 10 && getNativeAllocations()[1] !== freed + 3; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  t.deepEqual(getNativeAllocations(), [allocated + 3, freed + 3])
+})
+
 test('global', (t) => {
   t.is(getGlobal(), typeof global === 'undefined' ? globalThis : global)
 })
@@ -725,17 +1679,57 @@ test('create symbol', (t) => {
   t.is(createSymbol().toString(), 'Symbol(a symbol)')
 })
 
+test('create error with code', (t) => {
+  const err = createErrorWithCode('ERR_INVALID_STATE', 'Invalid state')
+  t.true(err instanceof Error)
+  t.is(err.message, 'Invalid state')
+  t.is((err as Error & { code: string }).code, 'ERR_INVALID_STATE')
+  const withoutCode = createErrorWithCode(null, 'No code')
+  t.is(withoutCode.message, 'No code')
+  t.false('code' in withoutCode)
+})
+
 test('Option', (t) => {
   t.is(mapOption(null), null)
   t.is(mapOption(3), 4)
 })
 
+test('Error cause', (t) => {
+  const err = t.throws(() => throwErrorWithCause(), {
+    code: 'InvalidArg',
+    message: 'Outer error',
+  })
+  t.is((err!.cause as Error).message, 'Inner error')
+  t.is(((err!.cause as Error).cause as Error).message, 'Root cause')
+  t.is(((err!.cause as Error).cause as Error).cause, undefined)
+  t.false(Object.getOwnPropertyDescriptor(err, 'cause')!.enumerable)
+
+  t.is(parseConfig('42'), 42)
+  const configError = t.throws(() => parseConfig('not a number'), {
+    message: 'Invalid config',
+  })
+  t.is(
+    (configError!.cause as Error).message,
+    'invalid digit found in string',
+  )
+})
+
 test('Result', (t) => {
   t.throws(() => throwError(), void 0, 'Manual Error')
   if (!process.env.SKIP_UNWIND_TEST) {
     t.throws(() => panic(), void 0, `Don't panic`)
+    t.throws(() => panicWithFormattedMessage(42), {
+      message: process.env.RUST_BACKTRACE
+        ? /^Panic with value 42/
+        : 'Panic with value 42',
+    })
   }
   t.throws(() => errorMessageContainsNullByte('\u001a\u0000'))
+  t.is(parseWithAnyhow('42'), 42)
+  t.throws(() => parseWithAnyhow('x'), {
+    code: 'GenericFailure',
+    message: 'invalid digit found in string',
+  })
 
   const errors = jsErrorCallback(new Error('JS Error'))
   t.deepEqual(errors[0]!.message, 'JS Error')
@@ -763,11 +1757,54 @@ test('custom status code in Error', (t) => {
   })
 })
 
+test('map the domain error into JavaScript error with AsJsError', (t) => {
+  t.is(validateUsername('napi'), 'napi')
+  t.throws(() => validateUsername(''), {
+    instanceOf: TypeError,
+    code: 'ERR_EMPTY_USERNAME',
+    message: 'Username is empty',
+  })
+  t.throws(() => validateUsername('a-very-long-name'), {
+    instanceOf: RangeError,
+    code: 'ERR_USERNAME_TOO_LONG',
+    message:
+      'Username is 16 characters long, at most 8 characters are allowed',
+  })
+})
+
+test('recover the pending exception thrown from JavaScript', (t) => {
+  const error = new TypeError('thrown from callback')
+  const results = callAndRecoverExceptions([
+    () => 1,
+    () => {
+      throw error
+    },
+    () => {
+      // eslint-disable-next-line no-throw-literal
+      throw 'thrown string'
+    },
+    () => 'called after the exceptions',
+  ])
+  t.deepEqual(results, [
+    1,
+    error,
+    'thrown string',
+    'called after the exceptions',
+  ])
+  t.is(results[1], error)
+})
+
 test('function ts type override', (t) => {
   // @ts-expect-error
   t.deepEqual(tsRename({ foo: 1, bar: 2, baz: 2 }), ['foo', 'bar', 'baz'])
 })
 
+test('function ts return type override', (t) => {
+  const point: { kind: 'point'; x: number; y: number } =
+    overrideUnknownReturnType(1, 2)
+  t.deepEqual(point, { kind: 'point', x: 1, y: 2 })
+})
+
 test('function individual ts arg type override', (t) => {
   t.is(
     overrideIndividualArgOnFunction('someStr', () => 'anotherStr', 42),
@@ -887,6 +1924,26 @@ test('serde-buffer-bytes', (t) => {
   t.is(testSerdeBufferBytes({ code: new ArrayBuffer(0) }), 0n)
 })
 
+test('serde keys order', (t) => {
+  for (let i = 0; i < 10; i++) {
+    const obj = serializeLabeledObject()
+    // struct fields are in the declaration order
+    t.deepEqual(Object.keys(obj), ['name', 'labels', 'version'])
+    t.deepEqual(Object.keys(obj.labels).sort(), [
+      '10',
+      '2',
+      'alpha',
+      'mid',
+      'zeta',
+    ])
+    const sorted = serializeLabeledObjectWithSortedKeys()
+    t.deepEqual(Object.keys(sorted), ['name', 'labels', 'version'])
+    // integer-like keys are always enumerated first in JavaScript
+    t.deepEqual(Object.keys(sorted.labels), ['2', '10', 'alpha', 'mid', 'zeta'])
+    t.deepEqual(sorted, obj)
+  }
+})
+
 test('get bigint json value', (t) => {
   t.notThrows(() => {
     getBigintJsonValue(-1n)
@@ -909,9 +1966,61 @@ test('buffer', (t) => {
   t.is(a.toString(), '')
   t.is(b.toString(), '')
 
+  const pooled = getPooledBuffers(2000)
+  t.is(pooled.length, 2000)
+  pooled[0][0] = 0x62
+  t.deepEqual(
+    pooled.map((buf) => buf.toString()),
+    Array.from({ length: 2000 }, (_, i) => `${i === 0 ? 'b' : 'B'}uffer ${i}`),
+  )
+
   t.true(Array.isArray(asyncBufferToArray(Buffer.from([1, 2, 3]).buffer)))
 })
 
+test('zero copy Buffer from Vec<u8>', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const buf = getZeroCopyBuffer(300)
+  t.is(buf.length, 300)
+  t.deepEqual([...buf.subarray(254, 258)], [254, 255, 0, 1])
+  t.is(getZeroCopyBuffer(0).length, 0)
+  t.deepEqual(getBufferSliceWithSpareCapacity(4), Buffer.from([0, 1, 2, 3]))
+  // the finalizers free the `Vec` with the spare capacity
+  for (let i = 0; i < 1000; i++) {
+    getZeroCopyBuffer(1024)
+    getBufferSliceWithSpareCapacity(1024)
+  }
+  gc()
+  await new Promise((resolve) => setImmediate(resolve))
+  t.pass()
+})
+
+test('Buffer from BufferPool', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const buffers = Array.from({ length: 32 }, () => getBufferFromPool(1024))
+  t.deepEqual([...buffers[31].subarray(254, 258)], [254, 255, 0, 1])
+  buffers.length = 0
+  for (let i = 0; i < 10; i++) {
+    gc()
+    await new Promise((resolve) => setImmediate(resolve))
+  }
+  // the allocations are returned to the pool, but no more than its bound
+  t.is(getBufferPoolCount(), 16)
+  const reused = getBufferFromPool(512)
+  t.is(reused.length, 512)
+  t.deepEqual([...reused.subarray(0, 4)], [0, 1, 2, 3])
+  t.is(getBufferPoolCount(), 15)
+})
+
 test('Return BufferSlice with lifetime', (t) => {
   const reader = new Reader()
   const reader2 = new Reader()
@@ -934,6 +2043,16 @@ test('TypedArray', (t) => {
   t.deepEqual(i32ArrayToArray(new Int32Array([1, 2, 3])), [1, 2, 3])
   t.deepEqual(u64ArrayToArray(new BigUint64Array([1n, 2n, 3n])), [1n, 2n, 3n])
   t.deepEqual(i64ArrayToArray(new BigInt64Array([1n, 2n, 3n])), [1, 2, 3])
+  t.deepEqual(
+    bigint64ArrayRoundTrip(
+      new BigInt64Array([-(2n ** 63n), 2n ** 63n - 1n, 0n]),
+    ),
+    new BigInt64Array([-(2n ** 63n), 2n ** 63n - 1n, 0n]),
+  )
+  t.deepEqual(
+    biguint64ArrayRoundTrip(new BigUint64Array([2n ** 64n - 1n, 0n])),
+    new BigUint64Array([2n ** 64n - 1n, 0n]),
+  )
   t.deepEqual(f32ArrayToArray(new Float32Array([1, 2, 3])), [1, 2, 3])
   t.deepEqual(f64ArrayToArray(new Float64Array([1, 2, 3])), [1, 2, 3])
 
@@ -951,6 +2070,91 @@ test('TypedArray', (t) => {
   )
 })
 
+test('TypedArray element type mismatch', (t) => {
+  // @ts-expect-error
+  t.throws(() => convertU32Array(new Float32Array([1, 2, 3])), {
+    code: 'InvalidArg',
+    message: 'Expected Uint32Array, got Float32Array',
+  })
+  // @ts-expect-error
+  t.throws(() => u8ArrayToArray(new Float32Array([1, 2, 3])), {
+    code: 'InvalidArg',
+    message: 'Expected Uint8Array, got Float32Array',
+  })
+  // @ts-expect-error
+  t.throws(() => acceptUint8ClampedSlice(new Uint8Array([1, 2, 3])), {
+    code: 'InvalidArg',
+    message: 'Expected Uint8ClampedArray, got Uint8Array',
+  })
+})
+
+test('SharedArrayBuffer', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const buf = createSharedArrayBuffer(8, 1)
+  t.true(buf instanceof SharedArrayBuffer)
+  t.is(sumSharedArrayBuffer(buf), 8)
+  t.throws(() => sumSharedArrayBuffer(new ArrayBuffer(8) as any), {
+    code: 'InvalidArg',
+    message: 'Expected a SharedArrayBuffer value',
+  })
+  const worker = new Worker(
+    `
+    const { parentPort } = require('node:worker_threads')
+    parentPort.once('message', (buf) => {
+      const view = new Uint8Array(buf)
+      for (let i = 0; i < view.length; i++) {
+        view[i] += 2
+      }
+      parentPort.postMessage('done')
+    })
+    `,
+    { eval: true },
+  )
+  await new Promise<void>((resolve) => {
+    worker.once('message', () => resolve())
+    worker.postMessage(buf)
+  })
+  await worker.terminate()
+  t.is(sumSharedArrayBuffer(buf), 24)
+})
+
+test('DataView', (t) => {
+  const header = {
+    kind: 7,
+    flags: -2,
+    length: 0xdeadbeef,
+    timestamp: 2n ** 63n + 5n,
+    ratio: 0.25,
+  }
+  const view = createPacketHeader(header)
+  t.true(view instanceof DataView)
+  t.is(view.byteLength, 23)
+  t.is(view.getInt16(1, false), -2)
+  t.is(view.getUint32(3, true), 0xdeadbeef)
+  t.is(view.getBigUint64(7, true), 2n ** 63n + 5n)
+  t.is(view.getFloat64(15, true), 0.25)
+  t.deepEqual(parsePacketHeader(view), header)
+
+  const buf = new ArrayBuffer(40)
+  new Uint8Array(buf, 8).set(new Uint8Array(view.buffer))
+  const subView = new DataView(buf, 8, 23)
+  t.deepEqual(parsePacketHeader(subView), header)
+  t.deepEqual(getDataviewByteOffset(subView), [8, 23, 40])
+
+  t.throws(() => parsePacketHeader(new DataView(new ArrayBuffer(10))), {
+    code: 'InvalidArg',
+    message:
+      'Reading or writing 8 bytes at offset 7 is outside the bounds of the DataView with length 10',
+  })
+  t.throws(() => parsePacketHeader(new Uint8Array(23) as any), {
+    code: 'InvalidArg',
+    message: 'Expected a DataView value',
+  })
+})
+
 test('emptybuffer', (t) => {
   let buf = new ArrayBuffer(0)
   t.is(acceptArraybuffer(buf), 0n)
@@ -991,6 +2195,27 @@ test('mutate TypedArray', (t) => {
   t.deepEqual(input, new Float32Array([2.0, 4.0, 6.0, 8.0, 10.0]))
 })
 
+test('mutate Float64Array with as_mut_slice', (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const input = new Float64Array([1, 2, 3, 4, 5])
+  mutateFloat64Array(input)
+  t.deepEqual(input, new Float64Array([2.0, 4.0, 6.0, 8.0, 10.0]))
+  const detached = new Float64Array([1, 2, 3])
+  t.throws(
+    () =>
+      mutateFloat64Array(detached, () => {
+        structuredClone(detached.buffer, { transfer: [detached.buffer] })
+      }),
+    {
+      code: 'InvalidArg',
+      message: 'The ArrayBuffer of Float64Array has been detached',
+    },
+  )
+})
+
 test('deref uint8 array', (t) => {
   t.is(
     derefUint8Array(new Uint8Array([1, 2]), new Uint8ClampedArray([3, 4])),
@@ -998,6 +2223,83 @@ test('deref uint8 array', (t) => {
   )
 })
 
+test('typed array subarray shares the ArrayBuffer', (t) => {
+  const parent = new Uint8Array([0, 1, 2, 3, 4, 5, 6, 7])
+  const sub = uint8ArraySubarray(parent, 2, 6)
+  t.deepEqual(Array.from(sub), [2, 3, 4, 5])
+  t.is(sub.buffer, parent.buffer)
+  t.is(sub.byteOffset, 2)
+  sub[0] = 42
+  t.is(parent[2], 42)
+  const nested = uint8ArraySubarray(sub, 1, 3)
+  t.is(nested.byteOffset, 3)
+  t.deepEqual(Array.from(nested), [3, 4])
+  t.is(uint8ArraySubarray(parent, 8, 8).length, 0)
+  t.throws(() => uint8ArraySubarray(parent, 2, 9), {
+    code: 'InvalidArg',
+    message: 'Subarray range 2..9 is out of range for Uint8Array with length 8',
+  })
+  t.throws(() => uint8ArraySubarray(parent, 5, 4), {
+    code: 'InvalidArg',
+  })
+  t.throws(() => subarrayOfRustData(), {
+    code: 'InvalidArg',
+    message: 'Only the Uint8Array from JavaScript can create subarray',
+  })
+  const floats = new Float32Array(6)
+  fillFloat32ArrayRange(floats.subarray(1), 1, 3, 0.5)
+  t.deepEqual(Array.from(floats), [0, 0, 0.5, 0.5, 0, 0])
+})
+
+test('typed array into fixed size array', (t) => {
+  const matrix = new Float64Array(16)
+  for (let i = 0; i < 4; i++) {
+    matrix[i * 5] = i + 1
+  }
+  t.is(matrix4Trace(matrix), 10)
+  t.is(rgbaToHex(new Uint8ClampedArray([255, 128, 0, 16])), '#ff800010')
+  t.throws(() => matrix4Trace(new Float64Array(9)), {
+    code: 'InvalidArg',
+    message: 'Expected Float64Array with length 16, but got length 9',
+  })
+  t.throws(() => rgbaToHex(new Uint8ClampedArray(5)), {
+    code: 'InvalidArg',
+    message: 'Expected Uint8ClampedArray with length 4, but got length 5',
+  })
+})
+
+test('detached ArrayBuffer', (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const detached = new ArrayBuffer(8)
+  t.true(detachArraybuffer(detached))
+  t.is(detached.byteLength, 0)
+  t.is(sumArraybufferAfter(new Uint8Array([1, 2, 3, 4]).buffer, () => {}), 10)
+  const transferred = new Uint8Array([1, 2, 3, 4]).buffer
+  t.throws(
+    () =>
+      sumArraybufferAfter(transferred, () => {
+        structuredClone(transferred, { transfer: [transferred] })
+      }),
+    { code: 'InvalidArg', message: 'The ArrayBuffer has been detached' },
+  )
+  const filled = new ArrayBuffer(4)
+  fillArraybufferAfter(filled, 7, () => {})
+  t.deepEqual(new Uint8Array(filled), new Uint8Array([7, 7, 7, 7]))
+  const posted = new ArrayBuffer(4)
+  t.throws(
+    () =>
+      fillArraybufferAfter(posted, 7, () => {
+        const { port1 } = new MessageChannel()
+        port1.postMessage(posted, [posted])
+        port1.close()
+      }),
+    { code: 'InvalidArg', message: 'The ArrayBuffer has been detached' },
+  )
+})
+
 test('async', async (t) => {
   if (process.env.WASI_TEST) {
     t.pass()
@@ -1030,6 +2332,46 @@ test('async move', async (t) => {
   t.is(await asyncMultiTwo(2), 4)
 })
 
+test('spawn onto the tokio runtime handle', async (t) => {
+  t.is(await spawnOnRuntimeHandle(21), 42)
+})
+
+test('await all the JavaScript promises from Rust', async (t) => {
+  const delay = (value: number, ms: number) =>
+    new Promise<number>((resolve) => setTimeout(() => resolve(value), ms))
+  t.is(
+    await sumAllPromises([delay(1, 20), delay(2, 10), Promise.resolve(3)]),
+    6,
+  )
+  t.is(await sumAllPromises([]), 0)
+  const error = new Error('rejected in JavaScript')
+  await t.throwsAsync(
+    () =>
+      sumAllPromises([
+        delay(1, 10),
+        Promise.reject(error),
+        delay(3, 20),
+        Promise.resolve(4),
+      ]),
+    { is: error },
+  )
+})
+
+test('env scope in async function', async (t) => {
+  t.is(await stringifyInEnvScope(42), '{"value":42}')
+  t.deepEqual(
+    await Promise.all([1, 2, 3].map((value) => stringifyInEnvScope(value))),
+    ['{"value":1}', '{"value":2}', '{"value":3}'],
+  )
+})
+
+test('turn the pending exception of env_scope into the returned error', async (t) => {
+  await t.throwsAsync(() => throwInEnvScope(), {
+    message: 'Error: Error thrown in env scope',
+  })
+  t.is(await stringifyInEnvScope(1), '{"value":1}')
+})
+
 test('buffer passthrough', async (t) => {
   const fixture = Buffer.from('hello world')
   const ret = await bufferPassThrough(fixture)
@@ -1111,6 +2453,14 @@ test('either from objects', (t) => {
   t.is(eitherFromObjects({ baz: 3 }), 'C')
 })
 
+test('try cast unknown value', (t) => {
+  t.is(describeUnknown(1.5), 'number: 1.5')
+  t.is(describeUnknown('napi'), 'string: napi')
+  t.is(describeUnknown({ a: 1, b: 2 }), 'object with keys: a, b')
+  t.is(describeUnknown(true), 'unsupported: Boolean')
+  t.is(describeUnknown(null), 'unsupported: Null')
+})
+
 test('either3', (t) => {
   t.is(either3(2), 2)
   t.is(either3('hello'), 'hello'.length)
@@ -1118,6 +2468,12 @@ test('either3', (t) => {
   t.is(either3(false), 0)
 })
 
+test('either variant', (t) => {
+  t.is(either3Variant('hello'), 'A')
+  t.is(either3Variant(2), 'B')
+  t.is(either3Variant(true), 'bool')
+})
+
 test('either4', (t) => {
   t.is(either4(2), 2)
   t.is(either4('hello'), 'hello'.length)
@@ -1149,6 +2505,13 @@ test('external', (t) => {
   t.is(e?.message, '<u32> on `External` is not the type of wrapped object')
 })
 
+test('take external', (t) => {
+  const ext = createExternalString('wtf')
+  t.is(takeExternalString(ext), 'wtf')
+  const e = t.throws(() => takeExternalString(ext))
+  t.regex(e?.message ?? '', /on `External` has been taken$/)
+})
+
 test('optional external', (t) => {
   const FX = 42
   const extEmpty = createOptionalExternal()
@@ -1170,6 +2533,138 @@ test('should be able to run script', async (t) => {
   t.is(await runScript(`Promise.resolve(1)`), 1)
 })
 
+test('adjust external memory', (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const size = 100 * 1024 * 1024
+  const [before, increased, decreased] = adjustExternalMemory(size)
+  t.is(increased, before + size)
+  t.is(decreased, before)
+})
+
+test('should be able to run script with filename', (t) => {
+  t.is(runScriptWithFilename(`1 + 1`, 'sum.js'), 2)
+  const err = t.throws(() =>
+    runScriptWithFilename(`throw new Error('boom')`, 'user-snippet.js'),
+  )
+  t.true(err?.stack?.includes('user-snippet.js:1:'))
+  t.throws(() => runScriptWithFilename(`1 + 1`, 'user snippet.js'), {
+    code: 'InvalidArg',
+  })
+})
+
+test('should be able to structured clone value', (t) => {
+  const original = {
+    name: 'napi',
+    nested: { list: [1, 2, { deep: true }], map: new Map([[1, 'one']]) },
+    date: new Date(0),
+  }
+  const cloned = structuredCloneValue(original) as typeof original
+  t.deepEqual(cloned, original)
+  t.not(cloned.nested, original.nested)
+  t.true(cloned.date instanceof Date)
+  cloned.nested.list.push(3)
+  cloned.nested.map.set(2, 'two')
+  ;(cloned.nested.list[2] as { deep: boolean }).deep = false
+  t.deepEqual(original.nested.list, [1, 2, { deep: true }])
+  t.is(original.nested.map.size, 1)
+  t.throws(() => structuredCloneValue({ fn: () => {} }), {
+    code: 'InvalidArg',
+    message: /^Failed to structured clone the value: DataCloneError/,
+  })
+})
+
+test('should transfer value with v8 serialization', (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const original = {
+    name: 'napi',
+    nested: { list: [1, 2, { deep: true }], map: new Map([[1, 'one']]) },
+    date: new Date(0),
+    big: 1n << 64n,
+  }
+  const bytes = toTransferableValue(original)
+  t.true(Buffer.isBuffer(bytes))
+  t.deepEqual(bytes, v8Serialize(original))
+  t.deepEqual(v8Deserialize(bytes), original)
+  const transferred = transferValueThroughThread(original) as typeof original
+  t.deepEqual(transferred, original)
+  t.not(transferred.nested, original.nested)
+  t.true(transferred.date instanceof Date)
+  t.throws(() => toTransferableValue({ fn: () => {} }), {
+    code: 'InvalidArg',
+    message: /^Failed to serialize the value: .* could not be cloned/,
+  })
+})
+
+test('get the global objects', (t) => {
+  t.is(getGlobalObject(''), globalThis)
+  t.is(getGlobalObject('globalThis'), globalThis)
+  t.is(getGlobalObject('console'), console)
+  t.is(getGlobalObject('Array'), Array)
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  t.is(getGlobalObject('process'), process)
+  t.is(getProcessEnvVar('PATH'), process.env.PATH)
+  t.is(getProcessEnvVar('NAPI_RS_NOT_EXISTED_ENV_VAR'), null)
+  t.throws(() => getGlobalObject('notExistedGlobal'), {
+    message: '`notExistedGlobal` is not available in the current context',
+  })
+  t.throws(() => getGlobalObject('NaN'), {
+    code: 'ObjectExpected',
+    message: 'Expected the global `NaN` to be an object, got Number',
+  })
+})
+
+test('JSON stringify and parse', (t) => {
+  const value = { name: 'napi', list: [1, true, null], nested: { a: 'b' } }
+  t.is(jsonStringifyValue(value), JSON.stringify(value))
+  t.is(jsonStringifyValue('str'), '"str"')
+  t.is(jsonStringifyValue(undefined), null)
+  t.is(jsonStringifyValue(() => {}), null)
+  const circular: Record<string, unknown> = {}
+  circular.self = circular
+  t.throws(() => jsonStringifyValue(circular), {
+    code: 'InvalidArg',
+    message: /^Failed to stringify the value: TypeError/,
+  })
+  t.deepEqual(jsonParseValue(JSON.stringify(value)), value)
+  t.is(jsonParseValue('42'), 42)
+  t.throws(() => jsonParseValue('{'), {
+    code: 'InvalidArg',
+    message: /^Failed to parse the JSON: SyntaxError/,
+  })
+})
+
+test('run the finalizer after the plain object is collected', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  setFlagsFromString('--expose_gc')
+  const gc = runInNewContext('gc')
+  const collected: string[] = []
+  const alive = {}
+  onObjectCollected(alive, 'alive', (label) => collected.push(label))
+  ;(() => {
+    onObjectCollected({}, 'temporary', (label) => collected.push(label))
+  })()
+  // the finalizer is called in a later task after the object is collected, the timing is not deterministic
+  for (let i = 0; i < 20 && collected.length === 0; i++) {
+    gc()
+    await new Promise((resolve) => setTimeout(resolve, 10))
+  }
+  t.deepEqual(collected, ['temporary'])
+  t.truthy(alive)
+  t.throws(() => onObjectCollected(1 as unknown as object, 'number', () => {}))
+})
+
 test('should be able to return object from shared crate', (t) => {
   t.deepEqual(returnFromSharedCrate(), {
     value: 42,
@@ -1202,6 +2697,70 @@ AbortSignalTest('abort resolved task', async (t) => {
   t.pass('should not throw')
 })
 
+AbortSignalTest('abort async function', async (t) => {
+  const ctrl = new AbortController()
+  const promise = countUntilAborted(100, ctrl.signal)
+  await new Promise((resolve) => setTimeout(resolve, 50))
+  ctrl.abort()
+  await t.throwsAsync(() => promise, { message: 'AbortError' })
+  // the future is dropped rather than run to completion in the background
+  const steps = getAbortableSteps()
+  t.true(steps < 100)
+  await new Promise((resolve) => setTimeout(resolve, 100))
+  t.is(getAbortableSteps(), steps)
+
+  t.is(await countUntilAborted(3, new AbortController().signal), 3)
+  t.is(getAbortableSteps(), steps + 3)
+  // without the signal it always runs to completion
+  t.is(await countUntilAborted(3), 3)
+  t.is(getAbortableSteps(), steps + 6)
+})
+
+AbortSignalTest('spawn task on the thread pool', async (t) => {
+  const fnv1a = (data: Buffer) => {
+    let hash = 0xcbf29ce484222325n
+    for (const byte of data) {
+      hash = ((hash ^ BigInt(byte)) * 0x100000001b3n) & 0xffffffffffffffffn
+    }
+    return hash.toString(16).padStart(16, '0')
+  }
+  const data = Buffer.from('hello world')
+  t.is(await hashBuffer(data), fnv1a(data))
+  t.is(await hashBuffer(Buffer.alloc(0), null), fnv1a(Buffer.alloc(0)))
+  t.is(await hashBuffer(data, new AbortController().signal), fnv1a(data))
+  t.throws(() => hashBuffer(data, 1 as any), {
+    message: 'Expected an AbortSignal, got Number',
+  })
+  // the object which only looks like an `AbortSignal` is rejected
+  t.throws(() => hashBuffer(data, { aborted: false } as any), {
+    message: 'Expected an AbortSignal, got Object',
+  })
+})
+
+Napi4Test('async task with progress', async (t) => {
+  const progress: number[] = []
+  t.is(await countWithProgress(4, (p) => progress.push(p)), 4)
+  // progress events are delivered through a threadsafe function, wait for the pending ones
+  await new Promise((resolve) => setTimeout(resolve, 50))
+  t.deepEqual(progress, [0.25, 0.5, 0.75, 1])
+})
+
+// the 10ms steps of the `u32::MAX` steps task don't finish in the test
+const MAX_STEPS = 0xffffffff
+
+AbortSignalTest('drop progress of aborted task', async (t) => {
+  const ctrl = new AbortController()
+  const progress: number[] = []
+  // the task never finishes without the abort
+  const count = await countWithProgress(MAX_STEPS, (p) => {
+    progress.push(p)
+    ctrl.abort()
+  }, ctrl.signal)
+  await new Promise((resolve) => setTimeout(resolve, 50))
+  t.true(count >= 1)
+  t.is(progress.length, 1)
+})
+
 test('abort signal should be able to reuse with different tasks', async (t) => {
   const ctrl = new AbortController()
   await t.notThrowsAsync(async () => {
@@ -1221,6 +2780,28 @@ test('abort signal should be able to reuse with different tasks', async (t) => {
   })
 })
 
+AbortSignalTest('abort task when any of the signals is aborted', async (t) => {
+  const timeout = new AbortController()
+  const user = new AbortController()
+  // the task never finishes without the abort
+  const count = await countWithAnySignal(
+    MAX_STEPS,
+    () => user.abort(),
+    [timeout.signal, user.signal],
+  )
+  t.true(count >= 1)
+  // aborting the other signal after the task is aborted is a no-op
+  t.notThrows(() => timeout.abort())
+})
+
+AbortSignalTest('abort signals composed into finished tasks', async (t) => {
+  const ctrl = new AbortController()
+  for (let i = 0; i < 5; i++) {
+    t.is(await countWithAnySignal(2, () => {}, [ctrl.signal]), 2)
+  }
+  t.notThrows(() => ctrl.abort())
+})
+
 const BigIntTest = typeof BigInt !== 'undefined' ? test : test.skip
 
 BigIntTest('BigInt add', (t) => {
@@ -1256,6 +2837,28 @@ BigIntTest('from i128 i64', (t) => {
   t.is(bigintFromI128(), BigInt('-100'))
 })
 
+BigIntTest('i128 u128 round trip', (t) => {
+  const u128Max = (BigInt(1) << BigInt(128)) - BigInt(1)
+  const i128Max = (BigInt(1) << BigInt(127)) - BigInt(1)
+  const i128Min = -(BigInt(1) << BigInt(127))
+  t.is(bigintU128RoundTrip(u128Max), u128Max)
+  t.is(bigintU128RoundTrip(BigInt(0)), BigInt(0))
+  t.is(bigintI128RoundTrip(i128Max), i128Max)
+  t.is(bigintI128RoundTrip(i128Min), i128Min)
+  t.is(bigintI128RoundTrip(BigInt(-100)), BigInt(-100))
+  t.is(bigintI128RoundTrip(BigInt(0)), BigInt(0))
+  t.throws(() => bigintU128RoundTrip(u128Max + BigInt(1)), {
+    code: 'BigintExpected',
+  })
+  t.throws(() => bigintU128RoundTrip(BigInt(-1)), { code: 'BigintExpected' })
+  t.throws(() => bigintI128RoundTrip(i128Max + BigInt(1)), {
+    code: 'BigintExpected',
+  })
+  t.throws(() => bigintI128RoundTrip(i128Min - BigInt(1)), {
+    code: 'BigintExpected',
+  })
+})
+
 Napi4Test('call ThreadsafeFunction', (t) => {
   if (process.env.WASI_TEST) {
     t.pass()
@@ -1342,6 +2945,58 @@ Napi4Test('throw error from thread safe function fatal mode', (t) => {
   })
 })
 
+test('abort the process on panic in the function without catch_unwind', (t) => {
+  if (process.env.WASI_TEST || process.env.SKIP_UNWIND_TEST) {
+    t.pass()
+    return
+  }
+  const p = exec(
+    `node -e "require('../index.cjs').panicWithoutCatchUnwind()"`,
+    {
+      cwd: __dirname,
+    },
+  )
+  return new Promise<void>((resolve) => {
+    p.on('exit', (code) => {
+      t.not(code, 0)
+      resolve()
+    })
+  })
+})
+
+test('call finalize error handler before throwing into env', (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const p = exec('node --expose-gc ./finalize-error.cjs', {
+    cwd: __dirname,
+  })
+  let stdout = ''
+  p.stdout?.on('data', (data) => {
+    stdout += data
+  })
+  return new Promise<void>((resolve) => {
+    p.on('exit', (code) => {
+      t.is(code, 0)
+      const result = stdout
+        .split('\n')
+        .find((line) => line.startsWith('FINALIZE_ERROR_RESULT '))
+      t.truthy(result)
+      t.deepEqual(JSON.parse(result!.slice('FINALIZE_ERROR_RESULT '.length)), {
+        errors: [
+          'Failed to release resource 0',
+          'Failed to release resource 1',
+          'Failed to release resource 2',
+        ],
+        liveCount: 0,
+        nextErrorsCount: 1,
+      })
+      resolve()
+    })
+  })
+})
+
 Napi4Test('await Promise in rust', async (t) => {
   const fx = 20
   const result = await asyncPlus100(
@@ -1370,6 +3025,31 @@ Napi4Test('call ThreadsafeFunction with callback', async (t) => {
   )
 })
 
+Napi4Test('release ThreadsafeFunction inside its own callback', async (t) => {
+  const times = 1000
+  const received = new Set<number>()
+  await new Promise<void>((resolve) => {
+    releaseTsfnInCallback((n) => {
+      received.add(n)
+      if (received.size === times) {
+        resolve()
+      }
+    }, times)
+  })
+  t.is(received.size, times)
+})
+
+Napi4Test('call ThreadsafeFunction with timeout', async (t) => {
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const received = await new Promise<number>((resolve) => {
+    t.deepEqual(tsfnCallWithTimeout(resolve), ['Ok', '1', 'QueueFull'])
+  })
+  t.is(received, 1)
+})
+
 Napi4Test('async call ThreadsafeFunction', async (t) => {
   await t.notThrowsAsync(() =>
     tsfnAsyncCall((arg1, arg2, arg3) => {
@@ -1420,6 +3100,19 @@ test('Throw from ThreadsafeFunction JavaScript callback', async (t) => {
   )
 })
 
+Napi4Test('async call ThreadsafeFunction in fatal mode', async (t) => {
+  t.is(await tsfnAsyncCallFatalMode((value) => value), 42)
+  await t.throwsAsync(
+    () =>
+      tsfnAsyncCallFatalMode(() => {
+        throw new Error('ThrowFromFatalModeCallback')
+      }),
+    {
+      message: 'ThrowFromFatalModeCallback',
+    },
+  )
+})
+
 Napi4Test('accept ThreadsafeFunction', async (t) => {
   await new Promise<void>((resolve, reject) => {
     acceptThreadsafeFunction((err, value) => {
@@ -1442,6 +3135,24 @@ Napi4Test('accept ThreadsafeFunction Fatal', async (t) => {
   })
 })
 
+Napi4Test('coalescing ThreadsafeFunction keeps the latest value', async (t) => {
+  const received: number[] = []
+  await new Promise<void>((resolve) => {
+    coalesceProgress((progress) => {
+      received.push(progress)
+      if (progress === 10000) {
+        resolve()
+      }
+    }, 10000)
+  })
+  // the 10001 calls are made before any of them is delivered, they are coalesced into the last one
+  t.true(received.length < 10001)
+  t.deepEqual(received, [10000])
+  for (let i = 1; i < received.length; i++) {
+    t.true(received[i] > received[i - 1])
+  }
+})
+
 Napi4Test('accept ThreadsafeFunction tuple args', async (t) => {
   await new Promise<void>((resolve, reject) => {
     acceptThreadsafeFunctionTupleArgs((err, num, bool, str) => {
@@ -1557,6 +3268,19 @@ Napi5Test('Date to chrono test', (t) => {
   )
 })
 
+Napi5Test('chrono DateTime<Utc> round trip', (t) => {
+  const fromRust = chronoUtcDateWithSubMillis()
+  // sub-millisecond precision is truncated
+  t.deepEqual(fromRust, new Date(1644435115396))
+  t.deepEqual(chronoUtcDateRoundTrip(fromRust), fromRust)
+  const fixture = new Date('1969-07-20T20:17:40.123Z')
+  t.deepEqual(chronoUtcDateRoundTrip(fixture), fixture)
+  t.throws(() => chronoUtcDateRoundTrip(new Date(NaN)), {
+    code: 'DateExpected',
+    message: 'Found invalid date',
+  })
+})
+
 Napi5Test('Get date', (t) => {
   const fixture1 = new Date('2024-02-07T18:28:18-0800')
   t.deepEqual(chronoDateFixtureReturn1(), fixture1)
@@ -1594,6 +3318,14 @@ Napi9Test('create symbol for', (t) => {
   t.is(createSymbolFor('foo'), Symbol.for('foo'))
 })
 
+Napi9Test('create symbol for key', (t) => {
+  const symbol = createSymbolForKey('nodejs.util.inspect.custom')
+  t.true(symbol === createSymbolForKey('nodejs.util.inspect.custom'))
+  t.true(symbol === Symbol.for('nodejs.util.inspect.custom'))
+  t.is(getSymbolDescription(symbol), 'nodejs.util.inspect.custom')
+  t.is(getSymbolDescription(Symbol()), null)
+})
+
 Napi9Test('get module file name', (t) => {
   if (process.env.WASI_TEST) {
     t.pass()
@@ -1619,6 +3351,23 @@ test('throw syntax error', (t) => {
   )
 })
 
+test('throw type error and range error', (t) => {
+  const typeError = t.throws(
+    () => throwTypeError('Expected a string', 'ERR_TYPE'),
+    {
+      code: 'ERR_TYPE',
+      instanceOf: TypeError,
+      message: 'Expected a string',
+    },
+  )
+  t.is(typeError?.name, 'TypeError')
+  const rangeError = t.throws(() => throwRangeError('Index out of range'), {
+    instanceOf: RangeError,
+    message: 'Index out of range',
+  })
+  t.is(rangeError?.name, 'RangeError')
+})
+
 test('type', (t) => {
   const rule: Rule = {
     name: 'rule',
@@ -1679,6 +3428,62 @@ test('create readable stream from channel', async (t) => {
   t.is(Buffer.concat(chunksFromClass).toString('utf-8'), 'hello'.repeat(100))
 })
 
+test('async fn returning Stream is an async iterator', async (t) => {
+  const numbers = []
+  for await (const n of await countUpStream(5)) {
+    numbers.push(n)
+  }
+  t.deepEqual(numbers, [0, 1, 2, 3, 4])
+  const failed = []
+  await t.throwsAsync(
+    async () => {
+      for await (const n of await countUpStream(5, 2)) {
+        failed.push(n)
+      }
+    },
+    { message: 'Failed at 2' },
+  )
+  t.deepEqual(failed, [0, 1])
+  const counter = new PullCounter()
+  const iterator = await counter.numbers(10)
+  t.is(iterator[Symbol.asyncIterator](), iterator)
+  // items are pulled only when `next()` is called
+  t.is(counter.pulled, 0)
+  t.deepEqual(await iterator.next(), { value: 0, done: false })
+  t.is(counter.pulled, 1)
+  t.deepEqual(
+    await Promise.all([iterator.next(), iterator.next(), iterator.next()]),
+    [1, 2, 3].map((value) => ({ value, done: false })),
+  )
+  for await (const n of iterator) {
+    if (n === 5) {
+      break
+    }
+  }
+  t.is(counter.pulled, 6)
+  t.deepEqual(await iterator.next(), { value: undefined, done: true })
+  t.is(counter.pulled, 6)
+})
+
+test('async iterator methods only work on the async iterator', async (t) => {
+  const iterator = await new PullCounter().numbers(3)
+  const incompatible = {
+    instanceOf: TypeError,
+    message:
+      'The iterator method is called on an object which is not the iterator',
+  }
+  await t.throwsAsync(
+    async () => iterator.next.call(new PullCounter()),
+    incompatible,
+  )
+  await t.throwsAsync(async () => iterator.return!.call({}), incompatible)
+  const numbers = []
+  for await (const n of iterator) {
+    numbers.push(n)
+  }
+  t.deepEqual(numbers, [0, 1, 2])
+})
+
 test('spawnThreadInThread should be fine', async (t) => {
   await new Promise((resolve, reject) => {
     spawnThreadInThread((err, num) => {
@@ -1745,3 +3550,11 @@ test('escapable handle scope', (t) => {
     shorterEscapableScope(makeIterFunction())
   })
 })
+
+test('Env::with_escapable_scope', (t) => {
+  t.deepEqual(sumInEscapableScope(1000), { sum: 499500 })
+  t.deepEqual(sumInEscapableScope(0), { sum: 0 })
+  t.throws(() => escapeTwice(), {
+    code: 'EscapeCalledTwice',
+  })
+})
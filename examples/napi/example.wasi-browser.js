@@ -70,6 +70,7 @@ export const Asset = __napiModule.exports.Asset
 export const JsAsset = __napiModule.exports.JsAsset
 export const Assets = __napiModule.exports.Assets
 export const JsAssets = __napiModule.exports.JsAssets
+export const AsyncFinalize = __napiModule.exports.AsyncFinalize
 export const Bird = __napiModule.exports.Bird
 export const Blake2BHasher = __napiModule.exports.Blake2BHasher
 export const Blake2bHasher = __napiModule.exports.Blake2bHasher
@@ -77,6 +78,7 @@ export const Blake2BKey = __napiModule.exports.Blake2BKey
 export const Blake2bKey = __napiModule.exports.Blake2bKey
 export const CatchOnConstructor = __napiModule.exports.CatchOnConstructor
 export const CatchOnConstructor2 = __napiModule.exports.CatchOnConstructor2
+export const Chart = __napiModule.exports.Chart
 export const ClassInArray = __napiModule.exports.ClassInArray
 export const ClassWithFactory = __napiModule.exports.ClassWithFactory
 export const ClassWithLifetime = __napiModule.exports.ClassWithLifetime
@@ -89,21 +91,31 @@ export const CustomFinalize = __napiModule.exports.CustomFinalize
 export const CustomStruct = __napiModule.exports.CustomStruct
 export const DefaultUseNullableClass = __napiModule.exports.DefaultUseNullableClass
 export const Dog = __napiModule.exports.Dog
+export const FailedFinalize = __napiModule.exports.FailedFinalize
 export const Fib = __napiModule.exports.Fib
 export const Fib2 = __napiModule.exports.Fib2
 export const Fib3 = __napiModule.exports.Fib3
 export const GetterSetterWithClosures = __napiModule.exports.GetterSetterWithClosures
+export const Graph = __napiModule.exports.Graph
+export const GraphNode = __napiModule.exports.GraphNode
+export const ImmutableBytes = __napiModule.exports.ImmutableBytes
+export const InspectablePoint = __napiModule.exports.InspectablePoint
 export const JsClassForEither = __napiModule.exports.JsClassForEither
 export const JsRemote = __napiModule.exports.JsRemote
 export const JsRepo = __napiModule.exports.JsRepo
+export const LeakProbe = __napiModule.exports.LeakProbe
 export const NinjaTurtle = __napiModule.exports.NinjaTurtle
 export const NotUseNullableClass = __napiModule.exports.NotUseNullableClass
 export const NotWritableClass = __napiModule.exports.NotWritableClass
 export const Optional = __napiModule.exports.Optional
 export const PackageJsonReader = __napiModule.exports.PackageJsonReader
+export const PersistentValue = __napiModule.exports.PersistentValue
+export const PullCounter = __napiModule.exports.PullCounter
 export const Reader = __napiModule.exports.Reader
 export const Selector = __napiModule.exports.Selector
+export const SharedCounter = __napiModule.exports.SharedCounter
 export const UseNullableClass = __napiModule.exports.UseNullableClass
+export const VisitCounter = __napiModule.exports.VisitCounter
 export const Width = __napiModule.exports.Width
 export const acceptArraybuffer = __napiModule.exports.acceptArraybuffer
 export const acceptSlice = __napiModule.exports.acceptSlice
@@ -114,12 +126,15 @@ export const acceptThreadsafeFunctionTupleArgs = __napiModule.exports.acceptThre
 export const acceptUint8ClampedSlice = __napiModule.exports.acceptUint8ClampedSlice
 export const acceptUint8ClampedSliceAndBufferSlice = __napiModule.exports.acceptUint8ClampedSliceAndBufferSlice
 export const add = __napiModule.exports.add
+export const adjustExternalMemory = __napiModule.exports.adjustExternalMemory
 export const ALIAS = __napiModule.exports.ALIAS
 export const AliasedEnum = __napiModule.exports.AliasedEnum
 export const appendBuffer = __napiModule.exports.appendBuffer
 export const apply0 = __napiModule.exports.apply0
 export const apply1 = __napiModule.exports.apply1
+export const applyWithSpreadArgs = __napiModule.exports.applyWithSpreadArgs
 export const arrayBufferPassThrough = __napiModule.exports.arrayBufferPassThrough
+export const assignAnimalToSymbol = __napiModule.exports.assignAnimalToSymbol
 export const asyncBufferToArray = __napiModule.exports.asyncBufferToArray
 export const asyncMultiTwo = __napiModule.exports.asyncMultiTwo
 export const asyncPlus100 = __napiModule.exports.asyncPlus100
@@ -127,10 +142,15 @@ export const asyncReduceBuffer = __napiModule.exports.asyncReduceBuffer
 export const asyncTaskOptionalReturn = __napiModule.exports.asyncTaskOptionalReturn
 export const asyncTaskReadFile = __napiModule.exports.asyncTaskReadFile
 export const asyncTaskVoidReturn = __napiModule.exports.asyncTaskVoidReturn
+export const bigint64ArrayRoundTrip = __napiModule.exports.bigint64ArrayRoundTrip
 export const bigintAdd = __napiModule.exports.bigintAdd
 export const bigintFromI128 = __napiModule.exports.bigintFromI128
 export const bigintFromI64 = __napiModule.exports.bigintFromI64
 export const bigintGetU64AsString = __napiModule.exports.bigintGetU64AsString
+export const bigintI128RoundTrip = __napiModule.exports.bigintI128RoundTrip
+export const bigintU128RoundTrip = __napiModule.exports.bigintU128RoundTrip
+export const biguint64ArrayRoundTrip = __napiModule.exports.biguint64ArrayRoundTrip
+export const bindRepoDir = __napiModule.exports.bindRepoDir
 export const btreeSetToJs = __napiModule.exports.btreeSetToJs
 export const btreeSetToRust = __napiModule.exports.btreeSetToRust
 export const bufferPassThrough = __napiModule.exports.bufferPassThrough
@@ -140,6 +160,7 @@ export const buildThreadsafeFunctionFromFunctionCalleeHandle = __napiModule.expo
 export const call0 = __napiModule.exports.call0
 export const call1 = __napiModule.exports.call1
 export const call2 = __napiModule.exports.call2
+export const callAndRecoverExceptions = __napiModule.exports.callAndRecoverExceptions
 export const callAsyncWithUnknownReturnValue = __napiModule.exports.callAsyncWithUnknownReturnValue
 export const callbackReturnPromise = __napiModule.exports.callbackReturnPromise
 export const callbackReturnPromiseAndSpawn = __napiModule.exports.callbackReturnPromiseAndSpawn
@@ -152,6 +173,8 @@ export const callLongThreadsafeFunction = __napiModule.exports.callLongThreadsaf
 export const callRuleHandler = __napiModule.exports.callRuleHandler
 export const callThenOnPromise = __napiModule.exports.callThenOnPromise
 export const callThreadsafeFunction = __napiModule.exports.callThreadsafeFunction
+export const callWithFastPaths = __napiModule.exports.callWithFastPaths
+export const callWithSpreadArgs = __napiModule.exports.callWithSpreadArgs
 export const captureErrorInCallback = __napiModule.exports.captureErrorInCallback
 export const chronoDateAdd1Minute = __napiModule.exports.chronoDateAdd1Minute
 export const chronoDateFixtureReturn1 = __napiModule.exports.chronoDateFixtureReturn1
@@ -163,36 +186,69 @@ export const chronoLocalDateToMillis = __napiModule.exports.chronoLocalDateToMil
 export const chronoNativeDateTime = __napiModule.exports.chronoNativeDateTime
 export const chronoNativeDateTimeReturn = __napiModule.exports.chronoNativeDateTimeReturn
 export const chronoUtcDateReturn = __napiModule.exports.chronoUtcDateReturn
+export const chronoUtcDateRoundTrip = __napiModule.exports.chronoUtcDateRoundTrip
 export const chronoUtcDateToMillis = __napiModule.exports.chronoUtcDateToMillis
+export const chronoUtcDateWithSubMillis = __napiModule.exports.chronoUtcDateWithSubMillis
+export const classInstanceEnvName = __napiModule.exports.classInstanceEnvName
+export const coalesceProgress = __napiModule.exports.coalesceProgress
+export const collectFinalizeErrors = __napiModule.exports.collectFinalizeErrors
+export const collectNextFinalizeError = __napiModule.exports.collectNextFinalizeError
+export const compareAnimalHandles = __napiModule.exports.compareAnimalHandles
+export const compareAnimals = __napiModule.exports.compareAnimals
 export const concatLatin1 = __napiModule.exports.concatLatin1
 export const concatStr = __napiModule.exports.concatStr
 export const concatUtf16 = __napiModule.exports.concatUtf16
 export const contains = __napiModule.exports.contains
 export const convertU32Array = __napiModule.exports.convertU32Array
+export const countLoneSurrogates = __napiModule.exports.countLoneSurrogates
+export const countUntilAborted = __napiModule.exports.countUntilAborted
+export const countUpStream = __napiModule.exports.countUpStream
+export const countWithAnySignal = __napiModule.exports.countWithAnySignal
+export const countWithProgress = __napiModule.exports.countWithProgress
+export const countWords = __napiModule.exports.countWords
 export const createArraybuffer = __napiModule.exports.createArraybuffer
+export const createArrayOfSquares = __napiModule.exports.createArrayOfSquares
 export const createBigInt = __napiModule.exports.createBigInt
 export const createBigIntI64 = __napiModule.exports.createBigIntI64
 export const createBufferSliceFromCopiedData = __napiModule.exports.createBufferSliceFromCopiedData
+export const createErrorWithCode = __napiModule.exports.createErrorWithCode
 export const createExternal = __napiModule.exports.createExternal
 export const createExternalBufferSlice = __napiModule.exports.createExternalBufferSlice
 export const createExternalString = __napiModule.exports.createExternalString
 export const createExternalTypedArray = __napiModule.exports.createExternalTypedArray
+export const createFrozenConfig = __napiModule.exports.createFrozenConfig
 export const createObj = __napiModule.exports.createObj
 export const createObjectWithClassField = __napiModule.exports.createObjectWithClassField
+export const createObjectWithGetterClosure = __napiModule.exports.createObjectWithGetterClosure
 export const createObjWithProperty = __napiModule.exports.createObjWithProperty
 export const createOptionalExternal = __napiModule.exports.createOptionalExternal
+export const createPacketHeader = __napiModule.exports.createPacketHeader
 export const createReadableStream = __napiModule.exports.createReadableStream
 export const createReadableStreamFromClass = __napiModule.exports.createReadableStreamFromClass
 export const createReferenceOnFunction = __napiModule.exports.createReferenceOnFunction
+export const createSealedConfig = __napiModule.exports.createSealedConfig
+export const createSharedArrayBuffer = __napiModule.exports.createSharedArrayBuffer
 export const createSymbol = __napiModule.exports.createSymbol
 export const createSymbolFor = __napiModule.exports.createSymbolFor
+export const createSymbolForKey = __napiModule.exports.createSymbolForKey
+export const createUniqueSet = __napiModule.exports.createUniqueSet
 export const CustomNumEnum = __napiModule.exports.CustomNumEnum
 export const customStatusCode = __napiModule.exports.customStatusCode
 export const CustomStringEnum = __napiModule.exports.CustomStringEnum
 export const dateToNumber = __napiModule.exports.dateToNumber
+export const decodeLatin1 = __napiModule.exports.decodeLatin1
+export const dedupStrictEqual = __napiModule.exports.dedupStrictEqual
 export const DEFAULT_COST = __napiModule.exports.DEFAULT_COST
 export const derefUint8Array = __napiModule.exports.derefUint8Array
+export const describeMapEntries = __napiModule.exports.describeMapEntries
+export const describeUnknown = __napiModule.exports.describeUnknown
+export const detachArraybuffer = __napiModule.exports.detachArraybuffer
+export const downcastBirdName = __napiModule.exports.downcastBirdName
+export const dropNativeLabel = __napiModule.exports.dropNativeLabel
+export const dropRefAfterUnref = __napiModule.exports.dropRefAfterUnref
+export const dropResolverFromThread = __napiModule.exports.dropResolverFromThread
 export const either3 = __napiModule.exports.either3
+export const either3Variant = __napiModule.exports.either3Variant
 export const either4 = __napiModule.exports.either4
 export const eitherBoolOrFunction = __napiModule.exports.eitherBoolOrFunction
 export const eitherBoolOrTuple = __napiModule.exports.eitherBoolOrTuple
@@ -202,31 +258,49 @@ export const eitherFromOption = __napiModule.exports.eitherFromOption
 export const eitherPromiseInEitherA = __napiModule.exports.eitherPromiseInEitherA
 export const eitherStringOrNumber = __napiModule.exports.eitherStringOrNumber
 export const Empty = __napiModule.exports.Empty
+export const encodeLatin1 = __napiModule.exports.encodeLatin1
 export const enumToI32 = __napiModule.exports.enumToI32
 export const errorMessageContainsNullByte = __napiModule.exports.errorMessageContainsNullByte
+export const escapeTwice = __napiModule.exports.escapeTwice
 export const esmResolve = __napiModule.exports.esmResolve
 export const extendsJavascriptError = __napiModule.exports.extendsJavascriptError
 export const f32ArrayToArray = __napiModule.exports.f32ArrayToArray
 export const f64ArrayToArray = __napiModule.exports.f64ArrayToArray
 export const fibonacci = __napiModule.exports.fibonacci
+export const fillArraybufferAfter = __napiModule.exports.fillArraybufferAfter
+export const fillFloat32ArrayRange = __napiModule.exports.fillFloat32ArrayRange
+export const findVisitCounter = __napiModule.exports.findVisitCounter
 export const fnReceivedAliased = __napiModule.exports.fnReceivedAliased
 export const generateFunctionAndCallIt = __napiModule.exports.generateFunctionAndCallIt
+export const getAbortableSteps = __napiModule.exports.getAbortableSteps
+export const getAnimalNameLengthFromForeign = __napiModule.exports.getAnimalNameLengthFromForeign
 export const getBigintJsonValue = __napiModule.exports.getBigintJsonValue
+export const getBranchRef = __napiModule.exports.getBranchRef
 export const getBtreeMapping = __napiModule.exports.getBtreeMapping
 export const getBuffer = __napiModule.exports.getBuffer
+export const getBufferFromPool = __napiModule.exports.getBufferFromPool
+export const getBufferPoolCount = __napiModule.exports.getBufferPoolCount
 export const getBufferSlice = __napiModule.exports.getBufferSlice
+export const getBufferSliceWithSpareCapacity = __napiModule.exports.getBufferSliceWithSpareCapacity
 export const getClassFromArray = __napiModule.exports.getClassFromArray
 export const getCwd = __napiModule.exports.getCwd
+export const getDataviewByteOffset = __napiModule.exports.getDataviewByteOffset
+export const getDroppedBranches = __napiModule.exports.getDroppedBranches
+export const getDroppedPropertyClosures = __napiModule.exports.getDroppedPropertyClosures
+export const getDroppedVisitCounters = __napiModule.exports.getDroppedVisitCounters
 export const getEmptyBuffer = __napiModule.exports.getEmptyBuffer
 export const getEmptyTypedArray = __napiModule.exports.getEmptyTypedArray
 export const getExternal = __napiModule.exports.getExternal
 export const getGlobal = __napiModule.exports.getGlobal
+export const getGlobalObject = __napiModule.exports.getGlobalObject
 export const getIndexMapping = __napiModule.exports.getIndexMapping
 export const getIndexMappingWithHasher = __napiModule.exports.getIndexMappingWithHasher
 export const getMapping = __napiModule.exports.getMapping
 export const getMappingWithHasher = __napiModule.exports.getMappingWithHasher
 export const getModuleFileName = __napiModule.exports.getModuleFileName
 export const getMyVec = __napiModule.exports.getMyVec
+export const getNativeAllocations = __napiModule.exports.getNativeAllocations
+export const getNativeLabel = __napiModule.exports.getNativeLabel
 export const getNestedNumArr = __napiModule.exports.getNestedNumArr
 export const getNull = __napiModule.exports.getNull
 export const getNullByteProperty = __napiModule.exports.getNullByteProperty
@@ -234,35 +308,67 @@ export const getNumArr = __napiModule.exports.getNumArr
 export const getNums = __napiModule.exports.getNums
 export const getOptionalExternal = __napiModule.exports.getOptionalExternal
 export const getPackageJsonName = __napiModule.exports.getPackageJsonName
+export const getPooledBuffers = __napiModule.exports.getPooledBuffers
+export const getProcessEnvVar = __napiModule.exports.getProcessEnvVar
+export const getPropertyDescriptor = __napiModule.exports.getPropertyDescriptor
+export const getSetValues = __napiModule.exports.getSetValues
 export const getStrFromObject = __napiModule.exports.getStrFromObject
+export const getSymbolDescription = __napiModule.exports.getSymbolDescription
 export const getterFromObj = __napiModule.exports.getterFromObj
 export const getTuple = __napiModule.exports.getTuple
 export const getUndefined = __napiModule.exports.getUndefined
 export const getWords = __napiModule.exports.getWords
+export const getZeroCopyBuffer = __napiModule.exports.getZeroCopyBuffer
+export const hashBuffer = __napiModule.exports.hashBuffer
 export const i16ArrayToArray = __napiModule.exports.i16ArrayToArray
 export const i32ArrayToArray = __napiModule.exports.i32ArrayToArray
 export const i64ArrayToArray = __napiModule.exports.i64ArrayToArray
 export const i8ArrayToArray = __napiModule.exports.i8ArrayToArray
 export const indexmapPassthrough = __napiModule.exports.indexmapPassthrough
+export const installCountingAllocator = __napiModule.exports.installCountingAllocator
+export const isTypeTaggedObject = __napiModule.exports.isTypeTaggedObject
+export const joinRestArgs = __napiModule.exports.joinRestArgs
 export const jsErrorCallback = __napiModule.exports.jsErrorCallback
+export const jsonParseValue = __napiModule.exports.jsonParseValue
+export const jsonStringifyValue = __napiModule.exports.jsonStringifyValue
 export const Kind = __napiModule.exports.Kind
 export const KindInValidate = __napiModule.exports.KindInValidate
+export const LAZY_COST = __napiModule.exports.LAZY_COST
+export const lazyAdd = __napiModule.exports.lazyAdd
 export const listObjKeys = __napiModule.exports.listObjKeys
+export const listObjPropertyNames = __napiModule.exports.listObjPropertyNames
+export const liveClassInstanceCount = __napiModule.exports.liveClassInstanceCount
+export const liveFailedFinalizeCount = __napiModule.exports.liveFailedFinalizeCount
+export const liveLeakProbeCount = __napiModule.exports.liveLeakProbeCount
+export const looseEqualsValues = __napiModule.exports.looseEqualsValues
 export const mapOption = __napiModule.exports.mapOption
+export const matrix4Trace = __napiModule.exports.matrix4Trace
+export const mergeObjects = __napiModule.exports.mergeObjects
 export const mergeTupleArray = __napiModule.exports.mergeTupleArray
 export const mutateExternal = __napiModule.exports.mutateExternal
+export const mutateFloat64Array = __napiModule.exports.mutateFloat64Array
 export const mutateOptionalExternal = __napiModule.exports.mutateOptionalExternal
 export const mutateTypedArray = __napiModule.exports.mutateTypedArray
 export const objectGetNamedPropertyShouldPerformTypecheck = __napiModule.exports.objectGetNamedPropertyShouldPerformTypecheck
+export const objectHasNamedProperty = __napiModule.exports.objectHasNamedProperty
+export const objectHasOwnProperty = __napiModule.exports.objectHasOwnProperty
+export const onObjectCollected = __napiModule.exports.onObjectCollected
 export const optionEnd = __napiModule.exports.optionEnd
 export const optionOnly = __napiModule.exports.optionOnly
 export const optionStart = __napiModule.exports.optionStart
 export const optionStartEnd = __napiModule.exports.optionStartEnd
 export const overrideIndividualArgOnFunction = __napiModule.exports.overrideIndividualArgOnFunction
 export const overrideIndividualArgOnFunctionWithCbArg = __napiModule.exports.overrideIndividualArgOnFunctionWithCbArg
+export const overrideUnknownReturnType = __napiModule.exports.overrideUnknownReturnType
 export const overrideWholeFunctionType = __napiModule.exports.overrideWholeFunctionType
 export const panic = __napiModule.exports.panic
 export const panicInAsync = __napiModule.exports.panicInAsync
+export const panicWithFormattedMessage = __napiModule.exports.panicWithFormattedMessage
+export const panicWithoutCatchUnwind = __napiModule.exports.panicWithoutCatchUnwind
+export const parseConfig = __napiModule.exports.parseConfig
+export const parseNumbers = __napiModule.exports.parseNumbers
+export const parsePacketHeader = __napiModule.exports.parsePacketHeader
+export const parseWithAnyhow = __napiModule.exports.parseWithAnyhow
 export const passSetToJs = __napiModule.exports.passSetToJs
 export const passSetToRust = __napiModule.exports.passSetToRust
 export const passSetWithHasherToJs = __napiModule.exports.passSetWithHasherToJs
@@ -281,30 +387,58 @@ export const receiveObjectWithClassField = __napiModule.exports.receiveObjectWit
 export const receiveStrictObject = __napiModule.exports.receiveStrictObject
 export const receiveString = __napiModule.exports.receiveString
 export const referenceAsCallback = __napiModule.exports.referenceAsCallback
+export const releaseTsfnInCallback = __napiModule.exports.releaseTsfnInCallback
+export const resetVisitCounter = __napiModule.exports.resetVisitCounter
+export const resolveFromThread = __napiModule.exports.resolveFromThread
 export const returnCString = __napiModule.exports.returnCString
 export const returnEither = __napiModule.exports.returnEither
 export const returnEitherClass = __napiModule.exports.returnEitherClass
 export const returnFromSharedCrate = __napiModule.exports.returnFromSharedCrate
+export const returnLatin1Bytes = __napiModule.exports.returnLatin1Bytes
 export const returnNull = __napiModule.exports.returnNull
 export const returnObjectOnlyToJs = __napiModule.exports.returnObjectOnlyToJs
+export const returnStrContainsNullByte = __napiModule.exports.returnStrContainsNullByte
 export const returnUndefined = __napiModule.exports.returnUndefined
 export const returnUndefinedIfInvalid = __napiModule.exports.returnUndefinedIfInvalid
 export const returnUndefinedIfInvalidPromise = __napiModule.exports.returnUndefinedIfInvalidPromise
+export const returnUtf8Bytes = __napiModule.exports.returnUtf8Bytes
+export const rgbaToHex = __napiModule.exports.rgbaToHex
 export const roundtripStr = __napiModule.exports.roundtripStr
+export const roundTripTuple = __napiModule.exports.roundTripTuple
 export const runScript = __napiModule.exports.runScript
+export const runScriptWithFilename = __napiModule.exports.runScriptWithFilename
+export const serializeLabeledObject = __napiModule.exports.serializeLabeledObject
+export const serializeLabeledObjectWithSortedKeys = __napiModule.exports.serializeLabeledObjectWithSortedKeys
 export const setNullByteProperty = __napiModule.exports.setNullByteProperty
 export const setSymbolInObj = __napiModule.exports.setSymbolInObj
 export const shorterEscapableScope = __napiModule.exports.shorterEscapableScope
 export const shorterScope = __napiModule.exports.shorterScope
 export const shutdownRuntime = __napiModule.exports.shutdownRuntime
+export const spawnOnRuntimeHandle = __napiModule.exports.spawnOnRuntimeHandle
 export const spawnThreadInThread = __napiModule.exports.spawnThreadInThread
+export const squareEvenNumbers = __napiModule.exports.squareEvenNumbers
 export const Status = __napiModule.exports.Status
 export const StatusInValidate = __napiModule.exports.StatusInValidate
+export const strictEqualsValues = __napiModule.exports.strictEqualsValues
 export const StringEnum = __napiModule.exports.StringEnum
+export const stringifyInEnvScope = __napiModule.exports.stringifyInEnvScope
+export const structuredCloneValue = __napiModule.exports.structuredCloneValue
+export const subarrayOfRustData = __napiModule.exports.subarrayOfRustData
+export const sumAllPromises = __napiModule.exports.sumAllPromises
+export const sumArraybufferAfter = __napiModule.exports.sumArraybufferAfter
+export const sumArrayToVec = __napiModule.exports.sumArrayToVec
 export const sumBtreeMapping = __napiModule.exports.sumBtreeMapping
 export const sumIndexMapping = __napiModule.exports.sumIndexMapping
+export const sumInEscapableScope = __napiModule.exports.sumInEscapableScope
 export const sumMapping = __napiModule.exports.sumMapping
+export const sumMapValues = __napiModule.exports.sumMapValues
 export const sumNums = __napiModule.exports.sumNums
+export const sumObjectValues = __napiModule.exports.sumObjectValues
+export const sumRestArgs = __napiModule.exports.sumRestArgs
+export const sumSharedArrayBuffer = __napiModule.exports.sumSharedArrayBuffer
+export const takeExternalString = __napiModule.exports.takeExternalString
+export const takeFinalizeErrors = __napiModule.exports.takeFinalizeErrors
+export const takeMapValue = __napiModule.exports.takeMapValue
 export const testSerdeBigNumberPrecision = __napiModule.exports.testSerdeBigNumberPrecision
 export const testSerdeBufferBytes = __napiModule.exports.testSerdeBufferBytes
 export const testSerdeRoundtrip = __napiModule.exports.testSerdeRoundtrip
@@ -316,21 +450,36 @@ export const threadsafeFunctionThrowError = __napiModule.exports.threadsafeFunct
 export const threadsafeFunctionThrowErrorWithStatus = __napiModule.exports.threadsafeFunctionThrowErrorWithStatus
 export const throwAsyncError = __napiModule.exports.throwAsyncError
 export const throwError = __napiModule.exports.throwError
+export const throwErrorWithCause = __napiModule.exports.throwErrorWithCause
+export const throwInEnvScope = __napiModule.exports.throwInEnvScope
+export const throwRangeError = __napiModule.exports.throwRangeError
 export const throwSyntaxError = __napiModule.exports.throwSyntaxError
+export const throwTypeError = __napiModule.exports.throwTypeError
+export const toggleSetValue = __napiModule.exports.toggleSetValue
 export const toJsObj = __napiModule.exports.toJsObj
+export const toTransferableValue = __napiModule.exports.toTransferableValue
+export const transferValueThroughThread = __napiModule.exports.transferValueThroughThread
+export const tryFindVisitCounter = __napiModule.exports.tryFindVisitCounter
 export const tsfnAsyncCall = __napiModule.exports.tsfnAsyncCall
+export const tsfnAsyncCallFatalMode = __napiModule.exports.tsfnAsyncCallFatalMode
 export const tsfnCallWithCallback = __napiModule.exports.tsfnCallWithCallback
+export const tsfnCallWithTimeout = __napiModule.exports.tsfnCallWithTimeout
 export const tsfnInEither = __napiModule.exports.tsfnInEither
 export const tsfnReturnPromise = __napiModule.exports.tsfnReturnPromise
 export const tsfnReturnPromiseTimeout = __napiModule.exports.tsfnReturnPromiseTimeout
 export const tsfnThrowFromJs = __napiModule.exports.tsfnThrowFromJs
 export const tsfnThrowFromJsCallbackContainsTsfn = __napiModule.exports.tsfnThrowFromJsCallbackContainsTsfn
 export const tsRename = __napiModule.exports.tsRename
+export const typeTagObject = __napiModule.exports.typeTagObject
 export const u16ArrayToArray = __napiModule.exports.u16ArrayToArray
 export const u32ArrayToArray = __napiModule.exports.u32ArrayToArray
 export const u64ArrayToArray = __napiModule.exports.u64ArrayToArray
 export const u8ArrayToArray = __napiModule.exports.u8ArrayToArray
 export const uInit8ArrayFromString = __napiModule.exports.uInit8ArrayFromString
+export const uint8ArraySubarray = __napiModule.exports.uint8ArraySubarray
+export const upgradeRepo = __napiModule.exports.upgradeRepo
+export const upgradeRepoDir = __napiModule.exports.upgradeRepoDir
+export const utf16CodeUnits = __napiModule.exports.utf16CodeUnits
 export const validateArray = __napiModule.exports.validateArray
 export const validateBigint = __napiModule.exports.validateBigint
 export const validateBoolean = __napiModule.exports.validateBoolean
@@ -354,9 +503,11 @@ export const validateTypedArray = __napiModule.exports.validateTypedArray
 export const validateTypedArraySlice = __napiModule.exports.validateTypedArraySlice
 export const validateUint8ClampedSlice = __napiModule.exports.validateUint8ClampedSlice
 export const validateUndefined = __napiModule.exports.validateUndefined
+export const validateUsername = __napiModule.exports.validateUsername
 export const withAbortController = __napiModule.exports.withAbortController
 export const withinAsyncRuntimeIfAvailable = __napiModule.exports.withinAsyncRuntimeIfAvailable
 export const withoutAbortController = __napiModule.exports.withoutAbortController
+export const wrapNativeLabel = __napiModule.exports.wrapNativeLabel
 export const xxh64Alias = __napiModule.exports.xxh64Alias
 export const xxh2 = __napiModule.exports.xxh2
 export const xxh3 = __napiModule.exports.xxh3
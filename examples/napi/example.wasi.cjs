@@ -94,6 +94,7 @@ module.exports.Asset = __napiModule.exports.Asset
 module.exports.JsAsset = __napiModule.exports.JsAsset
 module.exports.Assets = __napiModule.exports.Assets
 module.exports.JsAssets = __napiModule.exports.JsAssets
+module.exports.AsyncFinalize = __napiModule.exports.AsyncFinalize
 module.exports.Bird = __napiModule.exports.Bird
 module.exports.Blake2BHasher = __napiModule.exports.Blake2BHasher
 module.exports.Blake2bHasher = __napiModule.exports.Blake2bHasher
@@ -101,6 +102,7 @@ module.exports.Blake2BKey = __napiModule.exports.Blake2BKey
 module.exports.Blake2bKey = __napiModule.exports.Blake2bKey
 module.exports.CatchOnConstructor = __napiModule.exports.CatchOnConstructor
 module.exports.CatchOnConstructor2 = __napiModule.exports.CatchOnConstructor2
+module.exports.Chart = __napiModule.exports.Chart
 module.exports.ClassInArray = __napiModule.exports.ClassInArray
 module.exports.ClassWithFactory = __napiModule.exports.ClassWithFactory
 module.exports.ClassWithLifetime = __napiModule.exports.ClassWithLifetime
@@ -113,21 +115,31 @@ module.exports.CustomFinalize = __napiModule.exports.CustomFinalize
 module.exports.CustomStruct = __napiModule.exports.CustomStruct
 module.exports.DefaultUseNullableClass = __napiModule.exports.DefaultUseNullableClass
 module.exports.Dog = __napiModule.exports.Dog
+module.exports.FailedFinalize = __napiModule.exports.FailedFinalize
 module.exports.Fib = __napiModule.exports.Fib
 module.exports.Fib2 = __napiModule.exports.Fib2
 module.exports.Fib3 = __napiModule.exports.Fib3
 module.exports.GetterSetterWithClosures = __napiModule.exports.GetterSetterWithClosures
+module.exports.Graph = __napiModule.exports.Graph
+module.exports.GraphNode = __napiModule.exports.GraphNode
+module.exports.ImmutableBytes = __napiModule.exports.ImmutableBytes
+module.exports.InspectablePoint = __napiModule.exports.InspectablePoint
 module.exports.JsClassForEither = __napiModule.exports.JsClassForEither
 module.exports.JsRemote = __napiModule.exports.JsRemote
 module.exports.JsRepo = __napiModule.exports.JsRepo
+module.exports.LeakProbe = __napiModule.exports.LeakProbe
 module.exports.NinjaTurtle = __napiModule.exports.NinjaTurtle
 module.exports.NotUseNullableClass = __napiModule.exports.NotUseNullableClass
 module.exports.NotWritableClass = __napiModule.exports.NotWritableClass
 module.exports.Optional = __napiModule.exports.Optional
 module.exports.PackageJsonReader = __napiModule.exports.PackageJsonReader
+module.exports.PersistentValue = __napiModule.exports.PersistentValue
+module.exports.PullCounter = __napiModule.exports.PullCounter
 module.exports.Reader = __napiModule.exports.Reader
 module.exports.Selector = __napiModule.exports.Selector
+module.exports.SharedCounter = __napiModule.exports.SharedCounter
 module.exports.UseNullableClass = __napiModule.exports.UseNullableClass
+module.exports.VisitCounter = __napiModule.exports.VisitCounter
 module.exports.Width = __napiModule.exports.Width
 module.exports.acceptArraybuffer = __napiModule.exports.acceptArraybuffer
 module.exports.acceptSlice = __napiModule.exports.acceptSlice
@@ -138,12 +150,15 @@ module.exports.acceptThreadsafeFunctionTupleArgs = __napiModule.exports.acceptTh
 module.exports.acceptUint8ClampedSlice = __napiModule.exports.acceptUint8ClampedSlice
 module.exports.acceptUint8ClampedSliceAndBufferSlice = __napiModule.exports.acceptUint8ClampedSliceAndBufferSlice
 module.exports.add = __napiModule.exports.add
+module.exports.adjustExternalMemory = __napiModule.exports.adjustExternalMemory
 module.exports.ALIAS = __napiModule.exports.ALIAS
 module.exports.AliasedEnum = __napiModule.exports.AliasedEnum
 module.exports.appendBuffer = __napiModule.exports.appendBuffer
 module.exports.apply0 = __napiModule.exports.apply0
 module.exports.apply1 = __napiModule.exports.apply1
+module.exports.applyWithSpreadArgs = __napiModule.exports.applyWithSpreadArgs
 module.exports.arrayBufferPassThrough = __napiModule.exports.arrayBufferPassThrough
+module.exports.assignAnimalToSymbol = __napiModule.exports.assignAnimalToSymbol
 module.exports.asyncBufferToArray = __napiModule.exports.asyncBufferToArray
 module.exports.asyncMultiTwo = __napiModule.exports.asyncMultiTwo
 module.exports.asyncPlus100 = __napiModule.exports.asyncPlus100
@@ -151,10 +166,15 @@ module.exports.asyncReduceBuffer = __napiModule.exports.asyncReduceBuffer
 module.exports.asyncTaskOptionalReturn = __napiModule.exports.asyncTaskOptionalReturn
 module.exports.asyncTaskReadFile = __napiModule.exports.asyncTaskReadFile
 module.exports.asyncTaskVoidReturn = __napiModule.exports.asyncTaskVoidReturn
+module.exports.bigint64ArrayRoundTrip = __napiModule.exports.bigint64ArrayRoundTrip
 module.exports.bigintAdd = __napiModule.exports.bigintAdd
 module.exports.bigintFromI128 = __napiModule.exports.bigintFromI128
 module.exports.bigintFromI64 = __napiModule.exports.bigintFromI64
 module.exports.bigintGetU64AsString = __napiModule.exports.bigintGetU64AsString
+module.exports.bigintI128RoundTrip = __napiModule.exports.bigintI128RoundTrip
+module.exports.bigintU128RoundTrip = __napiModule.exports.bigintU128RoundTrip
+module.exports.biguint64ArrayRoundTrip = __napiModule.exports.biguint64ArrayRoundTrip
+module.exports.bindRepoDir = __napiModule.exports.bindRepoDir
 module.exports.btreeSetToJs = __napiModule.exports.btreeSetToJs
 module.exports.btreeSetToRust = __napiModule.exports.btreeSetToRust
 module.exports.bufferPassThrough = __napiModule.exports.bufferPassThrough
@@ -164,6 +184,7 @@ module.exports.buildThreadsafeFunctionFromFunctionCalleeHandle = __napiModule.ex
 module.exports.call0 = __napiModule.exports.call0
 module.exports.call1 = __napiModule.exports.call1
 module.exports.call2 = __napiModule.exports.call2
+module.exports.callAndRecoverExceptions = __napiModule.exports.callAndRecoverExceptions
 module.exports.callAsyncWithUnknownReturnValue = __napiModule.exports.callAsyncWithUnknownReturnValue
 module.exports.callbackReturnPromise = __napiModule.exports.callbackReturnPromise
 module.exports.callbackReturnPromiseAndSpawn = __napiModule.exports.callbackReturnPromiseAndSpawn
@@ -176,6 +197,8 @@ module.exports.callLongThreadsafeFunction = __napiModule.exports.callLongThreads
 module.exports.callRuleHandler = __napiModule.exports.callRuleHandler
 module.exports.callThenOnPromise = __napiModule.exports.callThenOnPromise
 module.exports.callThreadsafeFunction = __napiModule.exports.callThreadsafeFunction
+module.exports.callWithFastPaths = __napiModule.exports.callWithFastPaths
+module.exports.callWithSpreadArgs = __napiModule.exports.callWithSpreadArgs
 module.exports.captureErrorInCallback = __napiModule.exports.captureErrorInCallback
 module.exports.chronoDateAdd1Minute = __napiModule.exports.chronoDateAdd1Minute
 module.exports.chronoDateFixtureReturn1 = __napiModule.exports.chronoDateFixtureReturn1
@@ -187,36 +210,69 @@ module.exports.chronoLocalDateToMillis = __napiModule.exports.chronoLocalDateToM
 module.exports.chronoNativeDateTime = __napiModule.exports.chronoNativeDateTime
 module.exports.chronoNativeDateTimeReturn = __napiModule.exports.chronoNativeDateTimeReturn
 module.exports.chronoUtcDateReturn = __napiModule.exports.chronoUtcDateReturn
+module.exports.chronoUtcDateRoundTrip = __napiModule.exports.chronoUtcDateRoundTrip
 module.exports.chronoUtcDateToMillis = __napiModule.exports.chronoUtcDateToMillis
+module.exports.chronoUtcDateWithSubMillis = __napiModule.exports.chronoUtcDateWithSubMillis
+module.exports.classInstanceEnvName = __napiModule.exports.classInstanceEnvName
+module.exports.coalesceProgress = __napiModule.exports.coalesceProgress
+module.exports.collectFinalizeErrors = __napiModule.exports.collectFinalizeErrors
+module.exports.collectNextFinalizeError = __napiModule.exports.collectNextFinalizeError
+module.exports.compareAnimalHandles = __napiModule.exports.compareAnimalHandles
+module.exports.compareAnimals = __napiModule.exports.compareAnimals
 module.exports.concatLatin1 = __napiModule.exports.concatLatin1
 module.exports.concatStr = __napiModule.exports.concatStr
 module.exports.concatUtf16 = __napiModule.exports.concatUtf16
 module.exports.contains = __napiModule.exports.contains
 module.exports.convertU32Array = __napiModule.exports.convertU32Array
+module.exports.countLoneSurrogates = __napiModule.exports.countLoneSurrogates
+module.exports.countUntilAborted = __napiModule.exports.countUntilAborted
+module.exports.countUpStream = __napiModule.exports.countUpStream
+module.exports.countWithAnySignal = __napiModule.exports.countWithAnySignal
+module.exports.countWithProgress = __napiModule.exports.countWithProgress
+module.exports.countWords = __napiModule.exports.countWords
 module.exports.createArraybuffer = __napiModule.exports.createArraybuffer
+module.exports.createArrayOfSquares = __napiModule.exports.createArrayOfSquares
 module.exports.createBigInt = __napiModule.exports.createBigInt
 module.exports.createBigIntI64 = __napiModule.exports.createBigIntI64
 module.exports.createBufferSliceFromCopiedData = __napiModule.exports.createBufferSliceFromCopiedData
+module.exports.createErrorWithCode = __napiModule.exports.createErrorWithCode
 module.exports.createExternal = __napiModule.exports.createExternal
 module.exports.createExternalBufferSlice = __napiModule.exports.createExternalBufferSlice
 module.exports.createExternalString = __napiModule.exports.createExternalString
 module.exports.createExternalTypedArray = __napiModule.exports.createExternalTypedArray
+module.exports.createFrozenConfig = __napiModule.exports.createFrozenConfig
 module.exports.createObj = __napiModule.exports.createObj
 module.exports.createObjectWithClassField = __napiModule.exports.createObjectWithClassField
+module.exports.createObjectWithGetterClosure = __napiModule.exports.createObjectWithGetterClosure
 module.exports.createObjWithProperty = __napiModule.exports.createObjWithProperty
 module.exports.createOptionalExternal = __napiModule.exports.createOptionalExternal
+module.exports.createPacketHeader = __napiModule.exports.createPacketHeader
 module.exports.createReadableStream = __napiModule.exports.createReadableStream
 module.exports.createReadableStreamFromClass = __napiModule.exports.createReadableStreamFromClass
 module.exports.createReferenceOnFunction = __napiModule.exports.createReferenceOnFunction
+module.exports.createSealedConfig = __napiModule.exports.createSealedConfig
+module.exports.createSharedArrayBuffer = __napiModule.exports.createSharedArrayBuffer
 module.exports.createSymbol = __napiModule.exports.createSymbol
 module.exports.createSymbolFor = __napiModule.exports.createSymbolFor
+module.exports.createSymbolForKey = __napiModule.exports.createSymbolForKey
+module.exports.createUniqueSet = __napiModule.exports.createUniqueSet
 module.exports.CustomNumEnum = __napiModule.exports.CustomNumEnum
 module.exports.customStatusCode = __napiModule.exports.customStatusCode
 module.exports.CustomStringEnum = __napiModule.exports.CustomStringEnum
 module.exports.dateToNumber = __napiModule.exports.dateToNumber
+module.exports.decodeLatin1 = __napiModule.exports.decodeLatin1
+module.exports.dedupStrictEqual = __napiModule.exports.dedupStrictEqual
 module.exports.DEFAULT_COST = __napiModule.exports.DEFAULT_COST
 module.exports.derefUint8Array = __napiModule.exports.derefUint8Array
+module.exports.describeMapEntries = __napiModule.exports.describeMapEntries
+module.exports.describeUnknown = __napiModule.exports.describeUnknown
+module.exports.detachArraybuffer = __napiModule.exports.detachArraybuffer
+module.exports.downcastBirdName = __napiModule.exports.downcastBirdName
+module.exports.dropNativeLabel = __napiModule.exports.dropNativeLabel
+module.exports.dropRefAfterUnref = __napiModule.exports.dropRefAfterUnref
+module.exports.dropResolverFromThread = __napiModule.exports.dropResolverFromThread
 module.exports.either3 = __napiModule.exports.either3
+module.exports.either3Variant = __napiModule.exports.either3Variant
 module.exports.either4 = __napiModule.exports.either4
 module.exports.eitherBoolOrFunction = __napiModule.exports.eitherBoolOrFunction
 module.exports.eitherBoolOrTuple = __napiModule.exports.eitherBoolOrTuple
@@ -226,31 +282,49 @@ module.exports.eitherFromOption = __napiModule.exports.eitherFromOption
 module.exports.eitherPromiseInEitherA = __napiModule.exports.eitherPromiseInEitherA
 module.exports.eitherStringOrNumber = __napiModule.exports.eitherStringOrNumber
 module.exports.Empty = __napiModule.exports.Empty
+module.exports.encodeLatin1 = __napiModule.exports.encodeLatin1
 module.exports.enumToI32 = __napiModule.exports.enumToI32
 module.exports.errorMessageContainsNullByte = __napiModule.exports.errorMessageContainsNullByte
+module.exports.escapeTwice = __napiModule.exports.escapeTwice
 module.exports.esmResolve = __napiModule.exports.esmResolve
 module.exports.extendsJavascriptError = __napiModule.exports.extendsJavascriptError
 module.exports.f32ArrayToArray = __napiModule.exports.f32ArrayToArray
 module.exports.f64ArrayToArray = __napiModule.exports.f64ArrayToArray
 module.exports.fibonacci = __napiModule.exports.fibonacci
+module.exports.fillArraybufferAfter = __napiModule.exports.fillArraybufferAfter
+module.exports.fillFloat32ArrayRange = __napiModule.exports.fillFloat32ArrayRange
+module.exports.findVisitCounter = __napiModule.exports.findVisitCounter
 module.exports.fnReceivedAliased = __napiModule.exports.fnReceivedAliased
 module.exports.generateFunctionAndCallIt = __napiModule.exports.generateFunctionAndCallIt
+module.exports.getAbortableSteps = __napiModule.exports.getAbortableSteps
+module.exports.getAnimalNameLengthFromForeign = __napiModule.exports.getAnimalNameLengthFromForeign
 module.exports.getBigintJsonValue = __napiModule.exports.getBigintJsonValue
+module.exports.getBranchRef = __napiModule.exports.getBranchRef
 module.exports.getBtreeMapping = __napiModule.exports.getBtreeMapping
 module.exports.getBuffer = __napiModule.exports.getBuffer
+module.exports.getBufferFromPool = __napiModule.exports.getBufferFromPool
+module.exports.getBufferPoolCount = __napiModule.exports.getBufferPoolCount
 module.exports.getBufferSlice = __napiModule.exports.getBufferSlice
+module.exports.getBufferSliceWithSpareCapacity = __napiModule.exports.getBufferSliceWithSpareCapacity
 module.exports.getClassFromArray = __napiModule.exports.getClassFromArray
 module.exports.getCwd = __napiModule.exports.getCwd
+module.exports.getDataviewByteOffset = __napiModule.exports.getDataviewByteOffset
+module.exports.getDroppedBranches = __napiModule.exports.getDroppedBranches
+module.exports.getDroppedPropertyClosures = __napiModule.exports.getDroppedPropertyClosures
+module.exports.getDroppedVisitCounters = __napiModule.exports.getDroppedVisitCounters
 module.exports.getEmptyBuffer = __napiModule.exports.getEmptyBuffer
 module.exports.getEmptyTypedArray = __napiModule.exports.getEmptyTypedArray
 module.exports.getExternal = __napiModule.exports.getExternal
 module.exports.getGlobal = __napiModule.exports.getGlobal
+module.exports.getGlobalObject = __napiModule.exports.getGlobalObject
 module.exports.getIndexMapping = __napiModule.exports.getIndexMapping
 module.exports.getIndexMappingWithHasher = __napiModule.exports.getIndexMappingWithHasher
 module.exports.getMapping = __napiModule.exports.getMapping
 module.exports.getMappingWithHasher = __napiModule.exports.getMappingWithHasher
 module.exports.getModuleFileName = __napiModule.exports.getModuleFileName
 module.exports.getMyVec = __napiModule.exports.getMyVec
+module.exports.getNativeAllocations = __napiModule.exports.getNativeAllocations
+module.exports.getNativeLabel = __napiModule.exports.getNativeLabel
 module.exports.getNestedNumArr = __napiModule.exports.getNestedNumArr
 module.exports.getNull = __napiModule.exports.getNull
 module.exports.getNullByteProperty = __napiModule.exports.getNullByteProperty
@@ -258,35 +332,67 @@ module.exports.getNumArr = __napiModule.exports.getNumArr
 module.exports.getNums = __napiModule.exports.getNums
 module.exports.getOptionalExternal = __napiModule.exports.getOptionalExternal
 module.exports.getPackageJsonName = __napiModule.exports.getPackageJsonName
+module.exports.getPooledBuffers = __napiModule.exports.getPooledBuffers
+module.exports.getProcessEnvVar = __napiModule.exports.getProcessEnvVar
+module.exports.getPropertyDescriptor = __napiModule.exports.getPropertyDescriptor
+module.exports.getSetValues = __napiModule.exports.getSetValues
 module.exports.getStrFromObject = __napiModule.exports.getStrFromObject
+module.exports.getSymbolDescription = __napiModule.exports.getSymbolDescription
 module.exports.getterFromObj = __napiModule.exports.getterFromObj
 module.exports.getTuple = __napiModule.exports.getTuple
 module.exports.getUndefined = __napiModule.exports.getUndefined
 module.exports.getWords = __napiModule.exports.getWords
+module.exports.getZeroCopyBuffer = __napiModule.exports.getZeroCopyBuffer
+module.exports.hashBuffer = __napiModule.exports.hashBuffer
 module.exports.i16ArrayToArray = __napiModule.exports.i16ArrayToArray
 module.exports.i32ArrayToArray = __napiModule.exports.i32ArrayToArray
 module.exports.i64ArrayToArray = __napiModule.exports.i64ArrayToArray
 module.exports.i8ArrayToArray = __napiModule.exports.i8ArrayToArray
 module.exports.indexmapPassthrough = __napiModule.exports.indexmapPassthrough
+module.exports.installCountingAllocator = __napiModule.exports.installCountingAllocator
+module.exports.isTypeTaggedObject = __napiModule.exports.isTypeTaggedObject
+module.exports.joinRestArgs = __napiModule.exports.joinRestArgs
 module.exports.jsErrorCallback = __napiModule.exports.jsErrorCallback
+module.exports.jsonParseValue = __napiModule.exports.jsonParseValue
+module.exports.jsonStringifyValue = __napiModule.exports.jsonStringifyValue
 module.exports.Kind = __napiModule.exports.Kind
 module.exports.KindInValidate = __napiModule.exports.KindInValidate
+module.exports.LAZY_COST = __napiModule.exports.LAZY_COST
+module.exports.lazyAdd = __napiModule.exports.lazyAdd
 module.exports.listObjKeys = __napiModule.exports.listObjKeys
+module.exports.listObjPropertyNames = __napiModule.exports.listObjPropertyNames
+module.exports.liveClassInstanceCount = __napiModule.exports.liveClassInstanceCount
+module.exports.liveFailedFinalizeCount = __napiModule.exports.liveFailedFinalizeCount
+module.exports.liveLeakProbeCount = __napiModule.exports.liveLeakProbeCount
+module.exports.looseEqualsValues = __napiModule.exports.looseEqualsValues
 module.exports.mapOption = __napiModule.exports.mapOption
+module.exports.matrix4Trace = __napiModule.exports.matrix4Trace
+module.exports.mergeObjects = __napiModule.exports.mergeObjects
 module.exports.mergeTupleArray = __napiModule.exports.mergeTupleArray
 module.exports.mutateExternal = __napiModule.exports.mutateExternal
+module.exports.mutateFloat64Array = __napiModule.exports.mutateFloat64Array
 module.exports.mutateOptionalExternal = __napiModule.exports.mutateOptionalExternal
 module.exports.mutateTypedArray = __napiModule.exports.mutateTypedArray
 module.exports.objectGetNamedPropertyShouldPerformTypecheck = __napiModule.exports.objectGetNamedPropertyShouldPerformTypecheck
+module.exports.objectHasNamedProperty = __napiModule.exports.objectHasNamedProperty
+module.exports.objectHasOwnProperty = __napiModule.exports.objectHasOwnProperty
+module.exports.onObjectCollected = __napiModule.exports.onObjectCollected
 module.exports.optionEnd = __napiModule.exports.optionEnd
 module.exports.optionOnly = __napiModule.exports.optionOnly
 module.exports.optionStart = __napiModule.exports.optionStart
 module.exports.optionStartEnd = __napiModule.exports.optionStartEnd
 module.exports.overrideIndividualArgOnFunction = __napiModule.exports.overrideIndividualArgOnFunction
 module.exports.overrideIndividualArgOnFunctionWithCbArg = __napiModule.exports.overrideIndividualArgOnFunctionWithCbArg
+module.exports.overrideUnknownReturnType = __napiModule.exports.overrideUnknownReturnType
 module.exports.overrideWholeFunctionType = __napiModule.exports.overrideWholeFunctionType
 module.exports.panic = __napiModule.exports.panic
 module.exports.panicInAsync = __napiModule.exports.panicInAsync
+module.exports.panicWithFormattedMessage = __napiModule.exports.panicWithFormattedMessage
+module.exports.panicWithoutCatchUnwind = __napiModule.exports.panicWithoutCatchUnwind
+module.exports.parseConfig = __napiModule.exports.parseConfig
+module.exports.parseNumbers = __napiModule.exports.parseNumbers
+module.exports.parsePacketHeader = __napiModule.exports.parsePacketHeader
+module.exports.parseWithAnyhow = __napiModule.exports.parseWithAnyhow
 module.exports.passSetToJs = __napiModule.exports.passSetToJs
 module.exports.passSetToRust = __napiModule.exports.passSetToRust
 module.exports.passSetWithHasherToJs = __napiModule.exports.passSetWithHasherToJs
@@ -305,30 +411,58 @@ module.exports.receiveObjectWithClassField = __napiModule.exports.receiveObjectW
 module.exports.receiveStrictObject = __napiModule.exports.receiveStrictObject
 module.exports.receiveString = __napiModule.exports.receiveString
 module.exports.referenceAsCallback = __napiModule.exports.referenceAsCallback
+module.exports.releaseTsfnInCallback = __napiModule.exports.releaseTsfnInCallback
+module.exports.resetVisitCounter = __napiModule.exports.resetVisitCounter
+module.exports.resolveFromThread = __napiModule.exports.resolveFromThread
 module.exports.returnCString = __napiModule.exports.returnCString
 module.exports.returnEither = __napiModule.exports.returnEither
 module.exports.returnEitherClass = __napiModule.exports.returnEitherClass
 module.exports.returnFromSharedCrate = __napiModule.exports.returnFromSharedCrate
+module.exports.returnLatin1Bytes = __napiModule.exports.returnLatin1Bytes
 module.exports.returnNull = __napiModule.exports.returnNull
 module.exports.returnObjectOnlyToJs = __napiModule.exports.returnObjectOnlyToJs
+module.exports.returnStrContainsNullByte = __napiModule.exports.returnStrContainsNullByte
 module.exports.returnUndefined = __napiModule.exports.returnUndefined
 module.exports.returnUndefinedIfInvalid = __napiModule.exports.returnUndefinedIfInvalid
 module.exports.returnUndefinedIfInvalidPromise = __napiModule.exports.returnUndefinedIfInvalidPromise
+module.exports.returnUtf8Bytes = __napiModule.exports.returnUtf8Bytes
+module.exports.rgbaToHex = __napiModule.exports.rgbaToHex
 module.exports.roundtripStr = __napiModule.exports.roundtripStr
+module.exports.roundTripTuple = __napiModule.exports.roundTripTuple
 module.exports.runScript = __napiModule.exports.runScript
+module.exports.runScriptWithFilename = __napiModule.exports.runScriptWithFilename
+module.exports.serializeLabeledObject = __napiModule.exports.serializeLabeledObject
+module.exports.serializeLabeledObjectWithSortedKeys = __napiModule.exports.serializeLabeledObjectWithSortedKeys
 module.exports.setNullByteProperty = __napiModule.exports.setNullByteProperty
 module.exports.setSymbolInObj = __napiModule.exports.setSymbolInObj
 module.exports.shorterEscapableScope = __napiModule.exports.shorterEscapableScope
 module.exports.shorterScope = __napiModule.exports.shorterScope
 module.exports.shutdownRuntime = __napiModule.exports.shutdownRuntime
+module.exports.spawnOnRuntimeHandle = __napiModule.exports.spawnOnRuntimeHandle
 module.exports.spawnThreadInThread = __napiModule.exports.spawnThreadInThread
+module.exports.squareEvenNumbers = __napiModule.exports.squareEvenNumbers
 module.exports.Status = __napiModule.exports.Status
 module.exports.StatusInValidate = __napiModule.exports.StatusInValidate
+module.exports.strictEqualsValues = __napiModule.exports.strictEqualsValues
 module.exports.StringEnum = __napiModule.exports.StringEnum
+module.exports.stringifyInEnvScope = __napiModule.exports.stringifyInEnvScope
+module.exports.structuredCloneValue = __napiModule.exports.structuredCloneValue
+module.exports.subarrayOfRustData = __napiModule.exports.subarrayOfRustData
+module.exports.sumAllPromises = __napiModule.exports.sumAllPromises
+module.exports.sumArraybufferAfter = __napiModule.exports.sumArraybufferAfter
+module.exports.sumArrayToVec = __napiModule.exports.sumArrayToVec
 module.exports.sumBtreeMapping = __napiModule.exports.sumBtreeMapping
 module.exports.sumIndexMapping = __napiModule.exports.sumIndexMapping
+module.exports.sumInEscapableScope = __napiModule.exports.sumInEscapableScope
 module.exports.sumMapping = __napiModule.exports.sumMapping
+module.exports.sumMapValues = __napiModule.exports.sumMapValues
 module.exports.sumNums = __napiModule.exports.sumNums
+module.exports.sumObjectValues = __napiModule.exports.sumObjectValues
+module.exports.sumRestArgs = __napiModule.exports.sumRestArgs
+module.exports.sumSharedArrayBuffer = __napiModule.exports.sumSharedArrayBuffer
+module.exports.takeExternalString = __napiModule.exports.takeExternalString
+module.exports.takeFinalizeErrors = __napiModule.exports.takeFinalizeErrors
+module.exports.takeMapValue = __napiModule.exports.takeMapValue
 module.exports.testSerdeBigNumberPrecision = __napiModule.exports.testSerdeBigNumberPrecision
 module.exports.testSerdeBufferBytes = __napiModule.exports.testSerdeBufferBytes
 module.exports.testSerdeRoundtrip = __napiModule.exports.testSerdeRoundtrip
@@ -340,21 +474,36 @@ module.exports.threadsafeFunctionThrowError = __napiModule.exports.threadsafeFun
 module.exports.threadsafeFunctionThrowErrorWithStatus = __napiModule.exports.threadsafeFunctionThrowErrorWithStatus
 module.exports.throwAsyncError = __napiModule.exports.throwAsyncError
 module.exports.throwError = __napiModule.exports.throwError
+module.exports.throwErrorWithCause = __napiModule.exports.throwErrorWithCause
+module.exports.throwInEnvScope = __napiModule.exports.throwInEnvScope
+module.exports.throwRangeError = __napiModule.exports.throwRangeError
 module.exports.throwSyntaxError = __napiModule.exports.throwSyntaxError
+module.exports.throwTypeError = __napiModule.exports.throwTypeError
+module.exports.toggleSetValue = __napiModule.exports.toggleSetValue
 module.exports.toJsObj = __napiModule.exports.toJsObj
+module.exports.toTransferableValue = __napiModule.exports.toTransferableValue
+module.exports.transferValueThroughThread = __napiModule.exports.transferValueThroughThread
+module.exports.tryFindVisitCounter = __napiModule.exports.tryFindVisitCounter
 module.exports.tsfnAsyncCall = __napiModule.exports.tsfnAsyncCall
+module.exports.tsfnAsyncCallFatalMode = __napiModule.exports.tsfnAsyncCallFatalMode
 module.exports.tsfnCallWithCallback = __napiModule.exports.tsfnCallWithCallback
+module.exports.tsfnCallWithTimeout = __napiModule.exports.tsfnCallWithTimeout
 module.exports.tsfnInEither = __napiModule.exports.tsfnInEither
 module.exports.tsfnReturnPromise = __napiModule.exports.tsfnReturnPromise
 module.exports.tsfnReturnPromiseTimeout = __napiModule.exports.tsfnReturnPromiseTimeout
 module.exports.tsfnThrowFromJs = __napiModule.exports.tsfnThrowFromJs
 module.exports.tsfnThrowFromJsCallbackContainsTsfn = __napiModule.exports.tsfnThrowFromJsCallbackContainsTsfn
 module.exports.tsRename = __napiModule.exports.tsRename
+module.exports.typeTagObject = __napiModule.exports.typeTagObject
 module.exports.u16ArrayToArray = __napiModule.exports.u16ArrayToArray
 module.exports.u32ArrayToArray = __napiModule.exports.u32ArrayToArray
 module.exports.u64ArrayToArray = __napiModule.exports.u64ArrayToArray
 module.exports.u8ArrayToArray = __napiModule.exports.u8ArrayToArray
 module.exports.uInit8ArrayFromString = __napiModule.exports.uInit8ArrayFromString
+module.exports.uint8ArraySubarray = __napiModule.exports.uint8ArraySubarray
+module.exports.upgradeRepo = __napiModule.exports.upgradeRepo
+module.exports.upgradeRepoDir = __napiModule.exports.upgradeRepoDir
+module.exports.utf16CodeUnits = __napiModule.exports.utf16CodeUnits
 module.exports.validateArray = __napiModule.exports.validateArray
 module.exports.validateBigint = __napiModule.exports.validateBigint
 module.exports.validateBoolean = __napiModule.exports.validateBoolean
@@ -378,9 +527,11 @@ module.exports.validateTypedArray = __napiModule.exports.validateTypedArray
 module.exports.validateTypedArraySlice = __napiModule.exports.validateTypedArraySlice
 module.exports.validateUint8ClampedSlice = __napiModule.exports.validateUint8ClampedSlice
 module.exports.validateUndefined = __napiModule.exports.validateUndefined
+module.exports.validateUsername = __napiModule.exports.validateUsername
 module.exports.withAbortController = __napiModule.exports.withAbortController
 module.exports.withinAsyncRuntimeIfAvailable = __napiModule.exports.withinAsyncRuntimeIfAvailable
 module.exports.withoutAbortController = __napiModule.exports.withoutAbortController
+module.exports.wrapNativeLabel = __napiModule.exports.wrapNativeLabel
 module.exports.xxh64Alias = __napiModule.exports.xxh64Alias
 module.exports.xxh2 = __napiModule.exports.xxh2
 module.exports.xxh3 = __napiModule.exports.xxh3
@@ -384,6 +384,7 @@ module.exports.Asset = nativeBinding.Asset
 module.exports.JsAsset = nativeBinding.JsAsset
 module.exports.Assets = nativeBinding.Assets
 module.exports.JsAssets = nativeBinding.JsAssets
+module.exports.AsyncFinalize = nativeBinding.AsyncFinalize
 module.exports.Bird = nativeBinding.Bird
 module.exports.Blake2BHasher = nativeBinding.Blake2BHasher
 module.exports.Blake2bHasher = nativeBinding.Blake2bHasher
@@ -391,6 +392,7 @@ module.exports.Blake2BKey = nativeBinding.Blake2BKey
 module.exports.Blake2bKey = nativeBinding.Blake2bKey
 module.exports.CatchOnConstructor = nativeBinding.CatchOnConstructor
 module.exports.CatchOnConstructor2 = nativeBinding.CatchOnConstructor2
+module.exports.Chart = nativeBinding.Chart
 module.exports.ClassInArray = nativeBinding.ClassInArray
 module.exports.ClassWithFactory = nativeBinding.ClassWithFactory
 module.exports.ClassWithLifetime = nativeBinding.ClassWithLifetime
@@ -403,21 +405,31 @@ module.exports.CustomFinalize = nativeBinding.CustomFinalize
 module.exports.CustomStruct = nativeBinding.CustomStruct
 module.exports.DefaultUseNullableClass = nativeBinding.DefaultUseNullableClass
 module.exports.Dog = nativeBinding.Dog
+module.exports.FailedFinalize = nativeBinding.FailedFinalize
 module.exports.Fib = nativeBinding.Fib
 module.exports.Fib2 = nativeBinding.Fib2
 module.exports.Fib3 = nativeBinding.Fib3
 module.exports.GetterSetterWithClosures = nativeBinding.GetterSetterWithClosures
+module.exports.Graph = nativeBinding.Graph
+module.exports.GraphNode = nativeBinding.GraphNode
+module.exports.ImmutableBytes = nativeBinding.ImmutableBytes
+module.exports.InspectablePoint = nativeBinding.InspectablePoint
 module.exports.JsClassForEither = nativeBinding.JsClassForEither
 module.exports.JsRemote = nativeBinding.JsRemote
 module.exports.JsRepo = nativeBinding.JsRepo
+module.exports.LeakProbe = nativeBinding.LeakProbe
 module.exports.NinjaTurtle = nativeBinding.NinjaTurtle
 module.exports.NotUseNullableClass = nativeBinding.NotUseNullableClass
 module.exports.NotWritableClass = nativeBinding.NotWritableClass
 module.exports.Optional = nativeBinding.Optional
 module.exports.PackageJsonReader = nativeBinding.PackageJsonReader
+module.exports.PersistentValue = nativeBinding.PersistentValue
+module.exports.PullCounter = nativeBinding.PullCounter
 module.exports.Reader = nativeBinding.Reader
 module.exports.Selector = nativeBinding.Selector
+module.exports.SharedCounter = nativeBinding.SharedCounter
 module.exports.UseNullableClass = nativeBinding.UseNullableClass
+module.exports.VisitCounter = nativeBinding.VisitCounter
 module.exports.Width = nativeBinding.Width
 module.exports.acceptArraybuffer = nativeBinding.acceptArraybuffer
 module.exports.acceptSlice = nativeBinding.acceptSlice
@@ -428,12 +440,15 @@ module.exports.acceptThreadsafeFunctionTupleArgs = nativeBinding.acceptThreadsaf
 module.exports.acceptUint8ClampedSlice = nativeBinding.acceptUint8ClampedSlice
 module.exports.acceptUint8ClampedSliceAndBufferSlice = nativeBinding.acceptUint8ClampedSliceAndBufferSlice
 module.exports.add = nativeBinding.add
+module.exports.adjustExternalMemory = nativeBinding.adjustExternalMemory
 module.exports.ALIAS = nativeBinding.ALIAS
 module.exports.AliasedEnum = nativeBinding.AliasedEnum
 module.exports.appendBuffer = nativeBinding.appendBuffer
 module.exports.apply0 = nativeBinding.apply0
 module.exports.apply1 = nativeBinding.apply1
+module.exports.applyWithSpreadArgs = nativeBinding.applyWithSpreadArgs
 module.exports.arrayBufferPassThrough = nativeBinding.arrayBufferPassThrough
+module.exports.assignAnimalToSymbol = nativeBinding.assignAnimalToSymbol
 module.exports.asyncBufferToArray = nativeBinding.asyncBufferToArray
 module.exports.asyncMultiTwo = nativeBinding.asyncMultiTwo
 module.exports.asyncPlus100 = nativeBinding.asyncPlus100
@@ -441,10 +456,15 @@ module.exports.asyncReduceBuffer = nativeBinding.asyncReduceBuffer
 module.exports.asyncTaskOptionalReturn = nativeBinding.asyncTaskOptionalReturn
 module.exports.asyncTaskReadFile = nativeBinding.asyncTaskReadFile
 module.exports.asyncTaskVoidReturn = nativeBinding.asyncTaskVoidReturn
+module.exports.bigint64ArrayRoundTrip = nativeBinding.bigint64ArrayRoundTrip
 module.exports.bigintAdd = nativeBinding.bigintAdd
 module.exports.bigintFromI128 = nativeBinding.bigintFromI128
 module.exports.bigintFromI64 = nativeBinding.bigintFromI64
 module.exports.bigintGetU64AsString = nativeBinding.bigintGetU64AsString
+module.exports.bigintI128RoundTrip = nativeBinding.bigintI128RoundTrip
+module.exports.bigintU128RoundTrip = nativeBinding.bigintU128RoundTrip
+module.exports.biguint64ArrayRoundTrip = nativeBinding.biguint64ArrayRoundTrip
+module.exports.bindRepoDir = nativeBinding.bindRepoDir
 module.exports.btreeSetToJs = nativeBinding.btreeSetToJs
 module.exports.btreeSetToRust = nativeBinding.btreeSetToRust
 module.exports.bufferPassThrough = nativeBinding.bufferPassThrough
@@ -454,6 +474,7 @@ module.exports.buildThreadsafeFunctionFromFunctionCalleeHandle = nativeBinding.b
 module.exports.call0 = nativeBinding.call0
 module.exports.call1 = nativeBinding.call1
 module.exports.call2 = nativeBinding.call2
+module.exports.callAndRecoverExceptions = nativeBinding.callAndRecoverExceptions
 module.exports.callAsyncWithUnknownReturnValue = nativeBinding.callAsyncWithUnknownReturnValue
 module.exports.callbackReturnPromise = nativeBinding.callbackReturnPromise
 module.exports.callbackReturnPromiseAndSpawn = nativeBinding.callbackReturnPromiseAndSpawn
@@ -466,6 +487,8 @@ module.exports.callLongThreadsafeFunction = nativeBinding.callLongThreadsafeFunc
 module.exports.callRuleHandler = nativeBinding.callRuleHandler
 module.exports.callThenOnPromise = nativeBinding.callThenOnPromise
 module.exports.callThreadsafeFunction = nativeBinding.callThreadsafeFunction
+module.exports.callWithFastPaths = nativeBinding.callWithFastPaths
+module.exports.callWithSpreadArgs = nativeBinding.callWithSpreadArgs
 module.exports.captureErrorInCallback = nativeBinding.captureErrorInCallback
 module.exports.chronoDateAdd1Minute = nativeBinding.chronoDateAdd1Minute
 module.exports.chronoDateFixtureReturn1 = nativeBinding.chronoDateFixtureReturn1
@@ -477,36 +500,69 @@ module.exports.chronoLocalDateToMillis = nativeBinding.chronoLocalDateToMillis
 module.exports.chronoNativeDateTime = nativeBinding.chronoNativeDateTime
 module.exports.chronoNativeDateTimeReturn = nativeBinding.chronoNativeDateTimeReturn
 module.exports.chronoUtcDateReturn = nativeBinding.chronoUtcDateReturn
+module.exports.chronoUtcDateRoundTrip = nativeBinding.chronoUtcDateRoundTrip
 module.exports.chronoUtcDateToMillis = nativeBinding.chronoUtcDateToMillis
+module.exports.chronoUtcDateWithSubMillis = nativeBinding.chronoUtcDateWithSubMillis
+module.exports.classInstanceEnvName = nativeBinding.classInstanceEnvName
+module.exports.coalesceProgress = nativeBinding.coalesceProgress
+module.exports.collectFinalizeErrors = nativeBinding.collectFinalizeErrors
+module.exports.collectNextFinalizeError = nativeBinding.collectNextFinalizeError
+module.exports.compareAnimalHandles = nativeBinding.compareAnimalHandles
+module.exports.compareAnimals = nativeBinding.compareAnimals
 module.exports.concatLatin1 = nativeBinding.concatLatin1
 module.exports.concatStr = nativeBinding.concatStr
 module.exports.concatUtf16 = nativeBinding.concatUtf16
 module.exports.contains = nativeBinding.contains
 module.exports.convertU32Array = nativeBinding.convertU32Array
+module.exports.countLoneSurrogates = nativeBinding.countLoneSurrogates
+module.exports.countUntilAborted = nativeBinding.countUntilAborted
+module.exports.countUpStream = nativeBinding.countUpStream
+module.exports.countWithAnySignal = nativeBinding.countWithAnySignal
+module.exports.countWithProgress = nativeBinding.countWithProgress
+module.exports.countWords = nativeBinding.countWords
 module.exports.createArraybuffer = nativeBinding.createArraybuffer
+module.exports.createArrayOfSquares = nativeBinding.createArrayOfSquares
 module.exports.createBigInt = nativeBinding.createBigInt
 module.exports.createBigIntI64 = nativeBinding.createBigIntI64
 module.exports.createBufferSliceFromCopiedData = nativeBinding.createBufferSliceFromCopiedData
+module.exports.createErrorWithCode = nativeBinding.createErrorWithCode
 module.exports.createExternal = nativeBinding.createExternal
 module.exports.createExternalBufferSlice = nativeBinding.createExternalBufferSlice
 module.exports.createExternalString = nativeBinding.createExternalString
 module.exports.createExternalTypedArray = nativeBinding.createExternalTypedArray
+module.exports.createFrozenConfig = nativeBinding.createFrozenConfig
 module.exports.createObj = nativeBinding.createObj
 module.exports.createObjectWithClassField = nativeBinding.createObjectWithClassField
+module.exports.createObjectWithGetterClosure = nativeBinding.createObjectWithGetterClosure
 module.exports.createObjWithProperty = nativeBinding.createObjWithProperty
 module.exports.createOptionalExternal = nativeBinding.createOptionalExternal
+module.exports.createPacketHeader = nativeBinding.createPacketHeader
 module.exports.createReadableStream = nativeBinding.createReadableStream
 module.exports.createReadableStreamFromClass = nativeBinding.createReadableStreamFromClass
 module.exports.createReferenceOnFunction = nativeBinding.createReferenceOnFunction
+module.exports.createSealedConfig = nativeBinding.createSealedConfig
+module.exports.createSharedArrayBuffer = nativeBinding.createSharedArrayBuffer
 module.exports.createSymbol = nativeBinding.createSymbol
 module.exports.createSymbolFor = nativeBinding.createSymbolFor
+module.exports.createSymbolForKey = nativeBinding.createSymbolForKey
+module.exports.createUniqueSet = nativeBinding.createUniqueSet
 module.exports.CustomNumEnum = nativeBinding.CustomNumEnum
 module.exports.customStatusCode = nativeBinding.customStatusCode
 module.exports.CustomStringEnum = nativeBinding.CustomStringEnum
 module.exports.dateToNumber = nativeBinding.dateToNumber
+module.exports.decodeLatin1 = nativeBinding.decodeLatin1
+module.exports.dedupStrictEqual = nativeBinding.dedupStrictEqual
 module.exports.DEFAULT_COST = nativeBinding.DEFAULT_COST
 module.exports.derefUint8Array = nativeBinding.derefUint8Array
+module.exports.describeMapEntries = nativeBinding.describeMapEntries
+module.exports.describeUnknown = nativeBinding.describeUnknown
+module.exports.detachArraybuffer = nativeBinding.detachArraybuffer
+module.exports.downcastBirdName = nativeBinding.downcastBirdName
+module.exports.dropNativeLabel = nativeBinding.dropNativeLabel
+module.exports.dropRefAfterUnref = nativeBinding.dropRefAfterUnref
+module.exports.dropResolverFromThread = nativeBinding.dropResolverFromThread
 module.exports.either3 = nativeBinding.either3
+module.exports.either3Variant = nativeBinding.either3Variant
 module.exports.either4 = nativeBinding.either4
 module.exports.eitherBoolOrFunction = nativeBinding.eitherBoolOrFunction
 module.exports.eitherBoolOrTuple = nativeBinding.eitherBoolOrTuple
@@ -516,31 +572,49 @@ module.exports.eitherFromOption = nativeBinding.eitherFromOption
 module.exports.eitherPromiseInEitherA = nativeBinding.eitherPromiseInEitherA
 module.exports.eitherStringOrNumber = nativeBinding.eitherStringOrNumber
 module.exports.Empty = nativeBinding.Empty
+module.exports.encodeLatin1 = nativeBinding.encodeLatin1
 module.exports.enumToI32 = nativeBinding.enumToI32
 module.exports.errorMessageContainsNullByte = nativeBinding.errorMessageContainsNullByte
+module.exports.escapeTwice = nativeBinding.escapeTwice
 module.exports.esmResolve = nativeBinding.esmResolve
 module.exports.extendsJavascriptError = nativeBinding.extendsJavascriptError
 module.exports.f32ArrayToArray = nativeBinding.f32ArrayToArray
 module.exports.f64ArrayToArray = nativeBinding.f64ArrayToArray
 module.exports.fibonacci = nativeBinding.fibonacci
+module.exports.fillArraybufferAfter = nativeBinding.fillArraybufferAfter
+module.exports.fillFloat32ArrayRange = nativeBinding.fillFloat32ArrayRange
+module.exports.findVisitCounter = nativeBinding.findVisitCounter
 module.exports.fnReceivedAliased = nativeBinding.fnReceivedAliased
 module.exports.generateFunctionAndCallIt = nativeBinding.generateFunctionAndCallIt
+module.exports.getAbortableSteps = nativeBinding.getAbortableSteps
+module.exports.getAnimalNameLengthFromForeign = nativeBinding.getAnimalNameLengthFromForeign
 module.exports.getBigintJsonValue = nativeBinding.getBigintJsonValue
+module.exports.getBranchRef = nativeBinding.getBranchRef
 module.exports.getBtreeMapping = nativeBinding.getBtreeMapping
 module.exports.getBuffer = nativeBinding.getBuffer
+module.exports.getBufferFromPool = nativeBinding.getBufferFromPool
+module.exports.getBufferPoolCount = nativeBinding.getBufferPoolCount
 module.exports.getBufferSlice = nativeBinding.getBufferSlice
+module.exports.getBufferSliceWithSpareCapacity = nativeBinding.getBufferSliceWithSpareCapacity
 module.exports.getClassFromArray = nativeBinding.getClassFromArray
 module.exports.getCwd = nativeBinding.getCwd
+module.exports.getDataviewByteOffset = nativeBinding.getDataviewByteOffset
+module.exports.getDroppedBranches = nativeBinding.getDroppedBranches
+module.exports.getDroppedPropertyClosures = nativeBinding.getDroppedPropertyClosures
+module.exports.getDroppedVisitCounters = nativeBinding.getDroppedVisitCounters
 module.exports.getEmptyBuffer = nativeBinding.getEmptyBuffer
 module.exports.getEmptyTypedArray = nativeBinding.getEmptyTypedArray
 module.exports.getExternal = nativeBinding.getExternal
 module.exports.getGlobal = nativeBinding.getGlobal
+module.exports.getGlobalObject = nativeBinding.getGlobalObject
 module.exports.getIndexMapping = nativeBinding.getIndexMapping
 module.exports.getIndexMappingWithHasher = nativeBinding.getIndexMappingWithHasher
 module.exports.getMapping = nativeBinding.getMapping
 module.exports.getMappingWithHasher = nativeBinding.getMappingWithHasher
 module.exports.getModuleFileName = nativeBinding.getModuleFileName
 module.exports.getMyVec = nativeBinding.getMyVec
+module.exports.getNativeAllocations = nativeBinding.getNativeAllocations
+module.exports.getNativeLabel = nativeBinding.getNativeLabel
 module.exports.getNestedNumArr = nativeBinding.getNestedNumArr
 module.exports.getNull = nativeBinding.getNull
 module.exports.getNullByteProperty = nativeBinding.getNullByteProperty
@@ -548,35 +622,67 @@ module.exports.getNumArr = nativeBinding.getNumArr
 module.exports.getNums = nativeBinding.getNums
 module.exports.getOptionalExternal = nativeBinding.getOptionalExternal
 module.exports.getPackageJsonName = nativeBinding.getPackageJsonName
+module.exports.getPooledBuffers = nativeBinding.getPooledBuffers
+module.exports.getProcessEnvVar = nativeBinding.getProcessEnvVar
+module.exports.getPropertyDescriptor = nativeBinding.getPropertyDescriptor
+module.exports.getSetValues = nativeBinding.getSetValues
 module.exports.getStrFromObject = nativeBinding.getStrFromObject
+module.exports.getSymbolDescription = nativeBinding.getSymbolDescription
 module.exports.getterFromObj = nativeBinding.getterFromObj
 module.exports.getTuple = nativeBinding.getTuple
 module.exports.getUndefined = nativeBinding.getUndefined
 module.exports.getWords = nativeBinding.getWords
+module.exports.getZeroCopyBuffer = nativeBinding.getZeroCopyBuffer
+module.exports.hashBuffer = nativeBinding.hashBuffer
 module.exports.i16ArrayToArray = nativeBinding.i16ArrayToArray
 module.exports.i32ArrayToArray = nativeBinding.i32ArrayToArray
 module.exports.i64ArrayToArray = nativeBinding.i64ArrayToArray
 module.exports.i8ArrayToArray = nativeBinding.i8ArrayToArray
 module.exports.indexmapPassthrough = nativeBinding.indexmapPassthrough
+module.exports.installCountingAllocator = nativeBinding.installCountingAllocator
+module.exports.isTypeTaggedObject = nativeBinding.isTypeTaggedObject
+module.exports.joinRestArgs = nativeBinding.joinRestArgs
 module.exports.jsErrorCallback = nativeBinding.jsErrorCallback
+module.exports.jsonParseValue = nativeBinding.jsonParseValue
+module.exports.jsonStringifyValue = nativeBinding.jsonStringifyValue
 module.exports.Kind = nativeBinding.Kind
 module.exports.KindInValidate = nativeBinding.KindInValidate
+module.exports.LAZY_COST = nativeBinding.LAZY_COST
+module.exports.lazyAdd = nativeBinding.lazyAdd
 module.exports.listObjKeys = nativeBinding.listObjKeys
+module.exports.listObjPropertyNames = nativeBinding.listObjPropertyNames
+module.exports.liveClassInstanceCount = nativeBinding.liveClassInstanceCount
+module.exports.liveFailedFinalizeCount = nativeBinding.liveFailedFinalizeCount
+module.exports.liveLeakProbeCount = nativeBinding.liveLeakProbeCount
+module.exports.looseEqualsValues = nativeBinding.looseEqualsValues
 module.exports.mapOption = nativeBinding.mapOption
+module.exports.matrix4Trace = nativeBinding.matrix4Trace
+module.exports.mergeObjects = nativeBinding.mergeObjects
 module.exports.mergeTupleArray = nativeBinding.mergeTupleArray
 module.exports.mutateExternal = nativeBinding.mutateExternal
+module.exports.mutateFloat64Array = nativeBinding.mutateFloat64Array
 module.exports.mutateOptionalExternal = nativeBinding.mutateOptionalExternal
 module.exports.mutateTypedArray = nativeBinding.mutateTypedArray
 module.exports.objectGetNamedPropertyShouldPerformTypecheck = nativeBinding.objectGetNamedPropertyShouldPerformTypecheck
+module.exports.objectHasNamedProperty = nativeBinding.objectHasNamedProperty
+module.exports.objectHasOwnProperty = nativeBinding.objectHasOwnProperty
+module.exports.onObjectCollected = nativeBinding.onObjectCollected
 module.exports.optionEnd = nativeBinding.optionEnd
 module.exports.optionOnly = nativeBinding.optionOnly
 module.exports.optionStart = nativeBinding.optionStart
 module.exports.optionStartEnd = nativeBinding.optionStartEnd
 module.exports.overrideIndividualArgOnFunction = nativeBinding.overrideIndividualArgOnFunction
 module.exports.overrideIndividualArgOnFunctionWithCbArg = nativeBinding.overrideIndividualArgOnFunctionWithCbArg
+module.exports.overrideUnknownReturnType = nativeBinding.overrideUnknownReturnType
 module.exports.overrideWholeFunctionType = nativeBinding.overrideWholeFunctionType
 module.exports.panic = nativeBinding.panic
 module.exports.panicInAsync = nativeBinding.panicInAsync
+module.exports.panicWithFormattedMessage = nativeBinding.panicWithFormattedMessage
+module.exports.panicWithoutCatchUnwind = nativeBinding.panicWithoutCatchUnwind
+module.exports.parseConfig = nativeBinding.parseConfig
+module.exports.parseNumbers = nativeBinding.parseNumbers
+module.exports.parsePacketHeader = nativeBinding.parsePacketHeader
+module.exports.parseWithAnyhow = nativeBinding.parseWithAnyhow
 module.exports.passSetToJs = nativeBinding.passSetToJs
 module.exports.passSetToRust = nativeBinding.passSetToRust
 module.exports.passSetWithHasherToJs = nativeBinding.passSetWithHasherToJs
@@ -595,30 +701,58 @@ module.exports.receiveObjectWithClassField = nativeBinding.receiveObjectWithClas
 module.exports.receiveStrictObject = nativeBinding.receiveStrictObject
 module.exports.receiveString = nativeBinding.receiveString
 module.exports.referenceAsCallback = nativeBinding.referenceAsCallback
+module.exports.releaseTsfnInCallback = nativeBinding.releaseTsfnInCallback
+module.exports.resetVisitCounter = nativeBinding.resetVisitCounter
+module.exports.resolveFromThread = nativeBinding.resolveFromThread
 module.exports.returnCString = nativeBinding.returnCString
 module.exports.returnEither = nativeBinding.returnEither
 module.exports.returnEitherClass = nativeBinding.returnEitherClass
 module.exports.returnFromSharedCrate = nativeBinding.returnFromSharedCrate
+module.exports.returnLatin1Bytes = nativeBinding.returnLatin1Bytes
 module.exports.returnNull = nativeBinding.returnNull
 module.exports.returnObjectOnlyToJs = nativeBinding.returnObjectOnlyToJs
+module.exports.returnStrContainsNullByte = nativeBinding.returnStrContainsNullByte
 module.exports.returnUndefined = nativeBinding.returnUndefined
 module.exports.returnUndefinedIfInvalid = nativeBinding.returnUndefinedIfInvalid
 module.exports.returnUndefinedIfInvalidPromise = nativeBinding.returnUndefinedIfInvalidPromise
+module.exports.returnUtf8Bytes = nativeBinding.returnUtf8Bytes
+module.exports.rgbaToHex = nativeBinding.rgbaToHex
 module.exports.roundtripStr = nativeBinding.roundtripStr
+module.exports.roundTripTuple = nativeBinding.roundTripTuple
 module.exports.runScript = nativeBinding.runScript
+module.exports.runScriptWithFilename = nativeBinding.runScriptWithFilename
+module.exports.serializeLabeledObject = nativeBinding.serializeLabeledObject
+module.exports.serializeLabeledObjectWithSortedKeys = nativeBinding.serializeLabeledObjectWithSortedKeys
 module.exports.setNullByteProperty = nativeBinding.setNullByteProperty
 module.exports.setSymbolInObj = nativeBinding.setSymbolInObj
 module.exports.shorterEscapableScope = nativeBinding.shorterEscapableScope
 module.exports.shorterScope = nativeBinding.shorterScope
 module.exports.shutdownRuntime = nativeBinding.shutdownRuntime
+module.exports.spawnOnRuntimeHandle = nativeBinding.spawnOnRuntimeHandle
 module.exports.spawnThreadInThread = nativeBinding.spawnThreadInThread
+module.exports.squareEvenNumbers = nativeBinding.squareEvenNumbers
 module.exports.Status = nativeBinding.Status
 module.exports.StatusInValidate = nativeBinding.StatusInValidate
+module.exports.strictEqualsValues = nativeBinding.strictEqualsValues
 module.exports.StringEnum = nativeBinding.StringEnum
+module.exports.stringifyInEnvScope = nativeBinding.stringifyInEnvScope
+module.exports.structuredCloneValue = nativeBinding.structuredCloneValue
+module.exports.subarrayOfRustData = nativeBinding.subarrayOfRustData
+module.exports.sumAllPromises = nativeBinding.sumAllPromises
+module.exports.sumArraybufferAfter = nativeBinding.sumArraybufferAfter
+module.exports.sumArrayToVec = nativeBinding.sumArrayToVec
 module.exports.sumBtreeMapping = nativeBinding.sumBtreeMapping
 module.exports.sumIndexMapping = nativeBinding.sumIndexMapping
+module.exports.sumInEscapableScope = nativeBinding.sumInEscapableScope
 module.exports.sumMapping = nativeBinding.sumMapping
+module.exports.sumMapValues = nativeBinding.sumMapValues
 module.exports.sumNums = nativeBinding.sumNums
+module.exports.sumObjectValues = nativeBinding.sumObjectValues
+module.exports.sumRestArgs = nativeBinding.sumRestArgs
+module.exports.sumSharedArrayBuffer = nativeBinding.sumSharedArrayBuffer
+module.exports.takeExternalString = nativeBinding.takeExternalString
+module.exports.takeFinalizeErrors = nativeBinding.takeFinalizeErrors
+module.exports.takeMapValue = nativeBinding.takeMapValue
 module.exports.testSerdeBigNumberPrecision = nativeBinding.testSerdeBigNumberPrecision
 module.exports.testSerdeBufferBytes = nativeBinding.testSerdeBufferBytes
 module.exports.testSerdeRoundtrip = nativeBinding.testSerdeRoundtrip
@@ -630,21 +764,36 @@ module.exports.threadsafeFunctionThrowError = nativeBinding.threadsafeFunctionTh
 module.exports.threadsafeFunctionThrowErrorWithStatus = nativeBinding.threadsafeFunctionThrowErrorWithStatus
 module.exports.throwAsyncError = nativeBinding.throwAsyncError
 module.exports.throwError = nativeBinding.throwError
+module.exports.throwErrorWithCause = nativeBinding.throwErrorWithCause
+module.exports.throwInEnvScope = nativeBinding.throwInEnvScope
+module.exports.throwRangeError = nativeBinding.throwRangeError
 module.exports.throwSyntaxError = nativeBinding.throwSyntaxError
+module.exports.throwTypeError = nativeBinding.throwTypeError
+module.exports.toggleSetValue = nativeBinding.toggleSetValue
 module.exports.toJsObj = nativeBinding.toJsObj
+module.exports.toTransferableValue = nativeBinding.toTransferableValue
+module.exports.transferValueThroughThread = nativeBinding.transferValueThroughThread
+module.exports.tryFindVisitCounter = nativeBinding.tryFindVisitCounter
 module.exports.tsfnAsyncCall = nativeBinding.tsfnAsyncCall
+module.exports.tsfnAsyncCallFatalMode = nativeBinding.tsfnAsyncCallFatalMode
 module.exports.tsfnCallWithCallback = nativeBinding.tsfnCallWithCallback
+module.exports.tsfnCallWithTimeout = nativeBinding.tsfnCallWithTimeout
 module.exports.tsfnInEither = nativeBinding.tsfnInEither
 module.exports.tsfnReturnPromise = nativeBinding.tsfnReturnPromise
 module.exports.tsfnReturnPromiseTimeout = nativeBinding.tsfnReturnPromiseTimeout
 module.exports.tsfnThrowFromJs = nativeBinding.tsfnThrowFromJs
 module.exports.tsfnThrowFromJsCallbackContainsTsfn = nativeBinding.tsfnThrowFromJsCallbackContainsTsfn
 module.exports.tsRename = nativeBinding.tsRename
+module.exports.typeTagObject = nativeBinding.typeTagObject
 module.exports.u16ArrayToArray = nativeBinding.u16ArrayToArray
 module.exports.u32ArrayToArray = nativeBinding.u32ArrayToArray
 module.exports.u64ArrayToArray = nativeBinding.u64ArrayToArray
 module.exports.u8ArrayToArray = nativeBinding.u8ArrayToArray
 module.exports.uInit8ArrayFromString = nativeBinding.uInit8ArrayFromString
+module.exports.uint8ArraySubarray = nativeBinding.uint8ArraySubarray
+module.exports.upgradeRepo = nativeBinding.upgradeRepo
+module.exports.upgradeRepoDir = nativeBinding.upgradeRepoDir
+module.exports.utf16CodeUnits = nativeBinding.utf16CodeUnits
 module.exports.validateArray = nativeBinding.validateArray
 module.exports.validateBigint = nativeBinding.validateBigint
 module.exports.validateBoolean = nativeBinding.validateBoolean
@@ -668,9 +817,11 @@ module.exports.validateTypedArray = nativeBinding.validateTypedArray
 module.exports.validateTypedArraySlice = nativeBinding.validateTypedArraySlice
 module.exports.validateUint8ClampedSlice = nativeBinding.validateUint8ClampedSlice
 module.exports.validateUndefined = nativeBinding.validateUndefined
+module.exports.validateUsername = nativeBinding.validateUsername
 module.exports.withAbortController = nativeBinding.withAbortController
 module.exports.withinAsyncRuntimeIfAvailable = nativeBinding.withinAsyncRuntimeIfAvailable
 module.exports.withoutAbortController = nativeBinding.withoutAbortController
+module.exports.wrapNativeLabel = nativeBinding.wrapNativeLabel
 module.exports.xxh64Alias = nativeBinding.xxh64Alias
 module.exports.xxh2 = nativeBinding.xxh2
 module.exports.xxh3 = nativeBinding.xxh3
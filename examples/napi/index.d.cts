@@ -57,13 +57,13 @@ export declare class AnotherClassForEither {
 }
 
 export declare class AnotherCssStyleSheet {
-  get rules(): CssRuleList
+  readonly rules: CssRuleList
 }
 export type AnotherCSSStyleSheet = AnotherCssStyleSheet
 
 export declare class Asset {
   constructor()
-  get filePath(): number
+  readonly filePath: number
 }
 export type JsAsset = Asset
 
@@ -73,6 +73,11 @@ export declare class Assets {
 }
 export type JsAssets = Assets
 
+export declare class AsyncFinalize {
+  constructor(size: number)
+  static finalizedCount(): number
+}
+
 export declare class Bird {
   name: string
   constructor(name: string)
@@ -101,6 +106,13 @@ export declare class CatchOnConstructor2 {
   constructor()
 }
 
+export declare class Chart {
+  constructor(title: string)
+  /** Created on the first read and cached on the instance, the following reads return the same object */
+  readonly options: ChartOptions
+  readonly optionsCreated: number
+}
+
 export declare class ClassInArray {
   constructor(value: number)
 }
@@ -116,6 +128,7 @@ export declare class ClassWithFactory {
 export declare class ClassWithLifetime {
   constructor()
   getName(): string
+  getNamedAnimalName(this: this, name: string): string
 }
 
 export declare class Context {
@@ -129,14 +142,14 @@ export declare class Context {
 
 export declare class CssRuleList {
   getRules(): Array<string>
-  get parentStyleSheet(): CSSStyleSheet
-  get name(): string | null
+  readonly parentStyleSheet: CSSStyleSheet
+  readonly name: string | null
 }
 export type CSSRuleList = CssRuleList
 
 export declare class CssStyleSheet {
   constructor(name: string, rules: Array<string>)
-  get rules(): CssRuleList
+  readonly rules: CssRuleList
   anotherCssStyleSheet(): AnotherCssStyleSheet
 }
 export type CSSStyleSheet = CssStyleSheet
@@ -163,6 +176,10 @@ export declare class Dog {
   constructor(name: string)
 }
 
+export declare class FailedFinalize {
+  constructor(name: string)
+}
+
 export declare class Fib {
   [Symbol.iterator](): Iterator<number, void, number>
   constructor()
@@ -184,6 +201,29 @@ export declare class GetterSetterWithClosures {
   constructor()
 }
 
+export declare class Graph {
+  constructor()
+  node(id: number): GraphNode
+  readonly cachedNodes: number
+}
+
+export declare class GraphNode {
+  readonly id: number
+}
+
+/** Large immutable bytes, exposed to JavaScript without copying */
+export declare class ImmutableBytes {
+  constructor(size: number, label: string)
+  readonly data: Uint8Array
+  readonly copiedData: Uint8Array
+  readonly label: string
+  checksum(): number
+}
+
+export declare class InspectablePoint {
+  constructor(x: number, y: number)
+}
+
 export declare class JsClassForEither {
   constructor()
 }
@@ -196,11 +236,23 @@ export declare class JsRemote {
 export declare class JsRepo {
   constructor(dir: string)
   remote(): JsRemote
+  /** The returned `External` keeps the repository alive, the branch is dropped with the repository */
+  branch(name: string): ExternalObject<'Branch'>
+  /** Keep the repository alive until `release` is called */
+  retain(): number
+  release(): number
+  storeWeak(): void
+  dir(): string
+}
+
+export declare class LeakProbe {
+  constructor()
 }
 
 export declare class NinjaTurtle {
   name: string
   static isInstanceOf(value: unknown): boolean
+  static getNameOf(value: unknown): string
   /** Create your ninja turtle! 🐢 */
   static newRaph(): NinjaTurtle
   getMaskColor(): string
@@ -234,6 +286,21 @@ export declare class PackageJsonReader {
   read(): any
 }
 
+/** Hold any JavaScript value beyond the call which passes it in */
+export declare class PersistentValue {
+  /** The `value` could be garbage collected if the `ref_count` is `0` */
+  constructor(value: unknown, refCount: number)
+  get(): unknown
+  reference(): number
+  unref(): number
+}
+
+export declare class PullCounter {
+  constructor()
+  readonly pulled: number
+  numbers(to: number): Promise<AsyncIterableIterator<number>>
+}
+
 export declare class Reader {
 
   constructor()
@@ -248,6 +315,18 @@ export declare class Selector {
   constructor(orderBy: Array<string>, select: Array<string>, struct: string, where?: string)
 }
 
+export declare class SharedCounter {
+  constructor()
+  /** Create a counter shares the count with this one */
+  share(): SharedCounter
+  increment(): number
+  /** Increment after the `callback` returns, the count is mutably borrowed while calling it */
+  incrementAfter(callback: () => void): number
+  readonly count: number
+  readonly shareCount: number
+  sharesWith(other: SharedCounter): boolean
+}
+
 export declare class UseNullableClass {
   requiredNumberField: number
   requiredStringField: string
@@ -256,6 +335,13 @@ export declare class UseNullableClass {
   constructor(requiredNumberField: number, requiredStringField: string, nullableNumberField: number | null, nullableStringField: string | null)
 }
 
+export declare class VisitCounter {
+  constructor(label: string)
+  readonly label: string
+  readonly visits: number
+  visit(): number
+}
+
 export declare class Width {
   value: number
   constructor(value: number)
@@ -283,6 +369,8 @@ export declare function acceptUint8ClampedSliceAndBufferSlice(a: Buffer, b: Uint
 
 export declare function add(a: number, b: number): number
 
+export declare function adjustExternalMemory(changeInBytes: number): Array<number>
+
 export declare const enum ALIAS {
   A = 0,
   B = 1
@@ -304,8 +392,12 @@ export declare function apply0(ctx: Animal, callback: () => void): void
 
 export declare function apply1(ctx: Animal, callback: (arg: string) => void, name: string): void
 
+export declare function applyWithSpreadArgs(callback: (this: object, ...args: string[]) => string, context: object, args: string[]): string
+
 export declare function arrayBufferPassThrough(buf: Uint8Array): Promise<Uint8Array>
 
+export declare function assignAnimalToSymbol(this: object, key: symbol, name: string): void
+
 export declare function asyncBufferToArray(buf: ArrayBuffer): Array<number>
 
 export declare function asyncMultiTwo(arg: number): Promise<number>
@@ -324,6 +416,8 @@ export interface B {
   bar: number
 }
 
+export declare function bigint64ArrayRoundTrip(input: BigInt64Array): BigInt64Array
+
 export declare function bigintAdd(a: bigint, b: bigint): bigint
 
 export declare function bigintFromI128(): bigint
@@ -332,6 +426,14 @@ export declare function bigintFromI64(): bigint
 
 export declare function bigintGetU64AsString(bi: bigint): string
 
+export declare function bigintI128RoundTrip(value: bigint): bigint
+
+export declare function bigintU128RoundTrip(value: bigint): bigint
+
+export declare function biguint64ArrayRoundTrip(input: BigUint64Array): BigUint64Array
+
+export declare function bindRepoDir(dir: string): () => string
+
 export declare function btreeSetToJs(): Set<string>
 
 export declare function btreeSetToRust(set: Set<string>): void
@@ -354,6 +456,9 @@ export declare function call1(callback: (arg: number) => number, arg: number): n
 
 export declare function call2(callback: (arg0: number, arg1: number) => number, arg1: number, arg2: number): number
 
+/** Call the callbacks one by one, the value thrown from a callback is recovered and collected in place of its return value */
+export declare function callAndRecoverExceptions(callbacks: Array<() => unknown>): Array<unknown>
+
 export declare function callAsyncWithUnknownReturnValue(tsfn: ((err: Error | null, arg: number) => Ref<unknown>)): Promise<number>
 
 export declare function callbackReturnPromise<T>(functionInput: () => T | Promise<T>, callback: (err: Error | null, result: T) => void): T | Promise<T>
@@ -378,8 +483,18 @@ export declare function callThenOnPromise(input: Promise<number>): Promise<strin
 
 export declare function callThreadsafeFunction(tsfn: ((err: Error | null, arg: number) => unknown)): void
 
+export declare function callWithFastPaths(callback: (...args: number[]) => number): Array<number>
+
+export declare function callWithSpreadArgs(callback: (...args: number[]) => number, args: number[]): number
+
 export declare function captureErrorInCallback(cb1: () => void, cb2: (arg0: Error) => void): void
 
+export interface ChartOptions {
+  title: string
+  width: number
+  height: number
+}
+
 export declare function chronoDateAdd1Minute(input: Date): Date
 
 export declare function chronoDateFixtureReturn1(): Date
@@ -400,8 +515,30 @@ export declare function chronoNativeDateTimeReturn(): Date | null
 
 export declare function chronoUtcDateReturn(): Date | null
 
+export declare function chronoUtcDateRoundTrip(input: Date): Date
+
 export declare function chronoUtcDateToMillis(input: Date): number
 
+export declare function chronoUtcDateWithSubMillis(): Date
+
+/** The `Env` of the instance is the `Env` of the call, it can be used without the `env` argument */
+export declare function classInstanceEnvName(animal: Animal): string
+
+export declare function coalesceProgress(onProgress: ((arg: number) => any), total: number): void
+
+export declare function collectFinalizeErrors(): void
+
+/** Only collect the next finalize error, the handler is replaced by a no-op in itself */
+export declare function collectNextFinalizeError(): void
+
+/**
+ * Reads the same instance through two property lookups, so the two
+ * `ClassInstance`s have different `napi_value` handles to the same object.
+ */
+export declare function compareAnimalHandles(holder: { first: Animal, second: Animal }): Array<boolean>
+
+export declare function compareAnimals(a: Animal, b: Animal): Array<boolean>
+
 export declare function concatLatin1(s: string): string
 
 export declare function concatStr(s: string): string
@@ -412,14 +549,32 @@ export declare function contains(source: string, target: string): boolean
 
 export declare function convertU32Array(input: Uint32Array): Array<number>
 
+/** Count the lone surrogates of all strings, reusing one buffer for the UTF-16 code units */
+export declare function countLoneSurrogates(strings: Array<string>): number
+
+/** Count a step every 10ms, the future is dropped once the `signal` is aborted, so it stops counting */
+export declare function countUntilAborted(steps: number, signal?: AbortSignal | undefined | null): Promise<number>
+
+export declare function countUpStream(to: number, failAt?: number | undefined | null): Promise<AsyncIterableIterator<number>>
+
+export declare function countWithAnySignal(steps: number, onProgress: (arg: number) => void, signals: Array<AbortSignal>): Promise<number>
+
+export declare function countWithProgress(steps: number, onProgress: (arg: number) => void, signal?: AbortSignal | undefined | null): Promise<number>
+
+export declare function countWords(words: Array<string>): Map<any, any>
+
 export declare function createArraybuffer(): ArrayBuffer
 
+export declare function createArrayOfSquares(n: number): unknown[]
+
 export declare function createBigInt(): bigint
 
 export declare function createBigIntI64(): bigint
 
 export declare function createBufferSliceFromCopiedData(): Buffer
 
+export declare function createErrorWithCode(code: string | undefined | null, msg: string): Error
+
 export declare function createExternal(size: number): ExternalObject<number>
 
 export declare function createExternalBufferSlice(): Buffer
@@ -428,24 +583,38 @@ export declare function createExternalString(content: string): ExternalObject<st
 
 export declare function createExternalTypedArray(): Uint32Array
 
+export declare function createFrozenConfig(): object
+
 export declare function createObj(): object
 
 export declare function createObjectWithClassField(): ObjectFieldClassInstance
 
+export declare function createObjectWithGetterClosure(value: number): object
+
 export declare function createObjWithProperty(): { value: ArrayBuffer, get getter(): number }
 
 export declare function createOptionalExternal(size?: number | undefined | null): ExternalObject<number> | null
 
+export declare function createPacketHeader(header: PacketHeader): DataView
+
 export declare function createReadableStream(): ReadableStream<Buffer>
 
 export declare function createReadableStreamFromClass(readableStreamClass: typeof ReadableStream): ReadableStream<Buffer>
 
 export declare function createReferenceOnFunction(cb: () => void): Promise<void>
 
+export declare function createSealedConfig(): object
+
+export declare function createSharedArrayBuffer(length: number, value: number): SharedArrayBuffer
+
 export declare function createSymbol(): symbol
 
 export declare function createSymbolFor(desc: string): symbol
 
+export declare function createSymbolForKey(key: string): symbol
+
+export declare function createUniqueSet(values: Array<number>): Set<any>
+
 /** You could break the step and for an new continuous value. */
 export declare const enum CustomNumEnum {
   One = 1,
@@ -480,6 +649,11 @@ export interface DatesWithTimeZone {
 
 export declare function dateToNumber(input: Date): number
 
+export declare function decodeLatin1(bytes: Buffer): string
+
+/** Remove the duplicated values with `===`, the first one is kept */
+export declare function dedupStrictEqual(values: Array<unknown>): Array<unknown>
+
 /** This is a const */
 export const DEFAULT_COST: number
 
@@ -492,8 +666,25 @@ export interface DefaultUseNullableStruct {
 
 export declare function derefUint8Array(a: Uint8Array, b: Uint8ClampedArray): number
 
+export declare function describeMapEntries(map: Map<any, any>): Array<string>
+
+export declare function describeUnknown(value: unknown): string
+
+export declare function detachArraybuffer(buf: ArrayBuffer): boolean
+
+export declare function downcastBirdName(instance: Animal | Bird): string
+
+export declare function dropNativeLabel(obj: object): void
+
+/** Create a `Ref` with the `ref_count` and drop it after `unref`, returns the ref count after `unref` */
+export declare function dropRefAfterUnref(value: unknown, refCount: number): number
+
+export declare function dropResolverFromThread(): Promise<number>
+
 export declare function either3(input: string | number | boolean): number
 
+export declare function either3Variant(input: string | number | boolean): string
+
 export declare function either4(input: string | number | boolean | Obj): number
 
 export declare function eitherBoolOrFunction(input: boolean | (any)): void
@@ -514,10 +705,14 @@ export declare const enum Empty {
 
 }
 
+export declare function encodeLatin1(s: string): Buffer
+
 export declare function enumToI32(e: CustomNumEnum): number
 
 export declare function errorMessageContainsNullByte(msg: string): void
 
+export declare function escapeTwice(): void
+
 export declare function esmResolve(next: () => Promise<undefined>): Promise<undefined>
 
 export declare function extendsJavascriptError(errorClass: any): void
@@ -528,6 +723,14 @@ export declare function f64ArrayToArray(input: Float64Array): Array<number>
 
 export declare function fibonacci(n: number): number
 
+export declare function fillArraybufferAfter(buf: ArrayBuffer, value: number, beforeFill: () => void): void
+
+/** Fill the elements from `begin` to `end` of the `input` with `value` through a subarray */
+export declare function fillFloat32ArrayRange(input: Float32Array, begin: number, end: number, value: number): void
+
+/** Returns `null` if the `label` is empty */
+export declare function findVisitCounter(label: string): VisitCounter | null
+
 export declare function fnReceivedAliased(s: AliasedStruct, e: ALIAS): void
 
 export interface FunctionData {
@@ -536,18 +739,38 @@ export interface FunctionData {
 
 export declare function generateFunctionAndCallIt(): FunctionData
 
+export declare function getAbortableSteps(): number
+
+export declare function getAnimalNameLengthFromForeign(animal: Animal): number
+
 export declare function getBigintJsonValue(value: bigint): void
 
+export declare function getBranchRef(branch: ExternalObject<'Branch'>): string
+
 export declare function getBtreeMapping(): Record<string, number>
 
 export declare function getBuffer(): Buffer
 
+export declare function getBufferFromPool(len: number): Buffer
+
+export declare function getBufferPoolCount(): number
+
 export declare function getBufferSlice(): Buffer
 
+export declare function getBufferSliceWithSpareCapacity(len: number): Buffer
+
 export declare function getClassFromArray(arr: unknown[]): number | null
 
 export declare function getCwd(callback: (arg0: string) => void): void
 
+export declare function getDataviewByteOffset(view: DataView): Array<number>
+
+export declare function getDroppedBranches(): number
+
+export declare function getDroppedPropertyClosures(): number
+
+export declare function getDroppedVisitCounters(): number
+
 export declare function getEmptyBuffer(): Buffer
 
 export declare function getEmptyTypedArray(): Uint8Array
@@ -556,6 +779,13 @@ export declare function getExternal(external: ExternalObject<number>): number
 
 export declare function getGlobal(): typeof global
 
+/**
+ * Get the well known globals with the accessors of `Env`, the others are read with `Env::get_global_object`
+ *
+ * The empty `name` returns the global object itself
+ */
+export declare function getGlobalObject(name: string): object
+
 export declare function getIndexMapping(): Record<string, number>
 
 export declare function getIndexMappingWithHasher(): Record<string, number>
@@ -568,6 +798,11 @@ export declare function getModuleFileName(): string
 
 export declare function getMyVec(): MyVec
 
+/** The number of the allocated and freed native objects since the `installCountingAllocator` */
+export declare function getNativeAllocations(): Array<number>
+
+export declare function getNativeLabel(obj: object): string
+
 export declare function getNestedNumArr(): number[][][]
 
 export declare function getNull(): null
@@ -583,8 +818,19 @@ export declare function getOptionalExternal(external?: ExternalObject<number> |
 
 export declare function getPackageJsonName(packageJson: PackageJson): string
 
+export declare function getPooledBuffers(count: number): Array<Buffer>
+
+/** Read the environment variable from `process.env` */
+export declare function getProcessEnvVar(name: string): string | null
+
+export declare function getPropertyDescriptor(obj: object, key: string): JsPropertyDescriptor | null
+
+export declare function getSetValues(set: Set<any>): Array<number>
+
 export declare function getStrFromObject(): void
 
+export declare function getSymbolDescription(symbol: symbol): string | null
+
 export declare function getterFromObj(): number
 
 export declare function getTuple(val: [number, string, number]): number
@@ -593,6 +839,10 @@ export declare function getUndefined(): void
 
 export declare function getWords(): Array<string>
 
+export declare function getZeroCopyBuffer(len: number): Buffer
+
+export declare function hashBuffer(data: Buffer, signal?: AbortSignal | undefined | null): Promise<string>
+
 export declare function i16ArrayToArray(input: Int16Array): Array<number>
 
 export declare function i32ArrayToArray(input: Int32Array): Array<number>
@@ -603,8 +853,27 @@ export declare function i8ArrayToArray(input: Int8Array): Array<number>
 
 export declare function indexmapPassthrough(fixture: Record<string, number>): Record<string, number>
 
+export declare function installCountingAllocator(): void
+
+export declare function isTypeTaggedObject(obj: object): boolean
+
+export declare function joinRestArgs(separator: string, ...parts: Array<string>): string
+
 export declare function jsErrorCallback(value: unknown): Array<Error>
 
+export declare function jsonParseValue(s: string): unknown
+
+export declare function jsonStringifyValue(value: unknown): string | null
+
+export interface JsPropertyDescriptor {
+  enumerable: boolean
+  configurable: boolean
+  writable: boolean
+  isAccessor: boolean
+  hasGetter: boolean
+  hasSetter: boolean
+}
+
 /** default enum values are continuos i32s start from 0 */
 export declare const enum Kind {
   /** Barks */
@@ -625,15 +894,38 @@ export declare const enum KindInValidate {
   Duck = 2
 }
 
+/** This const is created on the first access */
+export const LAZY_COST: number
+
+export declare function lazyAdd(a: number, b: number): number
+
 export declare function listObjKeys(obj: object): Array<string>
 
+export declare function listObjPropertyNames(obj: object, includePrototypes: boolean): Array<string>
+
+/** Number of the alive class instances, it's always `0` in the release builds */
+export declare function liveClassInstanceCount(): number
+
+/** Number of the alive `FailedFinalize` instances, it's always `0` in the release builds */
+export declare function liveFailedFinalizeCount(): number
+
+/** Number of the alive `LeakProbe` instances, it's always `0` in the release builds */
+export declare function liveLeakProbeCount(): number
+
 export interface LocalDates {
   start: Date
   end?: Date
 }
 
+export declare function looseEqualsValues(a: unknown, b: unknown): boolean
+
 export declare function mapOption(val?: number | undefined | null): number | null
 
+/** The trace of a 4x4 matrix in column-major order */
+export declare function matrix4Trace(matrix: Float64Array): number
+
+export declare function mergeObjects(first: object, second: object): object
+
 export declare function mergeTupleArray(t1: TupleToArray, t2: TupleToArray): TupleToArray
 
 export interface Meta {
@@ -642,6 +934,8 @@ export interface Meta {
 
 export declare function mutateExternal(external: ExternalObject<number>, newVal: number): void
 
+export declare function mutateFloat64Array(input: Float64Array, beforeWrite?: () => void | undefined | null): void
+
 export declare function mutateOptionalExternal(external: ExternalObject<number> | undefined | null, newVal: number): void
 
 export declare function mutateTypedArray(input: Float32Array): void
@@ -672,6 +966,11 @@ export interface ObjectFieldClassInstance {
 
 export declare function objectGetNamedPropertyShouldPerformTypecheck(obj: { foo: number; bar: string; }): void
 
+/** Same as `key in obj`, the properties from the prototype chain are included */
+export declare function objectHasNamedProperty(obj: object, key: string): boolean
+
+export declare function objectHasOwnProperty(obj: object, key: string): boolean
+
 export interface ObjectOnlyFromJs {
   count: number
   callback: ((err: Error | null, arg: number) => any)
@@ -682,6 +981,9 @@ export interface ObjectOnlyToJs {
   dependencies: any
 }
 
+/** Call `on_collected` with the `label` after the `target` is garbage collected */
+export declare function onObjectCollected(target: object, label: string, onCollected: (arg: string) => void): void
+
 export declare function optionEnd(callback: (arg0: string, arg1?: string | undefined | null) => void): void
 
 export declare function optionOnly(callback: (arg0?: string | undefined | null) => void): void
@@ -694,6 +996,8 @@ export declare function overrideIndividualArgOnFunction(notOverridden: string, f
 
 export declare function overrideIndividualArgOnFunctionWithCbArg(callback: (town: string, name?: string | undefined | null) => string, notOverridden: number): object
 
+export declare function overrideUnknownReturnType(x: number, y: number): { kind: 'point', x: number, y: number }
+
 export declare function overrideWholeFunctionType(operation: 'add' | 'subtract' | 'multiply', a: number, b: number): number
 
 /** This is an interface for package.json */
@@ -705,10 +1009,31 @@ export interface PackageJson {
   devDependencies?: Record<string, any>
 }
 
+export interface PacketHeader {
+  kind: number
+  flags: number
+  length: number
+  timestamp: bigint
+  ratio: number
+}
+
 export declare function panic(): void
 
 export declare function panicInAsync(): Promise<void>
 
+export declare function panicWithFormattedMessage(value: number): number
+
+export declare function panicWithoutCatchUnwind(): void
+
+export declare function parseConfig(input: string): number
+
+export declare function parseNumbers(input: string): IterableIterator<number>
+
+/** `kind: u8`, `flags: i16` in big endian, `length: u32`, `timestamp: u64` and `ratio: f64` in little endian */
+export declare function parsePacketHeader(view: DataView): PacketHeader
+
+export declare function parseWithAnyhow(input: string): number
+
 export declare function passSetToJs(): Set<string>
 
 export declare function passSetToRust(set: Set<string>): void
@@ -752,6 +1077,13 @@ export declare function receiveString(s: string): string
 
 export declare function referenceAsCallback(callback: (arg0: number, arg1: number) => number, arg0: number, arg1: number): number
 
+export declare function releaseTsfnInCallback(callback: (arg: number) => void, times: number): void
+
+/** Replace the state behind the `counter` with a fresh one labeled `label`, returns the visits of the old state */
+export declare function resetVisitCounter(counter: VisitCounter, label: string): number
+
+export declare function resolveFromThread(value?: number | undefined | null): Promise<number>
+
 export declare function returnCString(): string
 
 export declare function returnEither(input: number): string | number
@@ -760,18 +1092,28 @@ export declare function returnEitherClass(input: number): number | JsClassForEit
 
 export declare function returnFromSharedCrate(): Shared
 
+export declare function returnLatin1Bytes(): string
+
 export declare function returnNull(): null
 
 export declare function returnObjectOnlyToJs(): ObjectOnlyToJs
 
+export declare function returnStrContainsNullByte(): string
+
 export declare function returnUndefined(): void
 
 export declare function returnUndefinedIfInvalid(input: boolean): boolean
 
 export declare function returnUndefinedIfInvalidPromise(input: Promise<boolean>): Promise<boolean>
 
+export declare function returnUtf8Bytes(withInvalidByte: boolean): string
+
+export declare function rgbaToHex(color: Uint8ClampedArray): string
+
 export declare function roundtripStr(s: string): string
 
+export declare function roundTripTuple(val: [number, string, boolean]): [boolean, string, number]
+
 export interface Rule {
   name: string
   handler: RuleHandler<number, number>
@@ -782,6 +1124,12 @@ export type RuleHandler<Args, Ret> =
 
 export declare function runScript(script: string): unknown
 
+export declare function runScriptWithFilename(script: string, filename: string): unknown
+
+export declare function serializeLabeledObject(): unknown
+
+export declare function serializeLabeledObjectWithSortedKeys(): unknown
+
 export declare function setNullByteProperty(obj: object): void
 
 export declare function setSymbolInObj(symbol: symbol): object
@@ -792,8 +1140,13 @@ export declare function shorterScope(arr: unknown[]): Array<number>
 
 export declare function shutdownRuntime(): void
 
+export declare function spawnOnRuntimeHandle(arg: number): Promise<number>
+
 export declare function spawnThreadInThread(tsfn: ((err: Error | null, arg: number) => number)): void
 
+/** Square the even numbers and skip the odd ones, fails if a square overflows `u32` */
+export declare function squareEvenNumbers(arr: number[]): number[]
+
 export declare const enum Status {
   Pristine = 'Pristine',
   Loading = 'Loading',
@@ -806,6 +1159,8 @@ export declare const enum StatusInValidate {
   Done = 'Done'
 }
 
+export declare function strictEqualsValues(a: unknown, b: unknown): boolean
+
 export interface StrictObject {
   name: string
 }
@@ -816,20 +1171,51 @@ export declare const enum StringEnum {
   VariantThree = 'variantthree'
 }
 
+export declare function stringifyInEnvScope(value: number): Promise<string>
+
+export declare function structuredCloneValue(value: unknown): unknown
+
 export type StructuredKind =
   | { type2: 'Hello' }
   | { type2: 'Greeting', name: string }
   | { type2: 'Birthday', name: string, age: number }
   | { type2: 'Tuple', field0: number, field1: number }
 
+export declare function subarrayOfRustData(): Uint8Array
+
+/** Await the `promises` concurrently and sum their values */
+export declare function sumAllPromises(promises: Array<Promise<number>>): Promise<number>
+
+export declare function sumArraybufferAfter(buf: ArrayBuffer, beforeSum: () => void): number
+
+export declare function sumArrayToVec(arr: unknown[]): number
+
 export declare function sumBtreeMapping(nums: Record<string, number>): number
 
 export declare function sumIndexMapping(nums: Record<string, number>): number
 
+export declare function sumInEscapableScope(count: number): object
+
 export declare function sumMapping(nums: Record<string, number>): number
 
+export declare function sumMapValues(map: Map<any, any>): number
+
 export declare function sumNums(nums: Array<number>): number
 
+export declare function sumObjectValues(obj: object): number
+
+/** Sum all the arguments, the number of them is not limited */
+export declare function sumRestArgs(...values: Array<number>): number
+
+export declare function sumSharedArrayBuffer(buf: SharedArrayBuffer): number
+
+export declare function takeExternalString(external: ExternalObject<string>): string
+
+export declare function takeFinalizeErrors(): Array<string>
+
+/** Remove the entry of the `key` from the `map` and return its value */
+export declare function takeMapValue(map: Map<any, any>, key: unknown): unknown | null
+
 export declare function testSerdeBigNumberPrecision(number: string): any
 
 export declare function testSerdeBufferBytes(obj: object): bigint
@@ -852,14 +1238,36 @@ export declare function throwAsyncError(): Promise<void>
 
 export declare function throwError(): void
 
+export declare function throwErrorWithCause(): void
+
+export declare function throwInEnvScope(): Promise<void>
+
+export declare function throwRangeError(error: string, code?: string | undefined | null): void
+
 export declare function throwSyntaxError(error: string, code?: string | undefined | null): void
 
+export declare function throwTypeError(error: string, code?: string | undefined | null): void
+
+/** Delete the `value` from the `set` if it's in the `set`, otherwise add it, returns if the `value` is in the `set` now */
+export declare function toggleSetValue(set: Set<any>, value: unknown): boolean
+
 export declare function toJsObj(): object
 
+export declare function toTransferableValue(value: unknown): Buffer
+
+/** Serialize the `value`, send the bytes to another thread and back, then deserialize them */
+export declare function transferValueThroughThread(value: unknown): unknown
+
+export declare function tryFindVisitCounter(label: string): VisitCounter | null
+
 export declare function tsfnAsyncCall(func: (arg0: number, arg1: number, arg2: number) => string): Promise<void>
 
+export declare function tsfnAsyncCallFatalMode(tsfn: ((arg: number) => number)): Promise<number>
+
 export declare function tsfnCallWithCallback(tsfn: ((err: Error | null, ) => string)): void
 
+export declare function tsfnCallWithTimeout(func: (arg: number) => void): Array<string>
+
 export declare function tsfnInEither(pet: Pet): void
 
 export declare function tsfnReturnPromise(func: ((err: Error | null, arg: number) => Promise<number>)): Promise<number>
@@ -885,6 +1293,8 @@ export interface TupleObject {
 export type TupleToArray =
   [field0: string, field1: number, field2?: Meta]
 
+export declare function typeTagObject(obj: object): void
+
 export declare function u16ArrayToArray(input: Uint16Array): Array<number>
 
 export declare function u32ArrayToArray(input: Uint32Array): Array<number>
@@ -895,6 +1305,12 @@ export declare function u8ArrayToArray(input: Uint8Array): Array<number>
 
 export declare function uInit8ArrayFromString(): Promise<Uint8Array>
 
+export declare function uint8ArraySubarray(input: Uint8Array, begin: number, end: number): Uint8Array
+
+export declare function upgradeRepo(): JsRepo | null
+
+export declare function upgradeRepoDir(): string | null
+
 export interface UseNullableStruct {
   requiredNumberField: number
   requiredStringField: string
@@ -907,6 +1323,8 @@ export interface UtcDates {
   end?: Date
 }
 
+export declare function utf16CodeUnits(s: string): Array<number>
+
 export declare function validateArray(arr: Array<number>): number
 
 export declare function validateBigint(input: bigint): bigint
@@ -953,6 +1371,8 @@ export declare function validateUint8ClampedSlice(input: Uint8ClampedArray): num
 
 export declare function validateUndefined(i: undefined): boolean
 
+export declare function validateUsername(name: string): string
+
 export type VoidNullable<T = void> =
   Nullable<T>
 
@@ -962,6 +1382,8 @@ export declare function withinAsyncRuntimeIfAvailable(): void
 
 export declare function withoutAbortController(a: number, b: number): Promise<number>
 
+export declare function wrapNativeLabel(obj: object, label: string): void
+
 export declare function xxh64Alias(input: Buffer): bigint
 
 export declare namespace xxh2 {
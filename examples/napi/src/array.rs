@@ -19,11 +19,42 @@ fn sum_nums(nums: Vec<u32>) -> u32 {
   nums.iter().sum()
 }
 
+#[napi]
+fn sum_array_to_vec(arr: Array<'_>) -> napi::Result<u32> {
+  Ok(arr.to_vec_with::<u32>()?.iter().sum())
+}
+
+#[napi]
+fn create_array_of_squares(env: &Env, n: u32) -> napi::Result<Array<'_>> {
+  env.create_array_from((0..n).map(|i| i * i).collect())
+}
+
+#[napi(ts_args_type = "arr: number[]", ts_return_type = "number[]")]
+/// Square the even numbers and skip the odd ones, fails if a square overflows `u32`
+fn square_even_numbers<'env>(env: &'env Env, arr: Array) -> napi::Result<Array<'env>> {
+  arr.map_collect(env, |n: u32| {
+    if n % 2 != 0 {
+      return Ok(None);
+    }
+    n.checked_mul(n).map(Some).ok_or_else(|| {
+      napi::Error::new(
+        napi::Status::InvalidArg,
+        format!("The square of {n} overflows u32"),
+      )
+    })
+  })
+}
+
 #[napi]
 fn get_tuple(val: (u32, String, u8)) -> u32 {
   val.0 + Into::<u32>::into(val.2)
 }
 
+#[napi]
+fn round_trip_tuple(val: (f64, String, bool)) -> (bool, String, f64) {
+  (val.2, val.1, val.0)
+}
+
 #[napi]
 fn to_js_obj(env: &Env) -> napi::Result<Object> {
   let mut arr = env.create_array(0)?;
@@ -25,6 +25,24 @@ async fn read_file_async(path: String) -> Result<Buffer> {
   }
 }
 
+#[napi]
+async fn spawn_on_runtime_handle(arg: u32) -> Result<u32> {
+  napi::tokio_runtime::handle()
+    .spawn(async move { arg * 2 })
+    .await
+    .map_err(|e| Error::new(Status::GenericFailure, format!("join error: {}", e)))
+}
+
+#[napi]
+/// Await the `promises` concurrently and sum their values
+pub fn sum_all_promises<'env>(
+  env: &'env Env,
+  promises: Vec<PromiseRaw<u32>>,
+) -> Result<PromiseRaw<'env, u32>> {
+  let all = env.await_all(promises);
+  env.spawn_future(async move { Ok(all.await?.into_iter().sum()) })
+}
+
 #[napi]
 async fn async_multi_two(arg: u32) -> Result<u32> {
   tokio::task::spawn(async move { Ok(arg * 2) })
@@ -32,11 +50,50 @@ async fn async_multi_two(arg: u32) -> Result<u32> {
     .unwrap()
 }
 
+#[napi]
+async fn stringify_in_env_scope(value: u32) -> Result<String> {
+  tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+  env_scope(move |env| {
+    let json: Object = env.get_global()?.get_named_property("JSON")?;
+    let stringify: Function<Object, String> = json.get_named_property("stringify")?;
+    let mut obj = Object::new(&env)?;
+    obj.set("value", value)?;
+    stringify.call(obj)
+  })
+  .await
+}
+
+#[napi]
+async fn throw_in_env_scope() -> Result<()> {
+  env_scope(|env| {
+    env.throw_error("Error thrown in env scope", None)?;
+    Err(Error::new(Status::GenericFailure, "Failed in env scope"))
+  })
+  .await
+}
+
 #[napi]
 async fn panic_in_async() {
   panic!("panic in async function");
 }
 
+static ABORTABLE_STEPS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+/// Count a step every 10ms, the future is dropped once the `signal` is aborted, so it stops counting
+#[napi]
+async fn count_until_aborted(steps: u32, _signal: Option<AbortSignal>) -> u32 {
+  for _ in 0..steps {
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    ABORTABLE_STEPS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+  }
+  steps
+}
+
+#[napi]
+fn get_abortable_steps() -> u32 {
+  ABORTABLE_STEPS.load(std::sync::atomic::Ordering::Relaxed)
+}
+
 #[napi(async_runtime)]
 pub fn within_async_runtime_if_available() {
   tokio::spawn(async {
@@ -32,3 +32,13 @@ pub fn bigint_from_i64() -> BigInt {
 pub fn bigint_from_i128() -> BigInt {
   BigInt::from(-100i128)
 }
+
+#[napi]
+pub fn bigint_i128_round_trip(value: i128) -> i128 {
+  value
+}
+
+#[napi]
+pub fn bigint_u128_round_trip(value: u128) -> u128 {
+  value
+}
@@ -1,9 +1,13 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use napi::{
   bindgen_prelude::{
-    Buffer, ClassInstance, JavaScriptClassExt, JsObjectValue, JsValue, ObjectFinalize, This,
-    Uint8Array, Unknown,
+    set_finalize_error_handler, AsyncObjectFinalize, Buffer, ClassInstance, FromNapiValue,
+    Function, JavaScriptClassExt, JsObjectValue, JsValue, Object, ObjectFinalize, SharedState,
+    This, Uint8Array, Unknown,
   },
-  Env, Property, PropertyAttributes, Result,
+  Env, Error, JsSymbol, Property, PropertyAttributes, Result,
 };
 
 use crate::r#enum::Kind;
@@ -234,6 +238,11 @@ impl NinjaTurtle {
     Self::instance_of(&env, &value)
   }
 
+  #[napi]
+  pub fn get_name_of(env: &Env, value: Unknown) -> Result<String> {
+    Ok(Self::cast_instance(env, &value)?.name.clone())
+  }
+
   /// Create your ninja turtle! 🐢
   #[napi(factory)]
   pub fn new_raph() -> Self {
@@ -368,6 +377,65 @@ pub fn receive_object_with_class_field(
   Ok(object.bird)
 }
 
+#[napi]
+pub fn downcast_bird_name(
+  #[napi(ts_arg_type = "Animal | Bird")] instance: ClassInstance<Animal>,
+) -> Result<String> {
+  Ok(instance.downcast::<Bird>()?.name.clone())
+}
+
+#[napi]
+pub fn compare_animals(
+  env: &Env,
+  a: ClassInstance<Animal>,
+  b: ClassInstance<Animal>,
+) -> Result<Vec<bool>> {
+  Ok(vec![a.ptr_eq(&b), a.js_eq(env, &b)?])
+}
+
+/// The `Env` of the instance is the `Env` of the call, it can be used without the `env` argument
+#[napi]
+pub fn class_instance_env_name(env: &Env, animal: ClassInstance<Animal>) -> Result<String> {
+  if animal.env().raw() != env.raw() {
+    return Err(Error::new(
+      napi::Status::GenericFailure,
+      "The env of the class instance is not the env of the call",
+    ));
+  }
+  animal
+    .env()
+    .create_string(&animal.name)?
+    .into_utf8()?
+    .into_owned()
+}
+
+/// Reads the same instance through two property lookups, so the two
+/// `ClassInstance`s have different `napi_value` handles to the same object.
+#[napi]
+pub fn compare_animal_handles(
+  env: &Env,
+  #[napi(ts_arg_type = "{ first: Animal, second: Animal }")] holder: Object,
+) -> Result<Vec<bool>> {
+  let first = holder.get_named_property::<ClassInstance<Animal>>("first")?;
+  let second = holder.get_named_property::<ClassInstance<Animal>>("second")?;
+  Ok(vec![
+    first.value == second.value,
+    first.ptr_eq(&second),
+    first.js_eq(env, &second)?,
+  ])
+}
+
+/// Pretend to be a C library which reads the `Animal` through the wrapped pointer
+extern "C" fn foreign_animal_name_length(animal: *mut std::ffi::c_void) -> u32 {
+  let animal = unsafe { &*animal.cast::<Animal>() };
+  animal.name.len() as u32
+}
+
+#[napi]
+pub fn get_animal_name_length_from_foreign(animal: ClassInstance<Animal>) -> u32 {
+  foreign_animal_name_length(unsafe { animal.as_raw_wrapped() })
+}
+
 #[napi(constructor)]
 pub struct NotWritableClass {
   #[napi(writable = false)]
@@ -411,6 +479,117 @@ impl ObjectFinalize for CustomFinalize {
   }
 }
 
+static FINALIZE_ERRORS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+#[napi(custom_finalize)]
+pub struct FailedFinalize {
+  name: String,
+}
+
+#[napi]
+impl FailedFinalize {
+  #[napi(constructor)]
+  pub fn new(name: String) -> Self {
+    Self { name }
+  }
+}
+
+impl ObjectFinalize for FailedFinalize {
+  fn finalize(self, _env: Env) -> Result<()> {
+    Err(Error::new(
+      napi::Status::GenericFailure,
+      format!("Failed to release {}", self.name),
+    ))
+  }
+}
+
+#[napi]
+pub fn collect_finalize_errors() {
+  set_finalize_error_handler(Box::new(|err| {
+    FINALIZE_ERRORS.lock().unwrap().push(err.reason.clone());
+  }));
+}
+
+/// Only collect the next finalize error, the handler is replaced by a no-op in itself
+#[napi]
+pub fn collect_next_finalize_error() {
+  set_finalize_error_handler(Box::new(|err| {
+    FINALIZE_ERRORS.lock().unwrap().push(err.reason.clone());
+    set_finalize_error_handler(Box::new(|_| {}));
+  }));
+}
+
+#[napi]
+pub fn take_finalize_errors() -> Vec<String> {
+  std::mem::take(&mut *FINALIZE_ERRORS.lock().unwrap())
+}
+
+/// Number of the alive `FailedFinalize` instances, it's always `0` in the release builds
+#[napi]
+pub fn live_failed_finalize_count() -> u32 {
+  #[cfg(debug_assertions)]
+  {
+    napi::bindgen_prelude::live_references_of::<FailedFinalize>() as u32
+  }
+  #[cfg(not(debug_assertions))]
+  {
+    0
+  }
+}
+
+#[napi(custom_inspect)]
+pub struct InspectablePoint {
+  x: i32,
+  y: i32,
+}
+
+#[napi]
+impl InspectablePoint {
+  #[napi(constructor)]
+  pub fn new(x: i32, y: i32) -> Self {
+    Self { x, y }
+  }
+}
+
+impl InspectablePoint {
+  fn inspect(&self) -> String {
+    format!("InspectablePoint({}, {})", self.x, self.y)
+  }
+}
+
+static ASYNC_FINALIZED_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[napi(async_finalize)]
+pub struct AsyncFinalize {
+  inner: Vec<u8>,
+}
+
+#[napi]
+impl AsyncFinalize {
+  #[napi(constructor)]
+  pub fn new(size: u32) -> Self {
+    Self {
+      inner: vec![0; size as usize],
+    }
+  }
+
+  #[napi]
+  pub fn finalized_count() -> u32 {
+    ASYNC_FINALIZED_COUNT.load(Ordering::Relaxed)
+  }
+}
+
+impl AsyncObjectFinalize for AsyncFinalize {
+  fn finalize_async(self, _env: Env) -> impl Future<Output = Result<()>> + Send + 'static {
+    async move {
+      tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+      drop(self.inner);
+      ASYNC_FINALIZED_COUNT.fetch_add(1, Ordering::Relaxed);
+      Ok(())
+    }
+  }
+}
+
 #[napi(constructor)]
 pub struct Width {
   pub value: i32,
@@ -421,6 +600,22 @@ pub fn plus_one(this: This<&Width>) -> i32 {
   this.object.value + 1
 }
 
+#[napi]
+pub fn assign_animal_to_symbol(
+  env: &Env,
+  mut this: This<Object>,
+  key: JsSymbol,
+  name: String,
+) -> Result<()> {
+  let animal = Animal {
+    kind: Kind::Cat,
+    name,
+  }
+  .into_instance(env)?;
+  animal.assign_to_this_symbol(&key, &mut this)?;
+  Ok(())
+}
+
 #[napi]
 pub struct GetterSetterWithClosures {}
 
@@ -451,6 +646,37 @@ impl GetterSetterWithClosures {
   }
 }
 
+static DROPPED_PROPERTY_CLOSURES: AtomicU32 = AtomicU32::new(0);
+
+struct ClosureDropGuard(u32);
+
+impl ClosureDropGuard {
+  fn value(&self) -> u32 {
+    self.0
+  }
+}
+
+impl Drop for ClosureDropGuard {
+  fn drop(&mut self) {
+    DROPPED_PROPERTY_CLOSURES.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+#[napi]
+pub fn create_object_with_getter_closure(env: &Env, value: u32) -> Result<Object<'_>> {
+  let guard = ClosureDropGuard(value);
+  let mut obj = Object::new(env)?;
+  obj.define_properties(&[Property::new()
+    .with_utf8_name("value")?
+    .with_getter_closure(move |_env, _this| Ok(guard.value()))])?;
+  Ok(obj)
+}
+
+#[napi]
+pub fn get_dropped_property_closures() -> u32 {
+  DROPPED_PROPERTY_CLOSURES.load(Ordering::Relaxed)
+}
+
 #[napi]
 pub struct CatchOnConstructor {}
 
@@ -498,4 +724,224 @@ impl<'scope> ClassWithLifetime<'scope> {
   pub fn get_name(&self) -> &str {
     self.inner.get_name()
   }
+
+  #[napi]
+  pub fn get_named_animal_name(&self, env: &Env, this: This, name: String) -> Result<String> {
+    let animal: ClassInstance<Animal> = this.get_named(env, &name)?;
+    Ok(animal.get_name().to_owned())
+  }
+}
+
+/// Large immutable bytes, exposed to JavaScript without copying
+#[napi]
+pub struct ImmutableBytes {
+  data: Vec<u8>,
+  label: String,
+}
+
+#[napi]
+impl ImmutableBytes {
+  #[napi(constructor)]
+  pub fn new(size: u32, label: String) -> Self {
+    Self {
+      data: (0..size).map(|i| i as u8).collect(),
+      label,
+    }
+  }
+
+  #[napi(getter, zero_copy)]
+  pub unsafe fn data(&self) -> &[u8] {
+    &self.data
+  }
+
+  #[napi(getter)]
+  pub fn copied_data(&self) -> &[u8] {
+    &self.data
+  }
+
+  #[napi(getter)]
+  pub fn label(&self) -> &str {
+    &self.label
+  }
+
+  #[napi]
+  pub fn checksum(&self) -> u32 {
+    self.data.iter().map(|b| *b as u32).sum()
+  }
+}
+
+static DROPPED_VISIT_COUNTERS: AtomicU32 = AtomicU32::new(0);
+
+#[napi]
+pub struct VisitCounter {
+  label: String,
+  visits: u32,
+}
+
+#[napi]
+impl VisitCounter {
+  #[napi(constructor)]
+  pub fn new(label: String) -> Self {
+    Self { label, visits: 0 }
+  }
+
+  #[napi(getter)]
+  pub fn label(&self) -> &str {
+    &self.label
+  }
+
+  #[napi(getter)]
+  pub fn visits(&self) -> u32 {
+    self.visits
+  }
+
+  #[napi]
+  pub fn visit(&mut self) -> u32 {
+    self.visits += 1;
+    self.visits
+  }
+}
+
+impl Drop for VisitCounter {
+  fn drop(&mut self) {
+    DROPPED_VISIT_COUNTERS.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+#[napi]
+/// Replace the state behind the `counter` with a fresh one labeled `label`, returns the visits of the old state
+pub fn reset_visit_counter(
+  env: &Env,
+  mut counter: ClassInstance<VisitCounter>,
+  label: String,
+) -> Result<u32> {
+  let old = counter.replace(VisitCounter::new(label));
+  // another `ClassInstance` of the same JavaScript object sees the new state
+  let reloaded =
+    unsafe { ClassInstance::<VisitCounter>::from_napi_value(env.raw(), counter.value)? };
+  assert_eq!(reloaded.visits, 0);
+  assert_eq!(reloaded.label, counter.label);
+  Ok(old.visits)
+}
+
+#[napi]
+pub fn get_dropped_visit_counters() -> u32 {
+  DROPPED_VISIT_COUNTERS.load(Ordering::Relaxed)
+}
+
+/// Returns `null` if the `label` is empty
+#[napi]
+pub fn find_visit_counter<'env>(
+  env: &'env Env,
+  label: String,
+) -> Option<ClassInstance<'env, VisitCounter>> {
+  if label.is_empty() {
+    return None;
+  }
+  VisitCounter::new(label).into_instance(env).ok()
+}
+
+#[napi]
+pub fn try_find_visit_counter<'env>(
+  env: &'env Env,
+  label: String,
+) -> Result<Option<ClassInstance<'env, VisitCounter>>> {
+  if label.is_empty() {
+    return Ok(None);
+  }
+  VisitCounter::new(label).into_instance(env).map(Some)
+}
+
+#[napi(object)]
+pub struct ChartOptions {
+  pub title: String,
+  pub width: u32,
+  pub height: u32,
+}
+
+#[napi]
+pub struct Chart {
+  title: String,
+  options_created: u32,
+}
+
+#[napi]
+impl Chart {
+  #[napi(constructor)]
+  pub fn new(title: String) -> Self {
+    Self {
+      title,
+      options_created: 0,
+    }
+  }
+
+  /// Created on the first read and cached on the instance, the following reads return the same object
+  #[napi(getter, cache)]
+  pub fn options(&mut self) -> ChartOptions {
+    self.options_created += 1;
+    ChartOptions {
+      title: self.title.clone(),
+      width: 800,
+      height: 600,
+    }
+  }
+
+  #[napi(getter)]
+  pub fn options_created(&self) -> u32 {
+    self.options_created
+  }
+}
+
+#[napi]
+pub struct SharedCounter {
+  count: SharedState<u32>,
+}
+
+#[napi]
+impl SharedCounter {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      count: SharedState::new(0),
+    }
+  }
+
+  /// Create a counter shares the count with this one
+  #[napi]
+  pub fn share(&self) -> Self {
+    Self {
+      count: self.count.clone(),
+    }
+  }
+
+  #[napi]
+  pub fn increment(&self) -> Result<u32> {
+    let mut count = self.count.borrow_mut()?;
+    *count += 1;
+    Ok(*count)
+  }
+
+  /// Increment after the `callback` returns, the count is mutably borrowed while calling it
+  #[napi]
+  pub fn increment_after(&self, callback: Function<(), ()>) -> Result<u32> {
+    let mut count = self.count.borrow_mut()?;
+    callback.call(())?;
+    *count += 1;
+    Ok(*count)
+  }
+
+  #[napi(getter)]
+  pub fn count(&self) -> Result<u32> {
+    Ok(*self.count.borrow()?)
+  }
+
+  #[napi(getter)]
+  pub fn share_count(&self) -> u32 {
+    self.count.share_count() as u32
+  }
+
+  #[napi]
+  pub fn shares_with(&self, other: &SharedCounter) -> bool {
+    self.count.ptr_eq(&other.count)
+  }
 }
@@ -28,6 +28,16 @@ fn chrono_date_add_1_minute(input: chrono::DateTime<Utc>) -> chrono::DateTime<Ut
   Duration::try_minutes(1).map(|d| input + d).unwrap()
 }
 
+#[napi]
+fn chrono_utc_date_round_trip(input: chrono::DateTime<Utc>) -> chrono::DateTime<Utc> {
+  input
+}
+
+#[napi]
+fn chrono_utc_date_with_sub_millis() -> chrono::DateTime<Utc> {
+  Utc.timestamp_nanos(1_644_435_115_396_789_123)
+}
+
 #[napi(object)]
 pub struct UtcDates {
   pub start: chrono::DateTime<Utc>,
@@ -26,6 +26,14 @@ fn either3(input: Either3<String, u32, bool>) -> u32 {
   }
 }
 
+#[napi]
+fn either3_variant(input: Either3<String, u32, bool>) -> String {
+  if input.is_c() {
+    return "bool".to_owned();
+  }
+  format!("{:?}", input.variant())
+}
+
 #[napi(object)]
 struct Obj {
   pub v: Either<String, u32>,
@@ -173,3 +181,20 @@ pub fn either_f64_or_u32(input: JsNumber) -> Result<Either<f64, u32>> {
     Ok(Either::A(input_f64))
   }
 }
+
+#[napi]
+fn describe_unknown(value: Unknown) -> Result<String> {
+  if let Some(n) = value.try_cast::<f64>()? {
+    return Ok(format!("number: {n}"));
+  }
+  if let Some(s) = value.try_cast::<String>()? {
+    return Ok(format!("string: {s}"));
+  }
+  if let Some(obj) = value.try_cast::<Object>()? {
+    return Ok(format!(
+      "object with keys: {}",
+      Object::keys(&obj)?.join(", ")
+    ));
+  }
+  Ok(format!("unsupported: {}", value.get_type()?))
+}
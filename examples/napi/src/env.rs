@@ -5,6 +5,11 @@ pub fn run_script(env: &Env, script: String) -> Result<Unknown> {
   env.run_script(script)
 }
 
+#[napi]
+pub fn run_script_with_filename(env: &Env, script: String, filename: String) -> Result<Unknown> {
+  env.run_script_with_filename(script, &filename)
+}
+
 #[napi]
 pub fn get_module_file_name(env: Env) -> Result<String> {
   env.get_module_file_name()
@@ -14,3 +19,94 @@ pub fn get_module_file_name(env: Env) -> Result<String> {
 pub fn throw_syntax_error(env: Env, error: String, code: Option<String>) {
   env.throw_syntax_error(error, code);
 }
+
+#[napi]
+pub fn throw_type_error(env: Env, error: String, code: Option<String>) -> Result<()> {
+  env.throw_type_error(&error, code.as_deref())
+}
+
+#[napi]
+pub fn throw_range_error(env: Env, error: String, code: Option<String>) -> Result<()> {
+  env.throw_range_error(&error, code.as_deref())
+}
+
+#[napi]
+pub fn adjust_external_memory(env: &Env, change_in_bytes: i64) -> Result<Vec<i64>> {
+  let before = env.adjust_external_memory(0)?;
+  let increased = env.adjust_external_memory(change_in_bytes)?;
+  let decreased = env.adjust_external_memory(-change_in_bytes)?;
+  Ok(vec![before, increased, decreased])
+}
+
+#[napi]
+pub fn structured_clone_value<'env>(env: &'env Env, value: Unknown) -> Result<Unknown<'env>> {
+  env.structured_clone(value)
+}
+
+#[napi]
+pub fn to_transferable_value(env: &Env, value: Unknown) -> Result<Buffer> {
+  env.to_transferable(value)
+}
+
+#[napi]
+pub fn json_stringify_value(env: &Env, value: Unknown) -> Result<Option<String>> {
+  env.json_stringify(value)
+}
+
+#[napi]
+pub fn json_parse_value<'env>(env: &'env Env, s: String) -> Result<Unknown<'env>> {
+  env.json_parse(&s)
+}
+
+#[napi]
+/// Serialize the `value`, send the bytes to another thread and back, then deserialize them
+pub fn transfer_value_through_thread<'env>(
+  env: &'env Env,
+  value: Unknown,
+) -> Result<Unknown<'env>> {
+  let bytes = env.to_transferable(value)?;
+  let bytes = std::thread::spawn(move || bytes)
+    .join()
+    .map_err(|_| Error::new(Status::GenericFailure, "Thread panicked"))?;
+  env.from_transferable(&bytes)
+}
+
+#[napi]
+/// Call `on_collected` with the `label` after the `target` is garbage collected
+pub fn on_object_collected(
+  env: &Env,
+  target: Object,
+  label: String,
+  on_collected: FunctionRef<String, ()>,
+) -> Result<()> {
+  let env = *env;
+  env.register_finalizer(target, label, move |label| {
+    // the finalizer runs in a later task of the event loop, so it's fine to call into JavaScript here
+    if let Ok(on_collected) = on_collected.borrow_back(&env) {
+      let _ = on_collected.call(label);
+    }
+  })
+}
+
+#[napi]
+/// Get the well known globals with the accessors of `Env`, the others are read with `Env::get_global_object`
+///
+/// The empty `name` returns the global object itself
+pub fn get_global_object<'env>(env: &'env Env, name: String) -> Result<Object<'env>> {
+  match name.as_str() {
+    "" => env.global(),
+    "globalThis" => env.global_this(),
+    "process" => env.process(),
+    "console" => env.console(),
+    _ => env.get_global_object(&name),
+  }
+}
+
+#[napi]
+/// Read the environment variable from `process.env`
+pub fn get_process_env_var(env: &Env, name: String) -> Result<Option<String>> {
+  env
+    .process()?
+    .get_named_property::<Object>("env")?
+    .get_named_property(&name)
+}
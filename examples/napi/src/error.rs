@@ -10,6 +10,16 @@ pub fn panic() {
   panic!("Don't panic");
 }
 
+#[napi]
+pub fn panic_with_formatted_message(value: u32) -> u32 {
+  panic!("Panic with value {}", value);
+}
+
+#[napi(no_catch_unwind)]
+pub fn panic_without_catch_unwind() {
+  panic!("Abort the process");
+}
+
 #[napi]
 pub fn receive_string(s: String) -> String {
   s
@@ -34,11 +44,57 @@ pub fn custom_status_code() -> Result<(), CustomError> {
   Err(Error::new(CustomError::Panic, "don't panic"))
 }
 
+#[napi]
+pub fn parse_with_anyhow(input: String) -> anyhow::Result<u32> {
+  Ok(input.parse()?)
+}
+
 #[napi]
 pub fn error_message_contains_null_byte(msg: Utf16String) -> Result<()> {
   Err(Error::new(Status::InvalidArg, msg))
 }
 
+#[napi]
+pub fn throw_error_with_cause() -> Result<()> {
+  Err(
+    Error::new(Status::InvalidArg, "Outer error")
+      .with_cause(Error::from_reason("Inner error").with_cause(Error::from_reason("Root cause"))),
+  )
+}
+
+#[derive(Debug)]
+struct ConfigError {
+  source: std::num::ParseIntError,
+}
+
+impl std::fmt::Display for ConfigError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Invalid config")
+  }
+}
+
+impl std::error::Error for ConfigError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(&self.source)
+  }
+}
+
+#[napi]
+pub fn parse_config(input: String) -> Result<u32> {
+  input
+    .parse()
+    .map_err(|source| Error::from_error(&ConfigError { source }))
+}
+
+#[napi(ts_return_type = "Error")]
+pub fn create_error_with_code<'env>(
+  env: &'env Env,
+  code: Option<String>,
+  msg: String,
+) -> Result<Object<'env>> {
+  env.create_error_with_code(code.as_deref(), &msg)
+}
+
 #[napi]
 pub async fn throw_async_error() -> Result<()> {
   Err(Error::new(Status::InvalidArg, "Async Error".to_owned()))
@@ -78,3 +134,68 @@ pub fn extends_javascript_error(env: Env, error_class: Function<String>) -> Resu
   env.throw(error_object)?;
   Ok(())
 }
+
+pub enum UsernameError {
+  Empty,
+  TooLong(usize),
+}
+
+impl AsJsError for UsernameError {
+  fn into_js_error(self) -> JsErrorKind {
+    match self {
+      UsernameError::Empty => JsErrorKind::TypeError(
+        Error::new("ERR_EMPTY_USERNAME".to_owned(), "Username is empty").into(),
+      ),
+      UsernameError::TooLong(len) => JsErrorKind::RangeError(
+        Error::new(
+          "ERR_USERNAME_TOO_LONG".to_owned(),
+          format!("Username is {len} characters long, at most 8 characters are allowed"),
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+#[napi]
+pub fn validate_username(name: String) -> std::result::Result<String, UsernameError> {
+  match name.chars().count() {
+    0 => Err(UsernameError::Empty),
+    len if len > 8 => Err(UsernameError::TooLong(len)),
+    _ => Ok(name),
+  }
+}
+
+#[napi]
+/// Call the callbacks one by one, the value thrown from a callback is recovered and collected in place of its return value
+pub fn call_and_recover_exceptions<'env>(
+  env: &'env Env,
+  callbacks: Vec<Function<(), Unknown>>,
+) -> Result<Vec<Unknown<'env>>> {
+  let global = env.get_global()?;
+  let mut results = Vec::with_capacity(callbacks.len());
+  for callback in callbacks {
+    let mut returned = std::ptr::null_mut();
+    // call the raw N-API directly, so the exception is left pending for the native code to handle
+    let status = unsafe {
+      napi::sys::napi_call_function(
+        env.raw(),
+        global.raw(),
+        callback.raw(),
+        0,
+        std::ptr::null(),
+        &mut returned,
+      )
+    };
+    if let Some(exception) = env.get_and_clear_last_exception()? {
+      results.push(exception);
+      continue;
+    }
+    if status != napi::sys::Status::napi_ok {
+      return Err(Error::new(Status::GenericFailure, "Call callback failed"));
+    }
+    results.push(unsafe { Unknown::from_raw_unchecked(env.raw(), returned) });
+  }
+  debug_assert!(!env.is_exception_pending()?);
+  Ok(results)
+}
@@ -20,6 +20,11 @@ pub fn mutate_external(external: &mut External<u32>, new_val: u32) {
   **external = new_val;
 }
 
+#[napi]
+pub fn take_external_string(env: &Env, external: &mut External<String>) -> Result<String> {
+  external.take(env)
+}
+
 #[napi]
 pub fn create_optional_external(size: Option<u32>) -> Option<External<u32>> {
   size.map(External::new)
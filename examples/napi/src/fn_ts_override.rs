@@ -1,4 +1,6 @@
-use napi::bindgen_prelude::{FnArgs, Function, JsObjectValue, Object, Result};
+use napi::bindgen_prelude::{
+  Env, FnArgs, Function, JsObjectValue, JsValue, Object, Result, Unknown,
+};
 
 #[napi(ts_args_type = "a: { foo: number }", ts_return_type = "string[]")]
 fn ts_rename(a: Object) -> Result<Object> {
@@ -34,3 +36,12 @@ fn override_whole_function_type(operation: String, a: i32, b: i32) -> i32 {
     _ => panic!("Invalid operation"),
   }
 }
+
+#[napi(ts_return_type = "{ kind: 'point', x: number, y: number }")]
+fn override_unknown_return_type(env: &Env, x: f64, y: f64) -> Result<Unknown<'_>> {
+  let mut point = Object::new(env)?;
+  point.set("kind", "point")?;
+  point.set("x", x)?;
+  point.set("y", y)?;
+  Ok(point.to_unknown())
+}
@@ -1,7 +1,7 @@
 #![allow(deprecated)]
 
 use napi::{
-  bindgen_prelude::{ClassInstance, FnArgs, Function, FunctionRef, PromiseRaw},
+  bindgen_prelude::{ClassInstance, FnArgs, Function, FunctionRef, Object, PromiseRaw},
   threadsafe_function::ThreadsafeFunctionCallMode,
   Env, Error, Result, Status,
 };
@@ -37,6 +37,31 @@ pub fn apply1(
   callback.apply(ctx, name)
 }
 
+#[napi(ts_args_type = "callback: (...args: number[]) => number, args: number[]")]
+pub fn call_with_spread_args(callback: Function<(), u32>, args: Vec<u32>) -> Result<u32> {
+  callback.call_spread(args)
+}
+
+#[napi(
+  ts_args_type = "callback: (this: object, ...args: string[]) => string, context: object, args: string[]"
+)]
+pub fn apply_with_spread_args(
+  callback: Function<(), String>,
+  context: Object,
+  args: Vec<String>,
+) -> Result<String> {
+  callback.apply_spread(context, args)
+}
+
+#[napi(ts_args_type = "callback: (...args: number[]) => number")]
+pub fn call_with_fast_paths(callback: Function<(), u32>) -> Result<Vec<u32>> {
+  Ok(vec![
+    callback.call0()?,
+    callback.call1(1)?,
+    callback.call2(1, 2)?,
+  ])
+}
+
 #[napi]
 pub fn call_function(cb: Function<(), u32>) -> Result<u32> {
   cb.call(())
@@ -140,3 +165,14 @@ pub fn build_threadsafe_function_from_function_callee_handle(
 
   Ok(())
 }
+
+/// Sum all the arguments, the number of them is not limited
+#[napi]
+pub fn sum_rest_args(#[napi(rest)] values: Vec<u32>) -> u32 {
+  values.iter().sum()
+}
+
+#[napi]
+pub fn join_rest_args(separator: String, #[napi(rest)] parts: Vec<String>) -> String {
+  parts.join(&separator)
+}
@@ -109,3 +109,20 @@ impl Generator for Fib3 {
     Some(self.current)
   }
 }
+
+#[napi]
+pub fn parse_numbers(input: String) -> IteratorGenerator<i32> {
+  IteratorGenerator::new(
+    input
+      .split(',')
+      .map(|part| {
+        part.trim().parse::<i32>().map_err(|err| {
+          Error::new(
+            Status::InvalidArg,
+            format!("Failed to parse `{}`: {}", part.trim(), err),
+          )
+        })
+      })
+      .collect::<Vec<_>>(),
+  )
+}
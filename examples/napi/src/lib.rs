@@ -41,6 +41,10 @@ pub const DEFAULT_COST: u32 = 12;
 #[napi(skip_typescript)]
 pub const TYPE_SKIPPED_CONST: u32 = 12;
 
+#[napi(lazy)]
+/// This const is created on the first access
+pub const LAZY_COST: u32 = 24;
+
 #[napi]
 pub fn shutdown_runtime() {
   #[cfg(all(target_family = "wasm", tokio_unstable))]
@@ -2,6 +2,8 @@ use rustc_hash::FxBuildHasher;
 use std::collections::{BTreeMap, HashMap};
 
 use indexmap::IndexMap;
+use napi::bindgen_prelude::{Env, Object, Result, Unknown};
+use napi::JsMap;
 
 #[napi]
 fn get_mapping() -> HashMap<String, u32> {
@@ -17,6 +19,11 @@ fn sum_mapping(nums: HashMap<String, u32>) -> u32 {
   nums.into_values().sum()
 }
 
+#[napi]
+fn sum_object_values(obj: Object) -> Result<u32> {
+  Ok(obj.to_hashmap::<u32>()?.into_values().sum())
+}
+
 #[napi]
 fn get_mapping_with_hasher() -> HashMap<String, u32, FxBuildHasher> {
   let mut map = HashMap::with_hasher(FxBuildHasher);
@@ -65,3 +72,37 @@ fn sum_index_mapping(nums: IndexMap<String, u32>) -> u32 {
 fn indexmap_passthrough(fixture: IndexMap<String, u32>) -> IndexMap<String, u32> {
   fixture
 }
+
+#[napi]
+pub fn count_words(env: &Env, words: Vec<String>) -> Result<JsMap<'_>> {
+  let mut counts = JsMap::new(env)?;
+  for word in words {
+    let count = counts.get::<_, u32>(word.as_str())?.unwrap_or(0);
+    counts.set(word.as_str(), count + 1)?;
+  }
+  Ok(counts)
+}
+
+#[napi]
+pub fn sum_map_values(map: JsMap) -> Result<f64> {
+  Ok(map.values::<f64>()?.into_iter().sum())
+}
+
+#[napi]
+/// Remove the entry of the `key` from the `map` and return its value
+pub fn take_map_value<'env>(mut map: JsMap<'env>, key: Unknown) -> Result<Option<Unknown<'env>>> {
+  let value = map.get(key)?;
+  map.delete(key)?;
+  Ok(value)
+}
+
+#[napi]
+pub fn describe_map_entries(map: JsMap) -> Result<Vec<String>> {
+  Ok(
+    map
+      .entries::<String, u32>()?
+      .into_iter()
+      .map(|(key, value)| format!("{key}={value}"))
+      .collect(),
+  )
+}
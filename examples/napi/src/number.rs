@@ -3,6 +3,11 @@ fn add(a: u32, b: u32) -> u32 {
   a + b
 }
 
+#[napi(lazy)]
+fn lazy_add(a: u32, b: u32) -> u32 {
+  a + b
+}
+
 #[napi(strict)]
 fn fibonacci(n: u32) -> u32 {
   match n {
@@ -1,10 +1,59 @@
-use napi::{bindgen_prelude::*, threadsafe_function::ThreadsafeFunction, JsGlobal, Result};
+use std::{
+  alloc::Layout,
+  sync::atomic::{AtomicU32, Ordering},
+};
+
+use napi::{
+  bindgen_prelude::*, set_tagged_object_allocator, threadsafe_function::ThreadsafeFunction,
+  JsGlobal, Result, TaggedObjectAllocator,
+};
 
 #[napi]
 fn list_obj_keys(obj: Object) -> Vec<String> {
   Object::keys(&obj).unwrap()
 }
 
+#[napi]
+fn list_obj_property_names(obj: Object, include_prototypes: bool) -> Result<Vec<String>> {
+  obj.property_names(
+    if include_prototypes {
+      KeyCollectionMode::IncludePrototypes
+    } else {
+      KeyCollectionMode::OwnOnly
+    },
+    KeyFilter::Enumerable,
+  )
+}
+
+const EXAMPLE_TYPE_TAG: TypeTag = TypeTag::new(0x1edf75a38336451d_a5ed9ce2e4c00c38);
+
+#[napi]
+fn type_tag_object(mut obj: Object) -> Result<()> {
+  obj.type_tag(&EXAMPLE_TYPE_TAG)
+}
+
+#[napi]
+fn is_type_tagged_object(obj: Object) -> Result<bool> {
+  obj.check_type_tag(&EXAMPLE_TYPE_TAG)
+}
+
+#[napi]
+fn create_frozen_config(env: &Env) -> Result<Object<'_>> {
+  let mut config = Object::new(env)?;
+  config.set("name", "napi-rs")?;
+  config.set("retries", 3)?;
+  config.freeze()?;
+  Ok(config)
+}
+
+#[napi]
+fn create_sealed_config(env: &Env) -> Result<Object<'_>> {
+  let mut config = Object::new(env)?;
+  config.set("retries", 3)?;
+  config.seal()?;
+  Ok(config)
+}
+
 #[napi]
 fn create_obj(env: &Env) -> Object {
   let mut obj = Object::new(env).unwrap();
@@ -106,6 +155,49 @@ fn getter_from_obj() -> u32 {
   42
 }
 
+#[napi(object)]
+pub struct JsPropertyDescriptor {
+  pub enumerable: bool,
+  pub configurable: bool,
+  pub writable: bool,
+  pub is_accessor: bool,
+  pub has_getter: bool,
+  pub has_setter: bool,
+}
+
+#[napi]
+pub fn get_property_descriptor(obj: Object, key: String) -> Result<Option<JsPropertyDescriptor>> {
+  Ok(obj.get_property_descriptor(&key)?.map(|descriptor| {
+    let PropertyDescriptor {
+      enumerable,
+      configurable,
+      writable,
+      is_accessor,
+      has_getter,
+      has_setter,
+    } = descriptor;
+    JsPropertyDescriptor {
+      enumerable,
+      configurable,
+      writable,
+      is_accessor,
+      has_getter,
+      has_setter,
+    }
+  }))
+}
+
+#[napi]
+pub fn object_has_own_property(obj: Object, key: String) -> Result<bool> {
+  obj.has_own_property(&key)
+}
+
+/// Same as `key in obj`, the properties from the prototype chain are included
+#[napi]
+pub fn object_has_named_property(obj: Object, key: String) -> Result<bool> {
+  obj.has_named_property(&key)
+}
+
 #[napi(object, object_to_js = false)]
 struct ObjectOnlyFromJs {
   pub count: u32,
@@ -183,3 +275,62 @@ pub fn get_null_byte_property(obj: Object) -> Result<Option<String>> {
 pub fn set_null_byte_property(mut obj: Object) -> Result<()> {
   obj.set("\0virtual", "test")
 }
+
+#[napi]
+pub fn merge_objects<'env>(env: &'env Env, first: Object, second: Object) -> Result<Object<'env>> {
+  let mut result = Object::new(env)?;
+  result.assign(env, &first)?;
+  result.assign(env, &second)?;
+  Ok(result)
+}
+
+struct CountingAllocator {
+  allocated: AtomicU32,
+  freed: AtomicU32,
+}
+
+unsafe impl TaggedObjectAllocator for CountingAllocator {
+  fn alloc(&self, layout: Layout) -> *mut u8 {
+    self.allocated.fetch_add(1, Ordering::Relaxed);
+    unsafe { std::alloc::alloc(layout) }
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    self.freed.fetch_add(1, Ordering::Relaxed);
+    unsafe { std::alloc::dealloc(ptr, layout) }
+  }
+}
+
+static COUNTING_ALLOCATOR: CountingAllocator = CountingAllocator {
+  allocated: AtomicU32::new(0),
+  freed: AtomicU32::new(0),
+};
+
+#[napi]
+pub fn install_counting_allocator() {
+  set_tagged_object_allocator(&COUNTING_ALLOCATOR);
+}
+
+#[napi]
+/// The number of the allocated and freed native objects since the `installCountingAllocator`
+pub fn get_native_allocations() -> Vec<u32> {
+  vec![
+    COUNTING_ALLOCATOR.allocated.load(Ordering::Relaxed),
+    COUNTING_ALLOCATOR.freed.load(Ordering::Relaxed),
+  ]
+}
+
+#[napi]
+pub fn wrap_native_label(mut obj: Object, label: String) -> Result<()> {
+  obj.wrap(label, None)
+}
+
+#[napi]
+pub fn get_native_label(obj: Object) -> Result<String> {
+  obj.unwrap::<String>().cloned()
+}
+
+#[napi]
+pub fn drop_native_label(mut obj: Object) -> Result<()> {
+  obj.drop_wrapped::<String>()
+}
@@ -34,3 +34,23 @@ pub fn esm_resolve<'env>(
 ) -> Result<PromiseRaw<'env, ()>> {
   next.call(())
 }
+
+#[napi]
+pub fn resolve_from_thread(env: &Env, value: Option<u32>) -> Result<PromiseRaw<'_, u32>> {
+  let (promise, resolver) = env.create_promise_with_resolver()?;
+  std::thread::spawn(move || {
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    match value {
+      Some(value) => resolver.resolve(value),
+      None => resolver.reject(Error::new(Status::InvalidArg, "No value to resolve")),
+    }
+  });
+  Ok(promise)
+}
+
+#[napi]
+pub fn drop_resolver_from_thread(env: &Env) -> Result<PromiseRaw<'_, u32>> {
+  let (promise, resolver) = env.create_promise_with_resolver()?;
+  std::thread::spawn(move || drop(resolver));
+  Ok(promise)
+}
@@ -1,7 +1,15 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+  cell::RefCell,
+  rc::Rc,
+  sync::atomic::{AtomicU32, Ordering},
+};
 
 use napi::bindgen_prelude::*;
 
+thread_local! {
+  static WEAK_REPO: RefCell<Option<WeakReference<JsRepo>>> = const { RefCell::new(None) };
+}
+
 pub struct Repository {
   dir: String,
 }
@@ -42,6 +50,73 @@ impl JsRepo {
       inner: reference.share_with(env, |repo| Ok(repo.inner.remote()))?,
     })
   }
+
+  /// The returned `External` keeps the repository alive, the branch is dropped with the repository
+  #[napi(ts_return_type = "ExternalObject<'Branch'>")]
+  pub fn branch<'env>(
+    &self,
+    reference: Reference<JsRepo>,
+    env: &'env Env,
+    name: String,
+  ) -> Result<Unknown<'env>> {
+    reference.create_linked_external(
+      env,
+      Branch {
+        dir: self.inner.dir.clone(),
+        name,
+      },
+    )
+  }
+
+  /// Keep the repository alive until `release` is called
+  #[napi]
+  pub fn retain(&self, env: Env, reference: Reference<JsRepo>) -> Result<u32> {
+    reference.reference(env)
+  }
+
+  #[napi]
+  pub fn release(&self, env: Env, reference: Reference<JsRepo>) -> Result<u32> {
+    reference.unreference(env)
+  }
+
+  #[napi]
+  pub fn store_weak(&self, reference: Reference<JsRepo>) {
+    WEAK_REPO.with(|weak| weak.replace(Some(reference.downgrade())));
+  }
+
+  #[napi]
+  pub fn dir(&self) -> String {
+    self.inner.dir.clone()
+  }
+}
+
+#[napi]
+pub fn bind_repo_dir(env: &Env, dir: String) -> Result<Function<'_, (), String>> {
+  let reference = JsRepo::new(dir).into_reference(*env)?;
+  reference.bind_method(env, "dir")
+}
+
+#[napi]
+pub fn upgrade_repo_dir(env: &Env) -> Result<Option<String>> {
+  WEAK_REPO.with(|weak| {
+    let weak = weak.borrow();
+    let Some(weak) = weak.as_ref() else {
+      return Ok(None);
+    };
+    Ok(
+      weak
+        .upgrade_instance(env)?
+        .map(|repo| repo.inner.dir.clone()),
+    )
+  })
+}
+
+#[napi]
+pub fn upgrade_repo(env: Env) -> Result<Option<Reference<JsRepo>>> {
+  WEAK_REPO.with(|weak| match weak.borrow().as_ref() {
+    Some(weak) => weak.upgrade(env),
+    None => Ok(None),
+  })
 }
 
 #[napi]
@@ -160,3 +235,136 @@ impl CSSStyleSheet {
     })
   }
 }
+
+#[napi]
+pub struct GraphNode {
+  #[napi(readonly)]
+  pub id: u32,
+}
+
+#[napi]
+pub struct Graph {
+  nodes: ReferenceCache<u32, GraphNode>,
+}
+
+#[napi]
+impl Graph {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Graph {
+      nodes: ReferenceCache::new(),
+    }
+  }
+
+  #[napi]
+  pub fn node<'env>(&mut self, env: &'env Env, id: u32) -> Result<ClassInstance<'env, GraphNode>> {
+    self
+      .nodes
+      .get_or_insert_with(env, id, || Ok(GraphNode { id }))
+  }
+
+  #[napi(getter)]
+  pub fn cached_nodes(&mut self) -> u32 {
+    self.nodes.evict_dead();
+    self.nodes.len() as u32
+  }
+}
+
+/// Hold any JavaScript value beyond the call which passes it in
+#[napi]
+pub struct PersistentValue {
+  inner: napi::Ref<Unknown<'static>>,
+}
+
+#[napi]
+impl PersistentValue {
+  /// The `value` could be garbage collected if the `ref_count` is `0`
+  #[napi(constructor)]
+  pub fn new(env: &Env, value: Unknown<'static>, ref_count: u32) -> Result<Self> {
+    Ok(PersistentValue {
+      inner: napi::Ref::new_with_ref_count(env, &value, ref_count)?,
+    })
+  }
+
+  #[napi]
+  pub fn get(&self, env: &Env) -> Result<Unknown<'static>> {
+    self.inner.get_value(env)
+  }
+
+  #[napi]
+  pub fn reference(&mut self, env: &Env) -> Result<u32> {
+    self.inner.reference(env)
+  }
+
+  #[napi]
+  pub fn unref(&mut self, env: &Env) -> Result<u32> {
+    self.inner.unreference(env)
+  }
+}
+
+/// Create a `Ref` with the `ref_count` and drop it after `unref`, returns the ref count after `unref`
+#[napi]
+pub fn drop_ref_after_unref(env: &Env, value: Unknown, ref_count: u32) -> Result<u32> {
+  let mut value_ref = napi::Ref::new_with_ref_count(env, &value, ref_count)?;
+  value_ref.unreference(env)
+}
+
+static DROPPED_BRANCHES: AtomicU32 = AtomicU32::new(0);
+
+pub struct Branch {
+  dir: String,
+  name: String,
+}
+
+impl Drop for Branch {
+  fn drop(&mut self) {
+    DROPPED_BRANCHES.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+#[napi(ts_args_type = "branch: ExternalObject<'Branch'>")]
+pub fn get_branch_ref(branch: &External<Branch>) -> String {
+  format!("{}:{}", branch.dir, branch.name)
+}
+
+#[napi]
+pub fn get_dropped_branches() -> u32 {
+  DROPPED_BRANCHES.load(Ordering::Relaxed)
+}
+
+#[napi]
+pub struct LeakProbe {}
+
+#[napi]
+impl LeakProbe {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    LeakProbe {}
+  }
+}
+
+/// Number of the alive class instances, it's always `0` in the release builds
+#[napi]
+pub fn live_class_instance_count() -> u32 {
+  #[cfg(debug_assertions)]
+  {
+    napi::bindgen_prelude::reference_count() as u32
+  }
+  #[cfg(not(debug_assertions))]
+  {
+    0
+  }
+}
+
+/// Number of the alive `LeakProbe` instances, it's always `0` in the release builds
+#[napi]
+pub fn live_leak_probe_count() -> u32 {
+  #[cfg(debug_assertions)]
+  {
+    napi::bindgen_prelude::live_references_of::<LeakProbe>() as u32
+  }
+  #[cfg(not(debug_assertions))]
+  {
+    0
+  }
+}
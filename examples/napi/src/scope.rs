@@ -60,3 +60,28 @@ pub fn shorter_escapable_scope<'env>(
   }
   Ok(longest_string)
 }
+
+#[napi]
+pub fn sum_in_escapable_scope(env: &Env, count: u32) -> Result<Object<'_>> {
+  env.with_escapable_scope(|scope| {
+    let mut sum = 0;
+    for i in 0..count {
+      // released when the scope is closed
+      let mut temp = Object::new(env)?;
+      temp.set("value", i)?;
+      sum += temp.get::<u32>("value")?.unwrap_or(0);
+    }
+    let mut result = Object::new(env)?;
+    result.set("sum", sum)?;
+    scope.escape(result)
+  })
+}
+
+#[napi]
+pub fn escape_twice(env: &Env) -> Result<()> {
+  env.with_escapable_scope(|scope| {
+    scope.escape(env.create_string("first")?)?;
+    scope.escape(env.create_string("second")?)?;
+    Ok(())
+  })
+}
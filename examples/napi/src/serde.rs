@@ -49,6 +49,37 @@ fn test_serde_buffer_bytes(obj: Object, env: Env) -> napi::Result<usize> {
   Ok(obj.code.len())
 }
 
+#[derive(Serialize)]
+struct LabeledObject {
+  name: String,
+  labels: std::collections::HashMap<String, u32>,
+  version: u32,
+}
+
+fn create_labeled_object() -> LabeledObject {
+  // the `HashMap` is seeded randomly, the iteration order is different on every call
+  let labels = ["zeta", "alpha", "10", "mid", "2"]
+    .into_iter()
+    .enumerate()
+    .map(|(i, key)| (key.to_owned(), i as u32))
+    .collect();
+  LabeledObject {
+    name: "labeled".to_owned(),
+    labels,
+    version: 1,
+  }
+}
+
+#[napi]
+fn serialize_labeled_object(env: &Env) -> Result<Unknown<'_>> {
+  env.to_js_value(&create_labeled_object())
+}
+
+#[napi]
+fn serialize_labeled_object_with_sorted_keys(env: &Env) -> Result<Unknown<'_>> {
+  env.to_js_value_with_sorted_keys(&create_labeled_object())
+}
+
 #[napi]
 struct PackageJsonReader {
   i: Value,
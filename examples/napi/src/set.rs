@@ -1,6 +1,9 @@
 use rustc_hash::FxBuildHasher;
 use std::collections::{BTreeSet, HashSet};
 
+use napi::bindgen_prelude::{Env, Result, Unknown};
+use napi::JsSet;
+
 #[napi]
 pub fn pass_set_to_rust(set: HashSet<String>) {
   assert_eq!(set.len(), 3);
@@ -43,3 +46,55 @@ pub fn btree_set_to_js() -> BTreeSet<String> {
   set.insert("c".to_string());
   set
 }
+
+#[napi]
+pub fn create_unique_set(env: &Env, values: Vec<u32>) -> Result<JsSet<'_>> {
+  let mut set = JsSet::new(env)?;
+  for value in values {
+    set.add(value)?;
+  }
+  Ok(set)
+}
+
+#[napi]
+/// Delete the `value` from the `set` if it's in the `set`, otherwise add it, returns if the `value` is in the `set` now
+pub fn toggle_set_value(mut set: JsSet, value: Unknown) -> Result<bool> {
+  if !set.delete(value)? {
+    set.add(value)?;
+  }
+  set.has(value)
+}
+
+#[napi]
+pub fn get_set_values(set: JsSet) -> Result<Vec<u32>> {
+  set.values()
+}
+
+#[napi]
+pub fn strict_equals_values(a: Unknown, b: Unknown) -> Result<bool> {
+  a.strict_equals(&b)
+}
+
+#[napi]
+pub fn loose_equals_values(a: Unknown, b: Unknown) -> Result<bool> {
+  a.loose_equals(&b)
+}
+
+/// Remove the duplicated values with `===`, the first one is kept
+#[napi]
+pub fn dedup_strict_equal<'env>(values: Vec<Unknown<'env>>) -> Result<Vec<Unknown<'env>>> {
+  let mut unique: Vec<Unknown> = Vec::with_capacity(values.len());
+  for value in values {
+    let mut duplicated = false;
+    for existed in &unique {
+      if existed.strict_equals(&value)? {
+        duplicated = true;
+        break;
+      }
+    }
+    if !duplicated {
+      unique.push(value);
+    }
+  }
+  Ok(unique)
+}
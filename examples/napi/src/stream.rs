@@ -1,4 +1,10 @@
+use std::sync::{
+  atomic::{AtomicU32, Ordering},
+  Arc,
+};
+
 use bytes::BytesMut;
+use futures::Stream;
 use napi::bindgen_prelude::*;
 use tokio::sync::mpsc::error::TrySendError;
 use tokio_stream::{wrappers::ReceiverStream, StreamExt};
@@ -82,3 +88,45 @@ pub fn create_readable_stream_from_class<'env>(
     ReceiverStream::new(rx),
   )
 }
+
+#[napi]
+pub async fn count_up_stream(to: u32, fail_at: Option<u32>) -> impl Stream<Item = Result<u32>> {
+  futures::stream::unfold(0, move |n| async move {
+    if n >= to {
+      return None;
+    }
+    if Some(n) == fail_at {
+      return Some((Err(Error::from_reason(format!("Failed at {}", n))), to));
+    }
+    Some((Ok(n), n + 1))
+  })
+}
+
+#[napi]
+pub struct PullCounter {
+  pulled: Arc<AtomicU32>,
+}
+
+#[napi]
+impl PullCounter {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      pulled: Arc::new(AtomicU32::new(0)),
+    }
+  }
+
+  #[napi(getter)]
+  pub fn pulled(&self) -> u32 {
+    self.pulled.load(Ordering::SeqCst)
+  }
+
+  #[napi]
+  pub async fn numbers(&self, to: u32) -> impl Stream<Item = Result<u32>> {
+    let pulled = self.pulled.clone();
+    futures::stream::iter(0..to).map(move |n| {
+      pulled.fetch_add(1, Ordering::SeqCst);
+      Ok(n)
+    })
+  }
+}
@@ -1,4 +1,4 @@
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, JsString};
 
 #[napi]
 fn contains(source: String, target: String) -> bool {
@@ -26,9 +26,60 @@ pub fn roundtrip_str(s: String) -> String {
   s
 }
 
+#[napi]
+pub fn return_str_contains_null_byte() -> &'static str {
+  "Hello\0from &str"
+}
+
 #[napi]
 pub fn return_c_string() -> RawCString {
   let mock_c_string = b"Hello from C string!\0";
   let mock_c_string_ptr = mock_c_string.as_ptr().cast();
   RawCString::new(mock_c_string_ptr, NAPI_AUTO_LENGTH)
 }
+
+#[napi]
+pub fn utf16_code_units(s: JsString) -> Result<Vec<u16>> {
+  s.as_utf16_vec()
+}
+
+#[napi]
+/// Count the lone surrogates of all strings, reusing one buffer for the UTF-16 code units
+pub fn count_lone_surrogates(strings: Vec<JsString>) -> Result<u32> {
+  let mut buf = Vec::new();
+  let mut count = 0;
+  for s in strings {
+    count += s.with_utf16(&mut buf, |units| {
+      char::decode_utf16(units.iter().copied())
+        .filter(|c| c.is_err())
+        .count() as u32
+    })?;
+  }
+  Ok(count)
+}
+
+#[napi]
+pub fn decode_latin1(bytes: Buffer) -> Latin1String {
+  Latin1String::from(bytes.to_vec())
+}
+
+#[napi]
+pub fn encode_latin1(s: Latin1String) -> Buffer {
+  s.to_vec().into()
+}
+
+static LEGACY_LABEL: &[u8] = b"Caf\xe9 \xa9 \xb5 \xff";
+
+#[napi]
+pub fn return_latin1_bytes() -> Latin1Bytes<'static> {
+  Latin1Bytes(LEGACY_LABEL)
+}
+
+#[napi]
+pub fn return_utf8_bytes(with_invalid_byte: bool) -> Utf8Bytes<'static> {
+  if with_invalid_byte {
+    Utf8Bytes(b"Rust \xf0\x9f\xa6\x80 \xff")
+  } else {
+    Utf8Bytes("Rust 🦀 é".as_bytes())
+  }
+}
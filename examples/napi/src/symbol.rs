@@ -16,3 +16,13 @@ pub fn create_symbol() -> Symbol {
 pub fn create_symbol_for(desc: String) -> Symbol {
   Symbol::for_desc(desc)
 }
+
+#[napi]
+pub fn create_symbol_for_key<'env>(env: &'env Env, key: String) -> Result<JsSymbol<'env>> {
+  JsSymbol::for_key(env, &key)
+}
+
+#[napi]
+pub fn get_symbol_description(symbol: JsSymbol) -> Result<Option<String>> {
+  symbol.description()
+}
@@ -1,6 +1,6 @@
 use std::thread::sleep;
 
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, ProgressReporter};
 
 struct DelaySum(u32, u32);
 
@@ -29,6 +29,51 @@ fn with_abort_controller(a: u32, b: u32, signal: AbortSignal) -> AsyncTask<Delay
   AsyncTask::with_signal(DelaySum(a, b), signal)
 }
 
+struct CountWithProgress(u32);
+
+#[napi]
+impl napi::Task for CountWithProgress {
+  type Output = u32;
+  type JsValue = u32;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    self.compute_with_progress(&ProgressReporter::default())
+  }
+
+  fn compute_with_progress(&mut self, progress: &ProgressReporter) -> Result<Self::Output> {
+    let mut count = 0;
+    while count < self.0 && !progress.is_aborted() {
+      sleep(std::time::Duration::from_millis(10));
+      count += 1;
+      progress.report(count as f64 / self.0 as f64);
+    }
+    Ok(count)
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+fn count_with_progress(
+  steps: u32,
+  on_progress: Function<f64, ()>,
+  signal: Option<AbortSignal>,
+) -> Result<AsyncTask<CountWithProgress>> {
+  AsyncTask::with_optional_signal(CountWithProgress(steps), signal).on_progress(&on_progress)
+}
+
+#[napi]
+fn count_with_any_signal(
+  steps: u32,
+  on_progress: Function<f64, ()>,
+  signals: Vec<AbortSignal>,
+) -> Result<AsyncTask<CountWithProgress>> {
+  AsyncTask::with_signal(CountWithProgress(steps), AbortSignal::any(&signals))
+    .on_progress(&on_progress)
+}
+
 struct AsyncTaskVoidReturn {}
 
 #[napi]
@@ -93,3 +138,31 @@ impl Task for AsyncTaskReadFile {
 pub fn async_task_read_file(path: String) -> AsyncTask<AsyncTaskReadFile> {
   AsyncTask::new(AsyncTaskReadFile { path })
 }
+
+struct HashBuffer(Buffer);
+
+#[napi]
+impl napi::Task for HashBuffer {
+  type Output = u64;
+  type JsValue = String;
+
+  /// FNV-1a 64 bit hash of the buffer
+  fn compute(&mut self) -> Result<Self::Output> {
+    Ok(self.0.iter().fold(0xcbf29ce484222325, |hash, byte| {
+      (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    }))
+  }
+
+  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(format!("{:016x}", output))
+  }
+}
+
+#[napi]
+fn hash_buffer(
+  env: &Env,
+  data: Buffer,
+  #[napi(ts_arg_type = "AbortSignal | undefined | null")] signal: Option<Unknown>,
+) -> Result<AsyncTask<HashBuffer>> {
+  env.spawn_task(HashBuffer(data), signal)
+}
@@ -2,7 +2,10 @@ use std::{sync::Arc, thread, time::Duration};
 
 use napi::{
   bindgen_prelude::*,
-  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode, UnknownReturnValue},
+  threadsafe_function::{
+    CoalescingThreadsafeFunction, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+    UnknownReturnValue,
+  },
   Ref,
 };
 
@@ -97,6 +100,27 @@ pub fn threadsafe_function_build_throw_error_with_status(cb: Function<'static>)
   Ok(())
 }
 
+#[napi]
+pub fn tsfn_call_with_timeout(func: Function<u32, ()>) -> Result<Vec<String>> {
+  let tsfn = func
+    .build_threadsafe_function()
+    .max_queue_size::<1>()
+    .build()?;
+  // The JavaScript thread is blocked by `join` so the queue can't be drained
+  thread::spawn(move || {
+    let first = tsfn.call_with_timeout(1, Duration::from_millis(10));
+    let queue_size = tsfn.queue_size();
+    let second = tsfn.call_with_timeout(2, Duration::from_millis(10));
+    vec![
+      first.to_string(),
+      queue_size.to_string(),
+      second.to_string(),
+    ]
+  })
+  .join()
+  .map_err(|_| Error::new(Status::GenericFailure, "Thread panicked"))
+}
+
 #[napi]
 pub fn threadsafe_function_fatal_mode(
   cb: ThreadsafeFunction<bool, UnknownReturnValue, bool, Status, false>,
@@ -140,6 +164,32 @@ fn threadsafe_function_closure_capture(
   Ok(())
 }
 
+#[napi]
+pub fn release_tsfn_in_callback(callback: Function<u32, ()>, times: u32) -> Result<()> {
+  for n in 0..times {
+    let tsfn = Arc::new(
+      callback
+        .build_threadsafe_function()
+        .callee_handled::<false>()
+        .build()?,
+    );
+    let tsfn_in_callback = tsfn.clone();
+    let call = move || {
+      tsfn.call_with_return_value(n, ThreadsafeFunctionCallMode::NonBlocking, move |_, _| {
+        // the last reference may be dropped inside the callback of the ThreadsafeFunction itself
+        drop(tsfn_in_callback);
+        Ok(())
+      });
+    };
+    if n % 2 == 0 {
+      call();
+    } else {
+      thread::spawn(call);
+    }
+  }
+  Ok(())
+}
+
 #[napi]
 pub fn tsfn_call_with_callback(tsfn: ThreadsafeFunction<(), String>) -> napi::Result<()> {
   tsfn.call_with_return_value(
@@ -239,6 +289,14 @@ pub fn call_async_with_unknown_return_value<'env>(
   )
 }
 
+#[napi]
+pub async fn tsfn_async_call_fatal_mode(
+  tsfn: ThreadsafeFunction<u32, u32, u32, Status, false>,
+) -> Result<u32> {
+  let value = tsfn.call_async(21).await?;
+  Ok(value * 2)
+}
+
 #[napi]
 pub async fn tsfn_throw_from_js(tsfn: ThreadsafeFunction<u32, Promise<u32>>) -> napi::Result<u32> {
   tsfn.call_async(Ok(42)).await?.await
@@ -282,3 +340,15 @@ pub fn tsfn_in_either(pet: Pet) {
     });
   }
 }
+
+#[napi]
+pub fn coalesce_progress(on_progress: CoalescingThreadsafeFunction<u32>, total: u32) {
+  // the JavaScript thread waits for all the calls, so none of them is delivered before the last one
+  thread::spawn(move || {
+    for progress in 0..=total {
+      on_progress.call_latest(progress);
+    }
+  })
+  .join()
+  .expect("Coalesce progress thread panicked");
+}
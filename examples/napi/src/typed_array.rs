@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
 use napi::bindgen_prelude::*;
 
@@ -24,6 +24,42 @@ fn get_empty_buffer() -> Buffer {
   vec![].into()
 }
 
+#[napi]
+fn get_pooled_buffers(count: u32) -> Vec<Buffer> {
+  (0..count)
+    .map(|i| Buffer::from_vec_pooled(format!("Buffer {i}").into_bytes()))
+    .collect()
+}
+
+static BUFFER_POOL: LazyLock<BufferPool> = LazyLock::new(|| BufferPool::new(16));
+
+#[napi]
+fn get_buffer_from_pool(len: u32) -> Buffer {
+  let mut data = BUFFER_POOL.checkout(len as usize);
+  data.extend((0..len).map(|i| i as u8));
+  data.into()
+}
+
+#[napi]
+fn get_buffer_pool_count() -> u32 {
+  BUFFER_POOL.pooled_count() as u32
+}
+
+#[napi]
+fn get_zero_copy_buffer(len: u32) -> Buffer {
+  // the spare capacity must be freed along with the data in the finalizer
+  let mut data = Vec::with_capacity(len as usize * 2);
+  data.extend((0..len).map(|i| i as u8));
+  Buffer::from_vec_zero_copy(data)
+}
+
+#[napi]
+fn get_buffer_slice_with_spare_capacity(env: &Env, len: u32) -> Result<BufferSlice<'_>> {
+  let mut data = Vec::with_capacity(len as usize * 2);
+  data.extend((0..len).map(|i| i as u8));
+  BufferSlice::from_data(env, data)
+}
+
 #[napi]
 pub fn create_external_buffer_slice(env: &Env) -> Result<BufferSlice> {
   let mut data = String::from("Hello world").as_bytes().to_vec();
@@ -65,11 +101,59 @@ fn mutate_typed_array(mut input: Float32Array) {
   }
 }
 
+#[napi]
+fn mutate_float64_array(
+  env: &Env,
+  mut input: Float64Array,
+  before_write: Option<Function<(), ()>>,
+) -> Result<()> {
+  if let Some(before_write) = before_write {
+    before_write.call(())?;
+  }
+  for item in input.as_mut_slice(env)? {
+    *item *= 2.0;
+  }
+  Ok(())
+}
+
 #[napi]
 fn deref_uint8_array(a: Uint8Array, b: Uint8ClampedArray) -> u32 {
   (a.len() + b.len()) as u32
 }
 
+#[napi]
+/// The trace of a 4x4 matrix in column-major order
+fn matrix4_trace(matrix: Float64Array) -> Result<f64> {
+  let m = matrix.to_array::<16>()?;
+  Ok(m[0] + m[5] + m[10] + m[15])
+}
+
+#[napi]
+fn rgba_to_hex(color: Uint8ClampedArray) -> Result<String> {
+  let [r, g, b, a] = color.to_array()?;
+  Ok(format!("#{r:02x}{g:02x}{b:02x}{a:02x}"))
+}
+
+#[napi]
+fn uint8_array_subarray(input: Uint8Array, begin: u32, end: u32) -> Result<Uint8Array> {
+  input.subarray(begin as usize, end as usize)
+}
+
+#[napi]
+/// Fill the elements from `begin` to `end` of the `input` with `value` through a subarray
+fn fill_float32_array_range(input: Float32Array, begin: u32, end: u32, value: f64) -> Result<()> {
+  let mut range = input.subarray(begin as usize, end as usize)?;
+  for item in unsafe { range.as_mut() } {
+    *item = value as f32;
+  }
+  Ok(())
+}
+
+#[napi]
+fn subarray_of_rust_data() -> Result<Uint8Array> {
+  Uint8Array::new(vec![1, 2, 3]).subarray(0, 1)
+}
+
 #[napi]
 async fn buffer_pass_through(buf: Buffer) -> Result<Buffer> {
   Ok(buf)
@@ -107,6 +191,86 @@ fn create_arraybuffer(env: &Env) -> Result<ArrayBuffer> {
   Ok(buf)
 }
 
+#[napi]
+fn detach_arraybuffer(buf: ArrayBuffer) -> Result<bool> {
+  buf.detach()?;
+  // `ArrayBuffer` is `Copy`, the copy is detached too
+  Ok(buf.is_detached()? && buf.as_slice().is_err())
+}
+
+#[napi]
+fn sum_arraybuffer_after(buf: ArrayBuffer, before_sum: Function<(), ()>) -> Result<u32> {
+  // `before_sum` may detach the `ArrayBuffer`
+  before_sum.call(())?;
+  Ok(buf.as_slice()?.iter().map(|v| *v as u32).sum())
+}
+
+#[napi]
+fn fill_arraybuffer_after(
+  mut buf: ArrayBuffer,
+  value: u8,
+  before_fill: Function<(), ()>,
+) -> Result<()> {
+  before_fill.call(())?;
+  buf.as_mut_slice()?.fill(value);
+  Ok(())
+}
+
+#[napi]
+fn create_shared_array_buffer(env: &Env, length: u32, value: u8) -> Result<SharedArrayBuffer<'_>> {
+  let mut buf = SharedArrayBuffer::new(env, length as usize)?;
+  // the `SharedArrayBuffer` is not shared with any other thread yet
+  unsafe { buf.as_mut_slice() }.fill(value);
+  Ok(buf)
+}
+
+#[napi]
+fn sum_shared_array_buffer(buf: SharedArrayBuffer) -> u32 {
+  // the worker threads have finished writing before calling into this function
+  unsafe { buf.as_slice() }.iter().map(|v| *v as u32).sum()
+}
+
+#[napi(object)]
+pub struct PacketHeader {
+  pub kind: u8,
+  pub flags: i16,
+  pub length: u32,
+  pub timestamp: BigInt,
+  pub ratio: f64,
+}
+
+/// `kind: u8`, `flags: i16` in big endian, `length: u32`, `timestamp: u64` and `ratio: f64` in little endian
+#[napi]
+fn parse_packet_header(view: DataView) -> Result<PacketHeader> {
+  Ok(PacketHeader {
+    kind: view.get_u8(0)?,
+    flags: view.get_i16(1, false)?,
+    length: view.get_u32(3, true)?,
+    timestamp: view.get_u64(7, true)?.into(),
+    ratio: view.get_f64(15, true)?,
+  })
+}
+
+#[napi]
+fn create_packet_header(env: &Env, header: PacketHeader) -> Result<DataView<'_>> {
+  let mut view = DataView::from_data(env, vec![0; 23])?;
+  view.set_u8(0, header.kind)?;
+  view.set_i16(1, header.flags, false)?;
+  view.set_u32(3, header.length, true)?;
+  view.set_u64(7, header.timestamp.get_u64().1, true)?;
+  view.set_f64(15, header.ratio, true)?;
+  Ok(view)
+}
+
+#[napi]
+fn get_dataview_byte_offset(view: DataView) -> Result<Vec<u32>> {
+  Ok(vec![
+    view.byte_offset() as u32,
+    view.len() as u32,
+    view.arraybuffer()?.len() as u32,
+  ])
+}
+
 #[napi]
 fn u8_array_to_array(input: &[u8]) -> Vec<u8> {
   input.to_vec()
@@ -157,6 +321,16 @@ fn i64_array_to_array(input: &[i64]) -> Vec<i64> {
   input.to_vec()
 }
 
+#[napi]
+fn bigint64_array_round_trip(input: BigInt64Array) -> BigInt64Array {
+  BigInt64Array::new(input.to_vec())
+}
+
+#[napi]
+fn biguint64_array_round_trip(input: BigUint64Array) -> BigUint64Array {
+  BigUint64Array::new(input.to_vec())
+}
+
 #[napi]
 fn accept_uint8_clamped_slice(input: Uint8ClampedSlice) -> usize {
   input.len()
@@ -0,0 +1,12 @@
+//! This is testing that `#[napi(custom_finalize)]` and `#[napi(async_finalize)]` can't be used together
+
+use napi_derive::napi;
+
+#[napi(custom_finalize, async_finalize)]
+pub struct Resource {
+  pub fd: u32,
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
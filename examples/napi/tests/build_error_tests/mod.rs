@@ -1,8 +1,12 @@
 //! Include the test files here so they can be formatted properly with `cargo fmt`
 
 pub mod assign_js_value_to_class;
+pub mod custom_and_async_finalize;
 pub mod fn_outside_impl_factory;
+pub mod rest_arg_1;
+pub mod rest_arg_2;
 pub mod ts_arg_type_1;
 pub mod ts_arg_type_2;
 pub mod ts_arg_type_3;
 pub mod ts_arg_type_4;
+pub mod zero_copy;
@@ -0,0 +1,12 @@
+//! This is testing that `#[napi(rest)]` can only be applied to the last argument
+
+use napi_derive::napi;
+
+#[napi]
+pub fn join(#[napi(rest)] parts: Vec<String>, separator: String) -> String {
+  parts.join(&separator)
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
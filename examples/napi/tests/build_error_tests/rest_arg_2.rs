@@ -0,0 +1,12 @@
+//! This is testing that `#[napi(rest)]` fails if the argument is not a `Vec<T>`
+
+use napi_derive::napi;
+
+#[napi]
+pub fn sum(#[napi(rest)] values: Option<u32>) -> u32 {
+  values.unwrap_or_default()
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
@@ -1,5 +1,5 @@
 //! This is testing that `#[napi(ts_arg_type="...")]` fails if the attribute is something other than
-//! `ts_arg_type`
+//! `ts_arg_type` or `rest`
 
 use napi_derive::napi;
 
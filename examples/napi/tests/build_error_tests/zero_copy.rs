@@ -0,0 +1,20 @@
+//! This is testing that `#[napi(zero_copy)]` fails if the method doesn't return `&[u8]` from `&self`
+
+use napi_derive::napi;
+
+#[napi]
+pub struct Bytes {
+  data: Vec<u8>,
+}
+
+#[napi]
+impl Bytes {
+  #[napi(getter, zero_copy)]
+  pub fn data(&mut self) -> &[u8] {
+    &self.data
+  }
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
@@ -8,6 +8,9 @@ mod build_error_tests;
 fn run_build_error_tests() {
   let t = trybuild::TestCases::new();
   t.compile_fail("tests/build_error_tests/ts_arg_type_*.rs");
+  t.compile_fail("tests/build_error_tests/rest_arg_*.rs");
   t.compile_fail("tests/build_error_tests/fn_outside_impl_factory.rs");
   t.compile_fail("tests/build_error_tests/assign_js_value_to_class.rs");
+  t.compile_fail("tests/build_error_tests/custom_and_async_finalize.rs");
+  t.compile_fail("tests/build_error_tests/zero_copy.rs");
 }